@@ -0,0 +1,170 @@
+//! DataFusion scalar functions exposing `rutcl` validation to SQL, mirroring
+//! the PostgreSQL extension in `rutcl-pg`.
+//!
+//! ```sql
+//! SELECT rut_is_valid('17.951.585-7');     -- true
+//! SELECT rut_format('179515857', 'dots');  -- '17.951.585-7'
+//! SELECT rut_num('17.951.585-7');          -- 17951585
+//! ```
+//!
+//! The crate lives outside of the workspace, test it with
+//! `just datafusion-test`.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, BooleanArray, Int64Array, StringArray};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::cast::as_string_array;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{create_udf, ColumnarValue, Volatility};
+use datafusion::prelude::SessionContext;
+use rutcl::{Format, Rut};
+
+/// Registers `rut_is_valid`, `rut_format` and `rut_num` in the provided
+/// [`SessionContext`]
+pub fn register(ctx: &SessionContext) {
+    ctx.register_udf(create_udf(
+        "rut_is_valid",
+        vec![DataType::Utf8],
+        DataType::Boolean,
+        Volatility::Immutable,
+        Arc::new(rut_is_valid),
+    ));
+    ctx.register_udf(create_udf(
+        "rut_format",
+        vec![DataType::Utf8, DataType::Utf8],
+        DataType::Utf8,
+        Volatility::Immutable,
+        Arc::new(rut_format),
+    ));
+    ctx.register_udf(create_udf(
+        "rut_num",
+        vec![DataType::Utf8],
+        DataType::Int64,
+        Volatility::Immutable,
+        Arc::new(rut_num),
+    ));
+}
+
+/// Checks whether the provided text is a valid RUT
+fn rut_is_valid(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let args = ColumnarValue::values_to_arrays(args)?;
+    let out = as_string_array(&args[0])?
+        .iter()
+        .map(|input| input.map(|input| Rut::from_str(input).is_ok()))
+        .collect::<BooleanArray>();
+
+    Ok(ColumnarValue::Array(Arc::new(out) as ArrayRef))
+}
+
+/// Writes the provided RUT in the format named by `style` (`sans`, `dash`
+/// or `dots`), `NULL` if the RUT is not valid.
+///
+/// Fails if `style` is not a known format.
+fn rut_format(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let args = ColumnarValue::values_to_arrays(args)?;
+    let out = as_string_array(&args[0])?
+        .iter()
+        .zip(as_string_array(&args[1])?.iter())
+        .map(|(input, style)| {
+            let (Some(input), Some(style)) = (input, style) else {
+                return Ok(None);
+            };
+            let fmt = Format::from_str(style).map_err(|_| {
+                DataFusionError::Execution(format!(
+                    "unknown RUT format {style:?}, expected sans, dash or dots"
+                ))
+            })?;
+
+            Ok(Rut::from_str(input).ok().map(|rut| rut.format(fmt)))
+        })
+        .collect::<Result<StringArray>>()?;
+
+    Ok(ColumnarValue::Array(Arc::new(out) as ArrayRef))
+}
+
+/// Retrieves the number of the provided RUT, `NULL` if it is not valid
+fn rut_num(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let args = ColumnarValue::values_to_arrays(args)?;
+    let out = as_string_array(&args[0])?
+        .iter()
+        .map(|input| {
+            input
+                .and_then(|input| Rut::from_str(input).ok())
+                .map(|rut| i64::from(rut.num()))
+        })
+        .collect::<Int64Array>();
+
+    Ok(ColumnarValue::Array(Arc::new(out) as ArrayRef))
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Array, BooleanArray, Int64Array, StringArray};
+    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::prelude::SessionContext;
+
+    async fn query(sql: &str) -> RecordBatch {
+        let ctx = SessionContext::new();
+
+        super::register(&ctx);
+
+        let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+
+        batches.into_iter().next().unwrap()
+    }
+
+    fn column<T: 'static>(batch: &RecordBatch, idx: usize) -> &T {
+        batch.column(idx).as_any().downcast_ref::<T>().unwrap()
+    }
+
+    #[tokio::test]
+    async fn validates_ruts() {
+        let batch = query(
+            "SELECT rut_is_valid(rut) FROM (VALUES ('17.951.585-7'), ('17.951.585-0'), (NULL)) AS t(rut)",
+        )
+        .await;
+        let valid = column::<BooleanArray>(&batch, 0);
+
+        assert!(valid.value(0));
+        assert!(!valid.value(1));
+        assert!(valid.is_null(2));
+    }
+
+    #[tokio::test]
+    async fn formats_ruts() {
+        let batch = query(
+            "SELECT rut_format(rut, 'dots') FROM (VALUES ('179515857'), ('17.951.585-0')) AS t(rut)",
+        )
+        .await;
+        let formatted = column::<StringArray>(&batch, 0);
+
+        assert_eq!(formatted.value(0), "17.951.585-7");
+        assert!(formatted.is_null(1));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_styles() {
+        let ctx = SessionContext::new();
+
+        super::register(&ctx);
+
+        let result = ctx
+            .sql("SELECT rut_format('179515857', 'spaces')")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn extracts_numbers() {
+        let batch = query("SELECT rut_num('17.951.585-7'), rut_num('17.951.585-0')").await;
+
+        assert_eq!(column::<Int64Array>(&batch, 0).value(0), 17_951_585);
+        assert!(column::<Int64Array>(&batch, 1).is_null(0));
+    }
+}
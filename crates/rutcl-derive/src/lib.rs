@@ -0,0 +1,211 @@
+//! Derive macros for `rutcl`, refer to `rutcl::RutNewtype` for usage.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, GenericParam, Generics,
+    WherePredicate,
+};
+
+/// Implements `FromStr`, `Display` and conversions to and from `rutcl::Rut`
+/// for a tuple struct wrapping a single `rutcl::Rut`.
+///
+/// Add `#[rut(serde)]` to also implement `serde::Serialize` and
+/// `serde::Deserialize`, which requires the `serde` feature in `rutcl`. The
+/// expanded code reaches `serde` and `sqlx` through `rutcl`, so the deriving
+/// crate doesn't need to depend on them.
+///
+/// Add `#[rut(sqlx)]` to also implement `sqlx::Type`, `sqlx::Encode` and
+/// `sqlx::Decode`, which requires the `sqlx` feature in `rutcl`. Both can
+/// be combined as `#[rut(serde, sqlx)]`.
+#[proc_macro_derive(RutNewtype, attributes(rut))]
+pub fn derive_rut_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let is_newtype = match &input.data {
+        Data::Struct(data) => {
+            matches!(&data.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+        }
+        _ => false,
+    };
+
+    if !is_newtype {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "RutNewtype can only be derived for tuple structs with a single `Rut` field",
+        ));
+    }
+
+    let mut with_serde = false;
+    let mut with_sqlx = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("rut"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("serde") {
+                with_serde = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("sqlx") {
+                with_sqlx = true;
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported rut attribute, expected `serde` or `sqlx`"))
+        })?;
+    }
+
+    let mut expanded = quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::rutcl::Error;
+
+            fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+                <::rutcl::Rut as ::std::str::FromStr>::from_str(input).map(#name)
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<::rutcl::Rut> for #name #ty_generics #where_clause {
+            fn from(rut: ::rutcl::Rut) -> Self {
+                #name(rut)
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#name #ty_generics> for ::rutcl::Rut #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                value.0
+            }
+        }
+
+        impl #impl_generics ::std::convert::AsRef<::rutcl::Rut> for #name #ty_generics #where_clause {
+            fn as_ref(&self) -> &::rutcl::Rut {
+                &self.0
+            }
+        }
+    };
+
+    if with_serde {
+        let de_generics = extend_generics(&input.generics, &[parse_quote!('de)], &[]);
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+        expanded.extend(quote! {
+            impl #impl_generics ::rutcl::__private::serde::Serialize for #name #ty_generics #where_clause {
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::rutcl::__private::serde::Serializer,
+                {
+                    ::rutcl::__private::serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl #de_impl_generics ::rutcl::__private::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::rutcl::__private::serde::Deserializer<'de>,
+                {
+                    <::rutcl::Rut as ::rutcl::__private::serde::Deserialize<'de>>::deserialize(deserializer).map(#name)
+                }
+            }
+        });
+    }
+
+    if with_sqlx {
+        let type_generics = extend_generics(
+            &input.generics,
+            &[parse_quote!(DB: ::rutcl::__private::sqlx::Database)],
+            &[parse_quote!(::rutcl::Rut: ::rutcl::__private::sqlx::Type<DB>)],
+        );
+        let encode_generics = extend_generics(
+            &input.generics,
+            &[
+                parse_quote!('q),
+                parse_quote!(DB: ::rutcl::__private::sqlx::Database),
+            ],
+            &[parse_quote!(::rutcl::Rut: ::rutcl::__private::sqlx::Encode<'q, DB>)],
+        );
+        let decode_generics = extend_generics(
+            &input.generics,
+            &[
+                parse_quote!('r),
+                parse_quote!(DB: ::rutcl::__private::sqlx::Database),
+            ],
+            &[parse_quote!(::rutcl::Rut: ::rutcl::__private::sqlx::Decode<'r, DB>)],
+        );
+        let (type_impl_generics, _, type_where_clause) = type_generics.split_for_impl();
+        let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+        let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+
+        expanded.extend(quote! {
+            impl #type_impl_generics ::rutcl::__private::sqlx::Type<DB> for #name #ty_generics #type_where_clause {
+                fn type_info() -> <DB as ::rutcl::__private::sqlx::Database>::TypeInfo {
+                    <::rutcl::Rut as ::rutcl::__private::sqlx::Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &<DB as ::rutcl::__private::sqlx::Database>::TypeInfo) -> bool {
+                    <::rutcl::Rut as ::rutcl::__private::sqlx::Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl #encode_impl_generics ::rutcl::__private::sqlx::Encode<'q, DB> for #name #ty_generics #encode_where_clause {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as ::rutcl::__private::sqlx::Database>::ArgumentBuffer<'q>,
+                ) -> ::std::result::Result<::rutcl::__private::sqlx::encode::IsNull, ::rutcl::__private::sqlx::error::BoxDynError> {
+                    <::rutcl::Rut as ::rutcl::__private::sqlx::Encode<'q, DB>>::encode_by_ref(&self.0, buf)
+                }
+            }
+
+            impl #decode_impl_generics ::rutcl::__private::sqlx::Decode<'r, DB> for #name #ty_generics #decode_where_clause {
+                fn decode(
+                    value: <DB as ::rutcl::__private::sqlx::Database>::ValueRef<'r>,
+                ) -> ::std::result::Result<Self, ::rutcl::__private::sqlx::error::BoxDynError> {
+                    <::rutcl::Rut as ::rutcl::__private::sqlx::Decode<'r, DB>>::decode(value).map(#name)
+                }
+            }
+        });
+    }
+
+    Ok(expanded)
+}
+
+/// Adds the generic parameters and where predicates taken by a trait impl
+/// to the type's own generics, lifetimes going first
+fn extend_generics(
+    generics: &Generics,
+    params: &[GenericParam],
+    predicates: &[WherePredicate],
+) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in params {
+        match param {
+            GenericParam::Lifetime(_) => generics.params.insert(0, param.clone()),
+            _ => generics.params.push(param.clone()),
+        }
+    }
+
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(predicates.iter().cloned());
+    generics
+}
@@ -0,0 +1,84 @@
+//! PostgreSQL extension exposing `rutcl` validation to SQL.
+//!
+//! ```sql
+//! CREATE EXTENSION rutcl_pg;
+//!
+//! SELECT rut_is_valid('17.951.585-7');        -- true
+//! SELECT rut_normalize('179515857', 'dots');  -- '17.951.585-7'
+//!
+//! CREATE TABLE taxpayers (id rut PRIMARY KEY);
+//! ```
+
+use std::str::FromStr;
+
+use pgrx::prelude::*;
+use rutcl::{Format, Rut};
+
+::pgrx::pg_module_magic!();
+
+/// Checks whether the provided text is a valid RUT
+#[pg_extern(immutable, parallel_safe, strict)]
+fn rut_is_valid(input: &str) -> bool {
+    Rut::from_str(input).is_ok()
+}
+
+/// Writes the provided RUT in the format named by `style` (`sans`, `dash`
+/// or `dots`), `NULL` if the RUT is not valid.
+///
+/// Raises an error if `style` is not a known format.
+#[pg_extern(immutable, parallel_safe, strict)]
+fn rut_normalize(input: &str, style: &str) -> Option<String> {
+    let fmt = Format::from_str(style)
+        .unwrap_or_else(|_| error!("unknown RUT format {style:?}, expected sans, dash or dots"));
+
+    Rut::from_str(input).ok().map(|rut| rut.format(fmt))
+}
+
+extension_sql!(
+    r#"
+    CREATE DOMAIN rut AS text CHECK (rut_is_valid(VALUE));
+    "#,
+    name = "rut_domain",
+    requires = [rut_is_valid],
+);
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pg_schema]
+mod tests {
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn validates_ruts() {
+        assert!(crate::rut_is_valid("17.951.585-7"));
+        assert!(!crate::rut_is_valid("17.951.585-0"));
+    }
+
+    #[pg_test]
+    fn normalizes_ruts() {
+        assert_eq!(
+            crate::rut_normalize("179515857", "dots").as_deref(),
+            Some("17.951.585-7")
+        );
+        assert_eq!(crate::rut_normalize("179515850", "dots"), None);
+    }
+
+    #[pg_test]
+    fn domain_accepts_valid_ruts() {
+        assert!(Spi::run("SELECT '17.951.585-7'::rut").is_ok());
+    }
+
+    #[pg_test(error = "value for domain rut violates check constraint \"rut_check\"")]
+    fn domain_rejects_invalid_ruts() {
+        Spi::run("SELECT '17.951.585-0'::rut").unwrap();
+    }
+}
+
+/// Required by `cargo pgrx test`
+#[cfg(test)]
+pub mod pg_test {
+    pub fn setup(_options: Vec<&str>) {}
+
+    pub fn postgresql_conf_options() -> Vec<&'static str> {
+        vec![]
+    }
+}
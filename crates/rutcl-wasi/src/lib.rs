@@ -0,0 +1,60 @@
+//! WASI Preview 2 component exporting the `rutcl:rut/rut` interface defined
+//! in `wit/rut.wit`.
+//!
+//! The crate lives outside of the workspace, build it with `just wasi-build`
+//! or with `cargo build --manifest-path crates/rutcl-wasi/Cargo.toml --target
+//! wasm32-wasip2 --release` from the repository root. The resulting `.wasm`
+//! runs in Wasmtime and Spin, and `jco transpile` turns it into an ES module
+//! for Deno and browsers.
+
+use std::str::FromStr;
+
+use rutcl::{RandomOptions, Rut};
+
+use exports::rutcl::rut::rut::{Error, Guest, Style};
+
+wit_bindgen::generate!({
+    world: "rutcl",
+    path: "wit",
+});
+
+struct Component;
+
+impl Guest for Component {
+    fn validate(input: String) -> Result<(), Error> {
+        Rut::from_str(&input).map(drop).map_err(Error::from)
+    }
+
+    fn format(input: String, style: Style) -> Result<String, Error> {
+        Rut::from_str(&input)
+            .map(|rut| rut.format(style.into()))
+            .map_err(Error::from)
+    }
+
+    fn random(style: Style) -> Result<String, Error> {
+        Rut::random_with(&RandomOptions::SAFE)
+            .map(|rut| rut.format(style.into()))
+            .map_err(Error::from)
+    }
+}
+
+impl From<Style> for rutcl::Format {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Sans => rutcl::Format::Sans,
+            Style::Dash => rutcl::Format::Dash,
+            Style::Dots => rutcl::Format::Dots,
+        }
+    }
+}
+
+impl From<rutcl::Error> for Error {
+    fn from(err: rutcl::Error) -> Self {
+        Error {
+            kind: err.kind().replace('_', "-"),
+            message: err.to_string(),
+        }
+    }
+}
+
+export!(Component);
@@ -0,0 +1,44 @@
+//! Compares `Rut::sans` with `Rut::sans_cow` on clean and formatted bulk
+//! inputs. Run with `cargo bench --bench sans`, the allocation columns show
+//! `sans_cow` allocating nothing for inputs which are already clean.
+
+use divan::{black_box, AllocProfiler, Bencher};
+use rutcl::{Format, Rut};
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+const LEN: usize = 1_000;
+
+fn main() {
+    divan::main();
+}
+
+/// RUTs written in the provided format, as found in a bulk export
+fn inputs(fmt: Format) -> Vec<String> {
+    Rut::sample_fixture(LEN, 0)
+        .map(|rut| rut.format(fmt))
+        .collect()
+}
+
+#[divan::bench(args = [Format::Sans, Format::Dots])]
+fn sans(bencher: Bencher, fmt: Format) {
+    let inputs = inputs(fmt);
+
+    bencher.bench_local(|| {
+        for input in &inputs {
+            black_box(Rut::sans(black_box(input)));
+        }
+    });
+}
+
+#[divan::bench(args = [Format::Sans, Format::Dots])]
+fn sans_cow(bencher: Bencher, fmt: Format) {
+    let inputs = inputs(fmt);
+
+    bencher.bench_local(|| {
+        for input in &inputs {
+            black_box(Rut::sans_cow(black_box(input)));
+        }
+    });
+}
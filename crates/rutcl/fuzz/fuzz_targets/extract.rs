@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rutcl::{Extractor, Rut};
+
+fuzz_target!(|text: &str| {
+    let strict = Rut::parse_many_from_text(text);
+    let tolerant = Extractor::builder().build().extract(text);
+
+    for found in strict.iter().chain(tolerant.iter()) {
+        assert_eq!(&text[found.range()], found.as_str());
+        assert_eq!(
+            Rut::parse_sanitized(found.as_str(), rutcl::SanitizeOptions::LENIENT).ok(),
+            Some(found.rut())
+        );
+    }
+
+    for pair in strict.windows(2).chain(tolerant.windows(2)) {
+        assert!(pair[0].end() <= pair[1].start());
+    }
+});
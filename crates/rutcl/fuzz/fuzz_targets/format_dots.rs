@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rutcl::{Format, Rut};
+
+fuzz_target!(|num: u32| {
+    let Ok(rut) = Rut::try_from(num) else {
+        return;
+    };
+
+    let dots = rut.format(Format::Dots);
+
+    assert_eq!(Rut::from_formatted(&dots, Format::Dots).ok(), Some(rut));
+    assert_eq!(dots.replace('.', ""), rut.format(Format::Dash));
+    rutcl::fuzz_check(dots.as_bytes());
+});
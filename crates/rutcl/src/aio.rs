@@ -0,0 +1,72 @@
+//! Async file validation on top of `tokio`.
+
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, BufReader, Lines};
+
+use crate::{Error, Rut};
+
+/// Opens the file at `path` and validates it line by line, one RUT per
+/// line, without blocking the runtime.
+///
+/// Fails if the file can't be opened. The returned [`ValidateFile`] stream
+/// yields each line number (starting at `1`) along with the parsing result,
+/// or the I/O error which stopped reading.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+///
+/// # async fn run() -> std::io::Result<()> {
+/// let mut lines = rutcl::aio::validate_file("ruts.txt").await?;
+///
+/// while let Some(line) = lines.next().await {
+///     let (line, result) = line?;
+///
+///     if let Err(err) = result {
+///         eprintln!("line {line}: {err}");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn validate_file<P: AsRef<Path>>(path: P) -> io::Result<ValidateFile> {
+    let file = File::open(path).await?;
+
+    Ok(ValidateFile {
+        lines: BufReader::new(file).lines(),
+        line: 0,
+    })
+}
+
+/// Stream returned by [`validate_file`]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ValidateFile {
+    lines: Lines<BufReader<File>>,
+    line: u64,
+}
+
+impl Stream for ValidateFile {
+    type Item = io::Result<(u64, Result<Rut, Error>)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.lines).poll_next_line(cx) {
+            Poll::Ready(Ok(Some(text))) => {
+                self.line += 1;
+
+                Poll::Ready(Some(Ok((self.line, Rut::from_str(&text)))))
+            }
+            Poll::Ready(Ok(None)) => Poll::Ready(None),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
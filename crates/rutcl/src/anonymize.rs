@@ -0,0 +1,153 @@
+//! Deterministic, format-preserving RUT pseudonymization.
+//!
+//! A [`Vault`] maps every RUT in a reserved [`RutBand`] to another RUT in the
+//! same band, keyed by a secret. The output is a valid [`Rut`], so anonymized
+//! datasets keep working with systems which validate RUTs, and the mapping can
+//! be reversed by whoever holds the secret.
+//!
+//! Every number in [`RANGE`](crate::RANGE) may be assigned to a taxpayer, so
+//! reserve a band for the shared dataset (e.g. [`RutBand::custom`]) and
+//! re-number its records into the band before tokenizing them.
+//!
+//! # Algorithm
+//!
+//! The band is treated as the domain `0..len`. RUT numbers are encrypted with
+//! a balanced Feistel network of [`ROUNDS`] rounds over the smallest even
+//! number of bits covering the domain, using keyed BLAKE3 as round function.
+//! Values falling outside of the domain are encrypted again until they land
+//! inside of it (cycle walking), which keeps the mapping a permutation of the
+//! band.
+//!
+//! > This is not a vetted format-preserving encryption scheme such as FF1, it
+//! > is meant to share realistic test data, not to protect data at rest.
+
+use crate::{Error, Num, Rut, RutBand, VerificationDigit};
+
+/// Context used to derive the BLAKE3 key from the secret
+const VAULT_CONTEXT: &str = "rutcl 2024-03-01 RUT vault v1";
+
+/// Number of Feistel rounds
+pub const ROUNDS: u8 = 8;
+
+/// Keyed, reversible permutation of the RUTs inside of a reserved
+/// [`RutBand`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::anonymize::Vault;
+/// use rutcl::{Rut, RutBand};
+///
+/// let band = RutBand::custom(10_000_000, 10_999_999).unwrap();
+/// let vault = Vault::new(b"vendor export secret", band);
+/// let rut: Rut = "10.123.456-8".parse().unwrap();
+/// let token = vault.tokenize(&rut).unwrap();
+///
+/// assert!(band.contains(&token));
+/// assert_eq!(token.to_string().parse::<Rut>().unwrap(), token);
+/// assert_eq!(vault.detokenize(&token).unwrap(), rut);
+/// ```
+#[derive(Clone)]
+pub struct Vault {
+    key: [u8; 32],
+    band: RutBand,
+    min: Num,
+    len: u32,
+    half_bits: u32,
+}
+
+impl Vault {
+    /// Creates a [`Vault`] mapping RUTs inside of the provided [`RutBand`]
+    /// using a key derived from the provided secret.
+    pub fn new(secret: &[u8], band: RutBand) -> Self {
+        let range = band.range();
+        let min = *range.start();
+        let len = range.end() - min + 1;
+        let bits = u32::BITS - (len - 1).leading_zeros();
+
+        Vault {
+            key: blake3::derive_key(VAULT_CONTEXT, secret),
+            band,
+            min,
+            len,
+            half_bits: bits.div_ceil(2),
+        }
+    }
+
+    /// Retrieves the [`RutBand`] this vault maps RUTs in
+    #[inline]
+    pub fn band(&self) -> RutBand {
+        self.band
+    }
+
+    /// Maps the provided [`Rut`] into its pseudonym, another [`Rut`] in the
+    /// vault's band.
+    ///
+    /// Fails with [`Error::OutOfBand`] if the RUT is not in the vault's band.
+    pub fn tokenize(&self, rut: &Rut) -> Result<Rut, Error> {
+        self.map(rut, |value| self.encrypt(value))
+    }
+
+    /// Maps a pseudonym produced by [`Vault::tokenize`] back into the
+    /// original [`Rut`].
+    ///
+    /// Fails with [`Error::OutOfBand`] if the RUT is not in the vault's band.
+    pub fn detokenize(&self, token: &Rut) -> Result<Rut, Error> {
+        self.map(token, |value| self.decrypt(value))
+    }
+
+    fn map(&self, rut: &Rut, permute: impl Fn(u32) -> u32) -> Result<Rut, Error> {
+        if !self.band.contains(rut) {
+            return Err(Error::OutOfBand(self.band));
+        }
+
+        let num = self.min + self.walk(rut.num() - self.min, permute);
+
+        Ok(Rut(num, VerificationDigit::compute(num)))
+    }
+
+    /// Applies the provided permutation until the value lands in the domain
+    fn walk(&self, value: u32, permute: impl Fn(u32) -> u32) -> u32 {
+        let mut value = permute(value);
+
+        while value >= self.len {
+            value = permute(value);
+        }
+
+        value
+    }
+
+    fn encrypt(&self, value: u32) -> u32 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut left, mut right) = (value >> self.half_bits, value & mask);
+
+        for round in 0..ROUNDS {
+            (left, right) = (right, left ^ (self.round(round, right) & mask));
+        }
+
+        (left << self.half_bits) | right
+    }
+
+    fn decrypt(&self, value: u32) -> u32 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut left, mut right) = (value >> self.half_bits, value & mask);
+
+        for round in (0..ROUNDS).rev() {
+            (left, right) = (right ^ (self.round(round, left) & mask), left);
+        }
+
+        (left << self.half_bits) | right
+    }
+
+    /// Feistel round function
+    fn round(&self, round: u8, half: u32) -> u32 {
+        let mut hasher = blake3::Hasher::new_keyed(&self.key);
+        hasher.update(&[round]);
+        hasher.update(&half.to_le_bytes());
+
+        let hash = hasher.finalize();
+        let bytes = hash.as_bytes();
+
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+}
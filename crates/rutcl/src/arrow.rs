@@ -0,0 +1,236 @@
+//! Apache Arrow representation of RUT columns.
+//!
+//! RUTs are stored as their number ([`Num`]) in `UInt32` arrays, the
+//! verification digit is derived when reading. Fields holding RUTs are
+//! tagged with the [`EXTENSION_NAME`] extension type, so readers can tell
+//! them apart from plain integers.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::builder::UInt32Builder;
+use arrow_array::{Array, ArrayRef, UInt32Array};
+use arrow_schema::{DataType, Field};
+
+use crate::{Error, Num, Rut, VerificationDigit, RANGE};
+
+/// Name of the Arrow extension type tagging RUT fields
+pub const EXTENSION_NAME: &str = "rutcl.rut";
+
+/// Arrow metadata key holding the extension type name
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Arrow metadata key holding the extension type metadata
+const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// Creates a `UInt32` [`Field`] tagged with the RUT extension type
+pub fn field(name: &str, nullable: bool) -> Field {
+    Field::new(name, DataType::UInt32, nullable).with_metadata(HashMap::from([
+        (EXTENSION_NAME_KEY.to_string(), EXTENSION_NAME.to_string()),
+        (EXTENSION_METADATA_KEY.to_string(), String::new()),
+    ]))
+}
+
+/// Checks whether the provided [`Field`] is tagged with the RUT extension
+/// type
+pub fn is_rut_field(field: &Field) -> bool {
+    field.data_type() == &DataType::UInt32
+        && field
+            .metadata()
+            .get(EXTENSION_NAME_KEY)
+            .is_some_and(|name| name == EXTENSION_NAME)
+}
+
+/// Arrow array of nullable RUTs, backed by a [`UInt32Array`] of RUT numbers.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::arrow::RutArray;
+/// use rutcl::Rut;
+///
+/// let rut: Rut = "17.951.585-7".parse().unwrap();
+/// let array = RutArray::from_iter([Some(rut), None]);
+///
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.value(0), Some(rut));
+/// assert_eq!(array.value(1), None);
+/// assert_eq!(array.as_inner().value(0), 17_951_585);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RutArray(UInt32Array);
+
+impl RutArray {
+    /// Creates a [`RutArrayBuilder`] with room for `capacity` RUTs
+    pub fn builder(capacity: usize) -> RutArrayBuilder {
+        RutArrayBuilder(UInt32Builder::with_capacity(capacity))
+    }
+
+    /// Reads a [`RutArray`] out of an Arrow array.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the array is not a `UInt32`
+    /// array and with [`Error::OutOfRange`] if any value is not a valid RUT
+    /// number.
+    pub fn try_from_array(array: &dyn Array) -> Result<Self, Error> {
+        let array = array
+            .as_any()
+            .downcast_ref::<UInt32Array>()
+            .ok_or(Error::InvalidFormat)?;
+
+        if array.iter().flatten().any(|num| !RANGE.contains(&num)) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(RutArray(array.clone()))
+    }
+
+    /// Retrieves the [`Rut`] at `idx`, `None` if the slot is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn value(&self, idx: usize) -> Option<Rut> {
+        if self.0.is_null(idx) {
+            return None;
+        }
+
+        Some(rut(self.0.value(idx)))
+    }
+
+    /// Iterates over the RUTs in this array, yielding `None` for nulls
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Option<Rut>> + '_ {
+        self.0.iter().map(|num| num.map(rut))
+    }
+
+    /// Amount of slots, including nulls
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether the array has no slots
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Retrieves the underlying [`UInt32Array`]
+    pub fn as_inner(&self) -> &UInt32Array {
+        &self.0
+    }
+
+    /// Retrieves the underlying [`UInt32Array`]
+    pub fn into_inner(self) -> UInt32Array {
+        self.0
+    }
+
+    /// Retrieves the underlying array as an [`ArrayRef`], ready to be used
+    /// in a `RecordBatch`
+    pub fn into_array_ref(self) -> ArrayRef {
+        Arc::new(self.0)
+    }
+}
+
+impl FromIterator<Option<Rut>> for RutArray {
+    fn from_iter<T: IntoIterator<Item = Option<Rut>>>(iter: T) -> Self {
+        RutArray(
+            iter.into_iter()
+                .map(|rut| rut.map(|rut| rut.num()))
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<Rut> for RutArray {
+    fn from_iter<T: IntoIterator<Item = Rut>>(iter: T) -> Self {
+        RutArray(iter.into_iter().map(|rut| Some(rut.num())).collect())
+    }
+}
+
+impl From<RutArray> for UInt32Array {
+    fn from(array: RutArray) -> Self {
+        array.0
+    }
+}
+
+/// Builder for a [`RutArray`], created with [`RutArray::builder`]
+#[derive(Debug)]
+pub struct RutArrayBuilder(UInt32Builder);
+
+impl RutArrayBuilder {
+    /// Appends a [`Rut`]
+    pub fn append(&mut self, rut: Rut) {
+        self.0.append_value(rut.num());
+    }
+
+    /// Appends a null slot
+    pub fn append_null(&mut self) {
+        self.0.append_null();
+    }
+
+    /// Appends a [`Rut`] or a null slot
+    pub fn append_option(&mut self, rut: Option<Rut>) {
+        self.0.append_option(rut.map(|rut| rut.num()));
+    }
+
+    /// Builds the [`RutArray`], leaving the builder empty
+    pub fn finish(&mut self) -> RutArray {
+        RutArray(self.0.finish())
+    }
+}
+
+/// Builds a [`Rut`] from a number already known to be valid
+fn rut(num: Num) -> Rut {
+    Rut(num, VerificationDigit::compute(num))
+}
+
+/// Parquet read and write helpers for RUT columns
+#[cfg(feature = "parquet")]
+pub mod parquet {
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::arrow::ArrowWriter;
+    use parquet::errors::ParquetError;
+    use parquet::file::reader::ChunkReader;
+
+    use super::{field, RutArray};
+    use crate::Rut;
+
+    /// Writes a Parquet file with a single RUT column named `name`
+    pub fn write<W>(writer: W, name: &str, ruts: RutArray) -> Result<(), ParquetError>
+    where
+        W: Write + Send,
+    {
+        let schema = Arc::new(Schema::new(vec![field(name, true)]));
+        let batch = RecordBatch::try_new(Arc::clone(&schema), vec![ruts.into_array_ref()])?;
+        let mut writer = ArrowWriter::try_new(writer, schema, None)?;
+
+        writer.write(&batch)?;
+        writer.close()?;
+
+        Ok(())
+    }
+
+    /// Reads the RUT column named `name` out of a Parquet file
+    pub fn read<R>(reader: R, name: &str) -> Result<Vec<Option<Rut>>, ParquetError>
+    where
+        R: ChunkReader + 'static,
+    {
+        let mut ruts = Vec::new();
+
+        for batch in ParquetRecordBatchReaderBuilder::try_new(reader)?.build()? {
+            let batch = batch?;
+            let column = batch
+                .column_by_name(name)
+                .ok_or_else(|| ParquetError::General(format!("missing column {name:?}")))?;
+            let array = RutArray::try_from_array(column)
+                .map_err(|err| ParquetError::General(format!("column {name:?}: {err}")))?;
+
+            ruts.extend(array.iter());
+        }
+
+        Ok(ruts)
+    }
+}
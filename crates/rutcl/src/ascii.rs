@@ -0,0 +1,38 @@
+//! Stack buffer formatting shared by the allocation-free writers.
+
+use crate::{Format, Rut};
+
+/// Length of the longest formatted RUT, `99.999.999-9`
+pub(crate) const MAX_LEN: usize = 12;
+
+impl Rut {
+    /// Writes the provided [`Format`] into `buf` without using `core::fmt`,
+    /// returning the written `str`
+    pub(crate) fn ascii<'b>(&self, fmt: Format, buf: &'b mut [u8; MAX_LEN]) -> &'b str {
+        let mut num = self.num();
+        let mut idx = MAX_LEN;
+        let mut digits = 0;
+
+        buf[idx - 1] = char::from(self.vd()) as u8;
+        idx -= 1;
+
+        if fmt != Format::Sans {
+            buf[idx - 1] = b'-';
+            idx -= 1;
+        }
+
+        while num > 0 {
+            if fmt == Format::Dots && digits > 0 && digits % 3 == 0 {
+                buf[idx - 1] = b'.';
+                idx -= 1;
+            }
+
+            buf[idx - 1] = b'0' + (num % 10) as u8;
+            idx -= 1;
+            num /= 10;
+            digits += 1;
+        }
+
+        std::str::from_utf8(&buf[idx..]).expect("Formatted RUTs are ASCII")
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt::Display;
+use std::ops::{Deref, RangeInclusive};
+use std::str::FromStr;
+
+use crate::{Error, Num, Rut, RutRange, VerificationDigit, MAX_NUM, MIN_NUM, RANGE};
+
+/// Max number for a RUT assigned to a natural person, upper bound of
+/// [`RutBand::Persons`]
+pub const PERSON_MAX_NUM: Num = 49_999_999;
+
+/// Min number for a RUT assigned to a company (legal person), lower bound of
+/// [`RutBand::Companies`]
+pub const COMPANY_MIN_NUM: Num = 50_000_000;
+
+/// Min number for a RUT assigned to a foreign investor, lower bound of
+/// [`RutBand::ForeignInvestors`]
+pub const FOREIGN_INVESTOR_MIN_NUM: Num = 46_000_000;
+
+/// Max number for a RUT assigned to a foreign investor, upper bound of
+/// [`RutBand::ForeignInvestors`]
+pub const FOREIGN_INVESTOR_MAX_NUM: Num = 47_999_999;
+
+/// Institutional band of RUT numbers.
+///
+/// Bands are expressed over the RUT's number ([`Num`]), the
+/// [`VerificationDigit`] is always derived from it.
+///
+/// > The cut-offs for the named bands follow the conventions used by the SII
+/// > when assigning RUTs, but are not a legal guarantee of the taxpayer kind.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RutBand {
+    /// RUTs assigned to natural persons, from `1.000.000` to `49.999.999`
+    Persons,
+    /// RUTs assigned to companies, from `50.000.000` to `99.999.999`
+    Companies,
+    /// RUTs assigned to foreign investors, from `46.000.000` to `47.999.999`
+    ForeignInvestors,
+    /// User defined band, created with [`RutBand::custom`]
+    Custom(CustomBand),
+}
+
+/// Bounds of a user defined [`RutBand`], both inclusive.
+///
+/// Only [`RutBand::custom`] creates them, so bounds are always valid RUT
+/// numbers and `min` is never greater than `max`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CustomBand {
+    min: Num,
+    max: Num,
+}
+
+impl CustomBand {
+    /// Band covering the whole [`RANGE`]
+    pub(crate) const ALL: Self = CustomBand {
+        min: MIN_NUM,
+        max: MAX_NUM,
+    };
+}
+
+impl RutBand {
+    /// Creates a user defined [`RutBand`] from `min` to `max` (inclusive).
+    ///
+    /// Fails with [`Error::OutOfRange`] if any of the bounds is not a valid
+    /// RUT number or if `min` is greater than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::RutBand;
+    ///
+    /// let sme = RutBand::custom(76_000_000, 77_999_999).unwrap();
+    ///
+    /// assert!(sme.contains(&"76.086.428-5".parse().unwrap()));
+    /// ```
+    pub fn custom(min: Num, max: Num) -> Result<Self, Error> {
+        if min > max || !RANGE.contains(&min) || !RANGE.contains(&max) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(RutBand::Custom(CustomBand { min, max }))
+    }
+
+    /// Retrieves the range of RUT numbers ([`Num`]) covered by this band
+    pub fn range(&self) -> RangeInclusive<Num> {
+        match self {
+            RutBand::Persons => MIN_NUM..=PERSON_MAX_NUM,
+            RutBand::Companies => COMPANY_MIN_NUM..=MAX_NUM,
+            RutBand::ForeignInvestors => FOREIGN_INVESTOR_MIN_NUM..=FOREIGN_INVESTOR_MAX_NUM,
+            RutBand::Custom(custom) => custom.min..=custom.max,
+        }
+    }
+
+    /// Retrieves the lowest [`Rut`] in this band
+    pub fn min(&self) -> Rut {
+        rut(*self.range().start())
+    }
+
+    /// Retrieves the highest [`Rut`] in this band
+    pub fn max(&self) -> Rut {
+        rut(*self.range().end())
+    }
+
+    /// Checks whether the provided [`Rut`] belongs to this band
+    #[inline]
+    pub fn contains(&self, rut: &Rut) -> bool {
+        self.range().contains(&rut.num())
+    }
+
+    /// Iterates over every [`Rut`] in this band in ascending order
+    pub fn iter(&self) -> RutRange {
+        RutRange::from_nums(self.range())
+    }
+
+    #[cfg(feature = "rand")]
+    /// Generates a random [`Rut`] instance inside this band
+    pub fn random(&self) -> Result<Rut, Error> {
+        Rut::random_in_range(self.range())
+    }
+}
+
+impl Rut {
+    /// Checks whether this RUT is in the [`RutBand::Persons`] band
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert!(rut.in_person_band());
+    /// assert!(!rut.in_company_band());
+    /// ```
+    #[inline]
+    pub fn in_person_band(&self) -> bool {
+        RutBand::Persons.contains(self)
+    }
+
+    /// Checks whether this RUT is in the [`RutBand::Companies`] band
+    #[inline]
+    pub fn in_company_band(&self) -> bool {
+        RutBand::Companies.contains(self)
+    }
+
+    /// Checks whether this RUT is in the [`RutBand::ForeignInvestors`] band
+    #[inline]
+    pub fn in_foreign_investor_band(&self) -> bool {
+        RutBand::ForeignInvestors.contains(self)
+    }
+}
+
+/// Builds a [`Rut`] from a number already known to be in [`RANGE`]
+fn rut(num: Num) -> Rut {
+    Rut(num, VerificationDigit::compute(num))
+}
+
+/// Implements a [`Rut`] newtype which only holds RUTs inside of `$band`
+macro_rules! band_newtype {
+    ($(#[$meta:meta])* $name:ident, $band:expr) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(Rut);
+
+        impl $name {
+            /// Retrieves the inner [`Rut`]
+            #[inline]
+            pub fn into_inner(self) -> Rut {
+                self.0
+            }
+        }
+
+        impl TryFrom<Rut> for $name {
+            type Error = Error;
+
+            fn try_from(rut: Rut) -> Result<Self, Self::Error> {
+                if $band.contains(&rut) {
+                    return Ok($name(rut));
+                }
+
+                Err(Error::OutOfBand($band))
+            }
+        }
+
+        impl From<$name> for Rut {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Rut;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<Rut> for $name {
+            fn as_ref(&self) -> &Rut {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                Rut::from_str(input).and_then($name::try_from)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+band_newtype!(
+    /// A [`Rut`] in the [`RutBand::Persons`] band, also known as RUN.
+    ///
+    /// There is no direct conversion to [`CompanyRut`], go through [`Rut`]
+    /// explicitly instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{PersonRut, Rut};
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let person = PersonRut::try_from(rut).unwrap();
+    ///
+    /// assert_eq!(person.num(), 17_951_585);
+    /// assert!("76.086.428-5".parse::<PersonRut>().is_err());
+    /// ```
+    PersonRut,
+    RutBand::Persons
+);
+
+band_newtype!(
+    /// A [`Rut`] in the [`RutBand::Companies`] band.
+    ///
+    /// There is no direct conversion to [`PersonRut`], go through [`Rut`]
+    /// explicitly instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{CompanyRut, Rut};
+    ///
+    /// let rut: Rut = "76.086.428-5".parse().unwrap();
+    /// let company = CompanyRut::try_from(rut).unwrap();
+    ///
+    /// assert_eq!(Rut::from(company), rut);
+    /// assert!("17.951.585-7".parse::<CompanyRut>().is_err());
+    /// ```
+    CompanyRut,
+    RutBand::Companies
+);
@@ -0,0 +1,125 @@
+//! Helpers for RUTs embedded in barcodes.
+//!
+//! # Cédula de Identidad
+//!
+//! Chilean ID cards carry the RUN in a 2D barcode:
+//!
+//! - Cards issued before 2013 use a PDF417 barcode whose first
+//!   [`CEDULA_RUN_LEN`] bytes hold the RUN as ASCII, number followed by the
+//!   verification digit without separators, left aligned and right padded
+//!   with spaces (e.g. `"70665247 "` or `"179515857"`).
+//! - Newer cards use a QR code with a Registro Civil URL holding the RUN in
+//!   the `RUN` query parameter (e.g. `...docstatus?RUN=17951585-7&type=...`),
+//!   read with [`Rut::from_url`].
+//!
+//! [`Rut::from_barcode_payload`] accepts both.
+//!
+//! # Timbre Electrónico (TED)
+//!
+//! SII electronic documents (DTE) print a PDF417 barcode with the `TED` XML
+//! node, where `RE` holds the issuer (`RUTEmisor`) and `RR` the receiver
+//! (`RUTRecep`), both written as `########-D`. Use [`ted_ruts`] to read them.
+
+use std::str::from_utf8;
+
+use crate::{sii, Error, Rut};
+
+/// Length of the RUN field in the legacy PDF417 cédula barcode
+pub const CEDULA_RUN_LEN: usize = 9;
+
+impl Rut {
+    /// Builds the RUN field of the legacy cédula PDF417 barcode payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "7.066.524-7".parse().unwrap();
+    ///
+    /// assert_eq!(&rut.to_barcode_payload(), b"70665247 ");
+    /// ```
+    pub fn to_barcode_payload(&self) -> [u8; CEDULA_RUN_LEN] {
+        let mut payload = [b' '; CEDULA_RUN_LEN];
+        let num = self.num().to_string();
+
+        payload[..num.len()].copy_from_slice(num.as_bytes());
+        payload[num.len()] = char::from(self.vd()) as u8;
+        payload
+    }
+
+    /// Reads the [`Rut`] out of a cédula barcode payload, either the legacy
+    /// PDF417 one or the newer QR code URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let pdf417 = b"179515857PEREZ                         ";
+    /// let qr = b"https://portal.sidiv.registrocivil.cl/docstatus?RUN=17951585-7&type=CEDULA";
+    ///
+    /// assert_eq!(Rut::from_barcode_payload(pdf417).unwrap().num(), 17_951_585);
+    /// assert_eq!(Rut::from_barcode_payload(qr).unwrap().num(), 17_951_585);
+    /// ```
+    pub fn from_barcode_payload(payload: &[u8]) -> Result<Self, Error> {
+        if payload.starts_with(b"http") {
+            let url = from_utf8(payload).map_err(|_| Error::InvalidFormat)?;
+
+            return Rut::from_url(url);
+        }
+
+        let run = payload.get(..CEDULA_RUN_LEN).ok_or(Error::InvalidFormat)?;
+        let run = from_utf8(run).map_err(|_| Error::InvalidFormat)?;
+
+        if run
+            .trim_end()
+            .contains(|c: char| !c.is_ascii_alphanumeric())
+        {
+            return Err(Error::InvalidFormat);
+        }
+
+        run.trim_end().parse::<Rut>()
+    }
+}
+
+/// Issuer and receiver RUTs of an SII Timbre Electrónico (TED)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TedRuts {
+    /// Issuer RUT, from the `RE` node (`RUTEmisor`)
+    pub emisor: Rut,
+    /// Receiver RUT, from the `RR` node (`RUTRecep`)
+    pub receptor: Rut,
+}
+
+/// Reads the issuer and receiver RUTs out of an SII Timbre Electrónico (TED)
+/// barcode payload.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::barcode::ted_ruts;
+///
+/// let ted = b"<TED version=\"1.0\"><DD><RE>76086428-5</RE><TD>33</TD><F>1</F>\
+///     <RR>17951585-7</RR></DD></TED>";
+/// let ruts = ted_ruts(ted).unwrap();
+///
+/// assert_eq!(ruts.emisor.num(), 76_086_428);
+/// assert_eq!(ruts.receptor.num(), 17_951_585);
+/// ```
+pub fn ted_ruts(payload: &[u8]) -> Result<TedRuts, Error> {
+    let payload = from_utf8(payload).map_err(|_| Error::InvalidFormat)?;
+
+    Ok(TedRuts {
+        emisor: ted_node(payload, "RE")?,
+        receptor: ted_node(payload, "RR")?,
+    })
+}
+
+/// Parses the RUT inside of the first `<node>...</node>` element
+fn ted_node(payload: &str, node: &str) -> Result<Rut, Error> {
+    sii::element(payload, node)
+        .ok_or(Error::InvalidFormat)?
+        .trim()
+        .parse::<Rut>()
+}
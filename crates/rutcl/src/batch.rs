@@ -0,0 +1,105 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::{Error, Format, Rut};
+
+/// Result of parsing a single line with [`Rut::parse_batch`]
+#[derive(Clone, Debug)]
+pub struct BatchLine<'t> {
+    line: usize,
+    offset: usize,
+    input: &'t str,
+    result: Result<Rut, Error>,
+}
+
+impl<'t> BatchLine<'t> {
+    /// 1-based line number
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Trimmed line as written in the batch
+    #[inline]
+    pub fn input(&self) -> &'t str {
+        self.input
+    }
+
+    /// Byte range of the trimmed line in the batch
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.input.len()
+    }
+
+    /// Parsing result for this line
+    #[inline]
+    pub fn result(&self) -> Result<&Rut, &Error> {
+        self.result.as_ref()
+    }
+
+    /// Byte range in the batch the parsing error points to, see
+    /// [`Error::span`]
+    pub fn error_span(&self) -> Option<Range<usize>> {
+        let err = self.result.as_ref().err()?;
+        let span = err.span(self.input);
+
+        Some(self.offset + span.start..self.offset + span.end)
+    }
+
+    /// Writes the parsed [`Rut`] in the provided [`Format`], or the line as
+    /// is if it failed to parse
+    pub fn convert(&self, fmt: Format) -> String {
+        match &self.result {
+            Ok(rut) => rut.format(fmt),
+            Err(_) => self.input.to_string(),
+        }
+    }
+
+    /// Consumes the line, retrieving the parsing result
+    pub fn into_result(self) -> Result<Rut, Error> {
+        self.result
+    }
+}
+
+impl Rut {
+    /// Parses a batch of RUTs written one per line, in any format, skipping
+    /// blank lines.
+    ///
+    /// Every line is trimmed and parsed as with [`Rut::from_str`], keeping
+    /// its position in the batch so errors can be pointed at.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Format, Rut};
+    ///
+    /// let batch = "17.951.585-7\n\n  76086428-0\n";
+    /// let lines = Rut::parse_batch(batch);
+    ///
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0].convert(Format::Dash), "17951585-7");
+    /// assert_eq!(lines[1].line(), 3);
+    /// assert_eq!(&batch[lines[1].error_span().unwrap()], "0");
+    /// ```
+    pub fn parse_batch(input: &str) -> Vec<BatchLine<'_>> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+
+        for (idx, raw) in input.split_inclusive('\n').enumerate() {
+            let trimmed = raw.trim();
+
+            if !trimmed.is_empty() {
+                lines.push(BatchLine {
+                    line: idx + 1,
+                    offset: offset + (raw.len() - raw.trim_start().len()),
+                    input: trimmed,
+                    result: Rut::from_str(trimmed),
+                });
+            }
+
+            offset += raw.len();
+        }
+
+        lines
+    }
+}
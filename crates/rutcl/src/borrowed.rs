@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use crate::{Error, Num, Rut, VerificationDigit};
+
+/// A verified RUT borrowing its input.
+///
+/// Parsing validates the input in place, without allocating, and keeps
+/// a reference to it, so records read from large buffers (e.g. memory
+/// mapped files) can be checked and passed around as written.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutRef};
+///
+/// let buf = "17.951.585-7\n76086428-5\n";
+/// let ruts = buf
+///     .lines()
+///     .map(RutRef::parse)
+///     .collect::<Result<Vec<RutRef>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(ruts[0].as_str(), "17.951.585-7");
+/// assert_eq!(ruts[1].num(), 76_086_428);
+/// assert_eq!(Rut::from(ruts[0]), "179515857".parse::<Rut>().unwrap());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RutRef<'a> {
+    input: &'a str,
+    rut: Rut,
+}
+
+impl<'a> RutRef<'a> {
+    /// Parses and verifies the provided input, as [`Rut::from_str`] does
+    ///
+    /// [`Rut::from_str`]: std::str::FromStr::from_str
+    pub fn parse(input: &'a str) -> Result<Self, Error> {
+        input.parse().map(|rut| RutRef { input, rut })
+    }
+
+    /// Parses and verifies the provided bytes, as [`Rut::from_bytes`] does
+    pub fn parse_bytes(input: &'a [u8]) -> Result<Self, Error> {
+        let rut = Rut::from_bytes(input)?;
+        let input = std::str::from_utf8(input).expect("Valid RUTs are ASCII");
+
+        Ok(RutRef { input, rut })
+    }
+
+    /// Retrieves the input as written
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.input
+    }
+
+    /// Return the RUT's number ([`Num`]) without the [`VerificationDigit`]
+    #[inline]
+    pub fn num(&self) -> Num {
+        self.rut.num()
+    }
+
+    /// Return the DV output
+    #[inline]
+    pub fn vd(&self) -> VerificationDigit {
+        self.rut.vd()
+    }
+
+    /// Retrieves the owned [`Rut`]
+    #[inline]
+    pub fn to_rut(&self) -> Rut {
+        self.rut
+    }
+}
+
+impl From<RutRef<'_>> for Rut {
+    fn from(value: RutRef<'_>) -> Self {
+        value.rut
+    }
+}
+
+impl PartialEq<Rut> for RutRef<'_> {
+    fn eq(&self, other: &Rut) -> bool {
+        self.rut == *other
+    }
+}
+
+impl AsRef<str> for RutRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.input
+    }
+}
+
+/// Writes the input as written
+impl Display for RutRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.input)
+    }
+}
@@ -0,0 +1,38 @@
+use ::bson::Bson;
+
+use crate::{Error, Format, Num, Rut};
+
+/// Stores the [`Rut`] as a [`Format::Sans`] string
+impl From<Rut> for Bson {
+    fn from(rut: Rut) -> Self {
+        Bson::String(rut.format(Format::Sans))
+    }
+}
+
+/// Reads a [`Rut`] from a string in any of the supported formats, or from an
+/// `Int32`/`Int64` holding the RUT's number ([`Num`]) as stored by legacy
+/// documents.
+impl TryFrom<&Bson> for Rut {
+    type Error = Error;
+
+    fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::String(value) => value.parse::<Rut>(),
+            Bson::Int32(num) => Num::try_from(*num)
+                .map_err(|_| Error::OutOfRange)
+                .and_then(Rut::try_from),
+            Bson::Int64(num) => Num::try_from(*num)
+                .map_err(|_| Error::OutOfRange)
+                .and_then(Rut::try_from),
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+}
+
+impl TryFrom<Bson> for Rut {
+    type Error = Error;
+
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        Rut::try_from(&value)
+    }
+}
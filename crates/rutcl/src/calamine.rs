@@ -0,0 +1,220 @@
+//! RUT columns from spreadsheets (XLSX, XLS, XLSB and ODS).
+//!
+//! Spreadsheet applications often turn RUT columns into numbers, dropping
+//! the dots and sometimes the dash, or split the verification digit into
+//! its own column. [`recover`] undoes those transformations where it can
+//! be done unambiguously enough, reporting how each RUT was recovered.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use ::calamine::{open_workbook_auto, Data, Range, Reader};
+
+use crate::{Error, Num, Rut, MAX_NUM, MIN_NUM};
+
+/// How a [`Rut`] was recovered from a spreadsheet cell
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Recovery {
+    /// The cell holds a RUT as text
+    Parsed,
+    /// The cell holds a number made of the RUT's number followed by its
+    /// verification digit, e.g. `179515857`
+    Sans,
+    /// The verification digit was read from its own column
+    SeparateDigit,
+    /// The cell holds the RUT's number only and the verification digit was
+    /// computed from it
+    ComputedDigit,
+}
+
+/// A [`Rut`] recovered from a spreadsheet cell
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Recovered {
+    /// Recovered RUT
+    pub rut: Rut,
+    /// How the RUT was recovered
+    pub recovery: Recovery,
+}
+
+/// Recovery result for each data row of a column, along with its 1-based
+/// row number in the sheet
+pub type Column = Vec<(u32, Result<Recovered, Error>)>;
+
+/// Error reading a RUT column out of a spreadsheet
+#[derive(Debug)]
+pub enum SheetError {
+    /// The spreadsheet couldn't be opened or read
+    Calamine(::calamine::Error),
+    /// The header row has no column with the provided name
+    MissingColumn(String),
+}
+
+impl fmt::Display for SheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SheetError::Calamine(err) => fmt::Display::fmt(err, f),
+            SheetError::MissingColumn(name) => write!(f, "Missing column {name:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SheetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SheetError::Calamine(err) => err.source(),
+            SheetError::MissingColumn(_) => None,
+        }
+    }
+}
+
+impl From<::calamine::Error> for SheetError {
+    fn from(err: ::calamine::Error) -> Self {
+        SheetError::Calamine(err)
+    }
+}
+
+/// Recovers a [`Rut`] from a spreadsheet cell, with the verification digit
+/// read from `vd` when it lives in a separate column.
+///
+/// Without `vd`, text cells are parsed as with [`Rut::from_str`] and whole
+/// numbers are recovered as follows:
+///
+/// - Numbers above `99.999.999` are read as a RUT written without dash,
+///   the last digit being the verification digit.
+/// - Numbers from `1.000.000` to `99.999.999` are read as a RUT's number
+///   whose verification digit was lost, and the digit is computed.
+///
+/// > A RUT with a 7 digit number written without dash, such as
+/// > `1234567-4`, turns into `12345674` which is indistinguishable from a
+/// > RUT's number. Prefer text columns or a separate digit column when
+/// > the data holds such RUTs.
+///
+/// Dates, booleans and fractional numbers fail with
+/// [`Error::InvalidFormat`], empty cells with [`Error::EmptyString`].
+///
+/// # Example
+///
+/// ```
+/// use calamine::Data;
+/// use rutcl::calamine::{recover, Recovery};
+///
+/// let recovered = recover(&Data::Float(92635843.0), None).unwrap();
+///
+/// assert_eq!(recovered.rut.to_string(), "92635843K");
+/// assert_eq!(recovered.recovery, Recovery::ComputedDigit);
+///
+/// let recovered = recover(&Data::Int(92635843), Some(&Data::String("k".into()))).unwrap();
+///
+/// assert_eq!(recovered.recovery, Recovery::SeparateDigit);
+/// assert!(recover(&Data::Int(92635843), Some(&Data::Int(1))).is_err());
+/// ```
+pub fn recover(cell: &Data, vd: Option<&Data>) -> Result<Recovered, Error> {
+    if let Some(vd) = vd.filter(|vd| !matches!(vd, Data::Empty)) {
+        let vd = match vd {
+            Data::String(vd) => vd.trim().to_string(),
+            vd => whole(vd)?.to_string(),
+        };
+        let rut = Rut::from_str(&format!("{}-{vd}", number(cell)?))?;
+
+        return Ok(Recovered {
+            rut,
+            recovery: Recovery::SeparateDigit,
+        });
+    }
+
+    if let Data::String(value) = cell {
+        let rut = Rut::from_str(value)?;
+
+        return Ok(Recovered {
+            rut,
+            recovery: Recovery::Parsed,
+        });
+    }
+
+    let value = whole(cell)?;
+
+    if value > MAX_NUM as u64 {
+        if value / 10 < MIN_NUM as u64 || value / 10 > MAX_NUM as u64 {
+            return Err(Error::OutOfRange);
+        }
+
+        return Ok(Recovered {
+            rut: Rut::from_str(&value.to_string())?,
+            recovery: Recovery::Sans,
+        });
+    }
+
+    Ok(Recovered {
+        rut: Rut::try_from(value as Num)?,
+        recovery: Recovery::ComputedDigit,
+    })
+}
+
+/// Reads the cell as a RUT's number, from a whole number or from text
+/// with optional thousands separators
+fn number(cell: &Data) -> Result<Num, Error> {
+    match cell {
+        Data::String(value) => value.trim().replace('.', "").parse().map_err(Error::NaN),
+        cell => Num::try_from(whole(cell)?).map_err(|_| Error::OutOfRange),
+    }
+}
+
+/// Reads the cell as a non negative whole number
+fn whole(cell: &Data) -> Result<u64, Error> {
+    match cell {
+        Data::Empty => Err(Error::EmptyString),
+        Data::Int(value) => u64::try_from(*value).map_err(|_| Error::OutOfRange),
+        Data::Float(value)
+            if value.fract() == 0.0 && *value >= 0.0 && *value <= u64::MAX as f64 =>
+        {
+            Ok(*value as u64)
+        }
+        _ => Err(Error::InvalidFormat),
+    }
+}
+
+/// Recovers the RUTs in the column with the header `column`, using the
+/// first row of `range` as header.
+///
+/// When `vd_column` is provided, verification digits are read from it, see
+/// [`recover`].
+pub fn from_range(
+    range: &Range<Data>,
+    column: &str,
+    vd_column: Option<&str>,
+) -> Result<Column, SheetError> {
+    let mut rows = range.rows();
+    let headers = rows.next().unwrap_or_default();
+    let position = |name: &str| {
+        headers
+            .iter()
+            .position(|header| matches!(header, Data::String(header) if header.trim() == name))
+            .ok_or_else(|| SheetError::MissingColumn(name.to_string()))
+    };
+    let column = position(column)?;
+    let vd_column = vd_column.map(position).transpose()?;
+    let first = range.start().map_or(0, |(row, _)| row) + 2;
+
+    Ok(rows
+        .zip(first..)
+        .map(|(row, idx)| {
+            let vd = vd_column.map(|vd_column| &row[vd_column]);
+
+            (idx, recover(&row[column], vd))
+        })
+        .collect())
+}
+
+/// Opens the spreadsheet at `path` and recovers the RUTs in the column with
+/// the header `column` of the worksheet named `sheet`, see [`from_range`].
+pub fn read_column<P: AsRef<Path>>(
+    path: P,
+    sheet: &str,
+    column: &str,
+    vd_column: Option<&str>,
+) -> Result<Column, SheetError> {
+    let range = open_workbook_auto(path)?.worksheet_range(sheet)?;
+
+    from_range(&range, column, vd_column)
+}
@@ -0,0 +1,74 @@
+use std::ffi::OsStr;
+
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command};
+
+use crate::Rut;
+
+/// [`TypedValueParser`] for [`Rut`] arguments, accepting any of the
+/// supported formats.
+///
+/// Picked automatically by `clap` for `Rut` fields, so there is no need to
+/// use it directly. On failure, the error includes the reason why the value
+/// is not a valid RUT, such as the expected verification digit.
+///
+/// # Example
+///
+/// ```
+/// use clap::{value_parser, Arg, Command};
+/// use rutcl::Rut;
+///
+/// let cmd = Command::new("payroll").arg(
+///     Arg::new("rut")
+///         .long("rut")
+///         .value_parser(value_parser!(Rut)),
+/// );
+///
+/// let matches = cmd.clone().try_get_matches_from(["payroll", "--rut", "17.951.585-7"]).unwrap();
+/// let err = cmd.try_get_matches_from(["payroll", "--rut", "17.951.585-K"]).unwrap_err();
+///
+/// assert_eq!(matches.get_one::<Rut>("rut").unwrap().to_string(), "179515857");
+/// assert!(err.to_string().contains("have K, want 7"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RutValueParser;
+
+impl TypedValueParser for RutValueParser {
+    type Value = Rut;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, ::clap::Error> {
+        let arg = arg
+            .map(|arg| arg.to_string())
+            .unwrap_or_else(|| String::from("..."));
+
+        let Some(value) = value.to_str() else {
+            return Err(::clap::Error::raw(
+                ErrorKind::InvalidUtf8,
+                format!("invalid UTF-8 was detected in the value for '{arg}'\n"),
+            )
+            .format(&mut cmd.clone()));
+        };
+
+        value.parse::<Rut>().map_err(|err| {
+            ::clap::Error::raw(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{arg}': {err}\n"),
+            )
+            .format(&mut cmd.clone())
+        })
+    }
+}
+
+impl ValueParserFactory for Rut {
+    type Parser = RutValueParser;
+
+    fn value_parser() -> Self::Parser {
+        RutValueParser
+    }
+}
@@ -0,0 +1,151 @@
+//! Generates RUT validation logic for other systems.
+//!
+//! The snippets are built from the same constants this crate validates RUTs
+//! with, so database constraints, frontends and other services stay
+//! consistent with it. Generated validators accept the same inputs as
+//! [`Rut::parse_sanitized`] with [`SanitizeOptions::STRICT`]: ASCII dots and
+//! dashes are stripped, the `K` digit may be lowercase.
+//!
+//! [`Rut::parse_sanitized`]: crate::Rut::parse_sanitized
+//! [`SanitizeOptions::STRICT`]: crate::SanitizeOptions::STRICT
+
+use crate::{Format, FACTOR, MAX_NUM, MIN_NUM, SYMBOLS};
+
+/// Name of the SQL function generated by [`plpgsql`]
+pub const SQL_FUNCTION: &str = "rut_is_valid";
+
+/// Name of the JavaScript function generated by [`javascript`]
+pub const JS_FUNCTION: &str = "isValidRut";
+
+/// Retrieves a regular expression matching RUTs written exactly in the
+/// provided [`Format`].
+///
+/// The expression checks the shape and the amount of digits, pair it with
+/// the checksum of [`javascript`] or [`plpgsql`] to validate the RUT. It
+/// uses a syntax common to most engines (PCRE, JavaScript, POSIX ERE,
+/// Rust's `regex`).
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{codegen, Format};
+///
+/// assert_eq!(codegen::regex(Format::Dash), "^[1-9][0-9]{6,7}-[0-9Kk]$");
+/// ```
+pub fn regex(fmt: Format) -> String {
+    let (min, max) = (digits(MIN_NUM), digits(MAX_NUM));
+
+    match fmt {
+        Format::Sans => format!("^[1-9][0-9]{{{},{}}}[0-9Kk]$", min - 1, max - 1),
+        Format::Dash => format!("^[1-9][0-9]{{{},{}}}-[0-9Kk]$", min - 1, max - 1),
+        Format::Dots => {
+            let groups = (min - 1) / 3;
+
+            format!(
+                "^[1-9][0-9]{{{},{}}}(\\.[0-9]{{3}}){{{groups}}}-[0-9Kk]$",
+                min - 1 - groups * 3,
+                max - 1 - groups * 3,
+            )
+        }
+    }
+}
+
+/// Retrieves a JavaScript function named [`JS_FUNCTION`] which takes a
+/// string and returns whether it is a valid RUT.
+pub fn javascript() -> String {
+    format!(
+        r#"function {JS_FUNCTION}(input) {{
+  const clean = String(input).replace(/[.-]/g, "").toUpperCase();
+
+  if (!/^[0-9]+[0-9K]$/.test(clean)) {{
+    return false;
+  }}
+
+  const factors = {factors:?};
+  let num = Number(clean.slice(0, -1));
+  let sum = 0;
+
+  if (num < {MIN_NUM} || num > {MAX_NUM}) {{
+    return false;
+  }}
+
+  for (let i = 0; num > 0; i++) {{
+    sum += (num % 10) * factors[i % factors.length];
+    num = Math.floor(num / 10);
+  }}
+
+  const digit = {SYMBOLS} - (sum % {SYMBOLS});
+  const want = digit === {SYMBOLS} ? "0" : digit === 10 ? "K" : String(digit);
+
+  return clean.slice(-1) === want;
+}}
+"#,
+        factors = FACTOR,
+    )
+}
+
+/// Retrieves a PL/pgSQL function named [`SQL_FUNCTION`] which takes a
+/// `text` and returns whether it is a valid RUT.
+///
+/// The function is `IMMUTABLE`, so it can back [`check_constraint`]s and
+/// indexes, and `STRICT`, returning `NULL` for `NULL` inputs.
+pub fn plpgsql() -> String {
+    let factors = FACTOR.map(|factor| factor.to_string()).join(", ");
+
+    format!(
+        r#"CREATE OR REPLACE FUNCTION {SQL_FUNCTION}(input text) RETURNS boolean AS $$
+DECLARE
+    clean text := upper(translate(input, '.-', ''));
+    factors int[] := ARRAY[{factors}];
+    num numeric;
+    total int := 0;
+    i int := 0;
+    digit int;
+BEGIN
+    IF clean !~ '^[0-9]+[0-9K]$' THEN
+        RETURN false;
+    END IF;
+
+    num := left(clean, -1)::numeric;
+
+    IF num < {MIN_NUM} OR num > {MAX_NUM} THEN
+        RETURN false;
+    END IF;
+
+    WHILE num > 0 LOOP
+        total := total + (num % 10)::int * factors[i % {len} + 1];
+        num := trunc(num / 10);
+        i := i + 1;
+    END LOOP;
+
+    digit := {SYMBOLS} - total % {SYMBOLS};
+
+    RETURN right(clean, 1) = CASE digit WHEN {SYMBOLS} THEN '0' WHEN 10 THEN 'K' ELSE digit::text END;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE STRICT;
+"#,
+        len = FACTOR.len(),
+    )
+}
+
+/// Retrieves a `CHECK` constraint validating the provided column with the
+/// function generated by [`plpgsql`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::codegen;
+///
+/// assert_eq!(
+///     codegen::check_constraint("customers_rut_valid", "rut"),
+///     "CONSTRAINT customers_rut_valid CHECK (rut_is_valid(rut))"
+/// );
+/// ```
+pub fn check_constraint(name: &str, column: &str) -> String {
+    format!("CONSTRAINT {name} CHECK ({SQL_FUNCTION}({column}))")
+}
+
+/// Amount of decimal digits in `num`
+fn digits(num: u32) -> u32 {
+    num.checked_ilog10().unwrap_or_default() + 1
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::{split_chars, Error, Rut, SanitizeOptions};
+
+/// A [`Rut`] which was parsed correcting a wrong verification digit.
+///
+/// Parsing (and deserializing, with the `serde` feature) accepts any input
+/// [`Rut::from_str`] accepts, plus inputs whose number is a valid RUT number
+/// but whose verification digit is wrong or not a digit at all. Such inputs
+/// are corrected to the RUT with the right digit and flagged, so consumers
+/// of dirty data can keep processing records while reporting the bad ones.
+///
+/// Inputs with an invalid number fail as with [`Rut::from_str`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::CorrectingRut;
+///
+/// let clean: CorrectingRut = "17.951.585-7".parse().unwrap();
+/// let dirty: CorrectingRut = "17.951.585-8".parse().unwrap();
+///
+/// assert!(!clean.was_corrected());
+/// assert!(dirty.was_corrected());
+/// assert_eq!(dirty.original_digit(), Some('8'));
+/// assert_eq!(dirty.into_inner(), clean.into_inner());
+/// assert!("17.951.X85-7".parse::<CorrectingRut>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CorrectingRut {
+    rut: Rut,
+    original: Option<char>,
+}
+
+impl CorrectingRut {
+    /// Checks whether the verification digit was corrected
+    #[inline]
+    pub fn was_corrected(&self) -> bool {
+        self.original.is_some()
+    }
+
+    /// Retrieves the wrong verification digit found in the input, if it was
+    /// corrected
+    #[inline]
+    pub fn original_digit(&self) -> Option<char> {
+        self.original
+    }
+
+    /// Retrieves the (corrected) [`Rut`]
+    #[inline]
+    pub fn into_inner(self) -> Rut {
+        self.rut
+    }
+}
+
+impl FromStr for CorrectingRut {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match Rut::from_str(input) {
+            Ok(rut) => Ok(CorrectingRut {
+                rut,
+                original: None,
+            }),
+            Err(
+                Error::InvalidVerificationDigit { .. } | Error::VerificationDigitOutOfBounds(_),
+            ) => {
+                let options = SanitizeOptions::default();
+                let (num, original) = split_chars(options.trim(input).chars(), options)?;
+
+                Ok(CorrectingRut {
+                    rut: Rut::try_from(num)?,
+                    original: Some(original),
+                })
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl From<Rut> for CorrectingRut {
+    fn from(rut: Rut) -> Self {
+        CorrectingRut {
+            rut,
+            original: None,
+        }
+    }
+}
+
+impl From<CorrectingRut> for Rut {
+    fn from(value: CorrectingRut) -> Self {
+        value.rut
+    }
+}
+
+impl Deref for CorrectingRut {
+    type Target = Rut;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rut
+    }
+}
+
+impl AsRef<Rut> for CorrectingRut {
+    fn as_ref(&self) -> &Rut {
+        &self.rut
+    }
+}
+
+impl Display for CorrectingRut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.rut, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::CorrectingRut;
+    use crate::Rut;
+
+    /// Serializes the corrected [`Rut`]
+    impl Serialize for CorrectingRut {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.rut.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CorrectingRut {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if cfg!(feature = "serde-always-str") || deserializer.is_human_readable() {
+                return deserializer.deserialize_str(CorrectingRutVisitor);
+            }
+
+            Rut::deserialize(deserializer).map(CorrectingRut::from)
+        }
+    }
+
+    struct CorrectingRutVisitor;
+
+    impl<'de> Visitor<'de> for CorrectingRutVisitor {
+        type Value = CorrectingRut;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a Rut String instance, possibly with a wrong verification digit")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            CorrectingRut::from_str(v).map_err(|err| E::custom(err.to_string()))
+        }
+    }
+}
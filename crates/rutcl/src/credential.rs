@@ -0,0 +1,171 @@
+//! Helpers for RUTs embedded in credential payloads.
+//!
+//! # vCard
+//!
+//! Contact cards carry the RUT in an [`X_RUT`] extension property, written
+//! in [`Format::Dash`] (e.g. `X-RUT:17951585-7`). Property names are case
+//! insensitive and may carry a group prefix (`item1.X-RUT`) or parameters
+//! (`X-RUT;TYPE=RUN`), long lines may be folded as per RFC 6350.
+//!
+//! # URLs
+//!
+//! Identity providers and the cédula QR code pass the RUN in a URL query
+//! parameter, [`RUN_PARAM`] (e.g. `...?RUN=17951585-7&type=CEDULA`). `rut`
+//! is accepted as well when reading, parameter names are case insensitive
+//! and values may be percent-encoded.
+
+use crate::{Error, Format, Rut};
+
+/// vCard extension property holding the RUT
+pub const X_RUT: &str = "X-RUT";
+
+/// URL query parameter holding the RUN
+pub const RUN_PARAM: &str = "RUN";
+
+/// Query parameters holding the RUT accepted by [`Rut::from_url`]
+const URL_PARAMS: [&str; 2] = [RUN_PARAM, "rut"];
+
+impl Rut {
+    /// Builds the vCard [`X_RUT`] property line, without line terminator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert_eq!(rut.to_vcard_line(), "X-RUT:17951585-7");
+    /// ```
+    pub fn to_vcard_line(&self) -> String {
+        format!("{X_RUT}:{}", self.format(Format::Dash))
+    }
+
+    /// Reads the [`Rut`] out of the first [`X_RUT`] property of a vCard.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the vCard has no such property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let vcard = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Juan Pérez\r\n\
+    ///     item1.x-rut;TYPE=RUN:17.951.\r\n 585-7\r\nEND:VCARD\r\n";
+    ///
+    /// assert_eq!(Rut::from_vcard(vcard).unwrap().num(), 17_951_585);
+    /// assert!(Rut::from_vcard("BEGIN:VCARD\r\nEND:VCARD\r\n").is_err());
+    /// ```
+    pub fn from_vcard(vcard: &str) -> Result<Self, Error> {
+        unfold(vcard)
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                let name = name.split(';').next()?;
+                let name = name.rsplit('.').next()?;
+
+                name.eq_ignore_ascii_case(X_RUT)
+                    .then_some(value.trim().to_string())
+            })
+            .ok_or(Error::InvalidFormat)?
+            .parse()
+    }
+
+    /// Appends the [`RUN_PARAM`] query parameter to the provided URL
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     rut.to_url("https://example.com/verify"),
+    ///     "https://example.com/verify?RUN=17951585-7"
+    /// );
+    /// assert_eq!(
+    ///     rut.to_url("https://example.com/verify?type=CEDULA"),
+    ///     "https://example.com/verify?type=CEDULA&RUN=17951585-7"
+    /// );
+    /// ```
+    pub fn to_url(&self, base: &str) -> String {
+        let sep = if base.contains('?') { '&' } else { '?' };
+
+        format!("{base}{sep}{RUN_PARAM}={}", self.format(Format::Dash))
+    }
+
+    /// Reads the [`Rut`] out of the `RUN` or `rut` query parameter of a URL.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the URL has no such parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let qr = "https://portal.sidiv.registrocivil.cl/docstatus?RUN=17951585-7&type=CEDULA";
+    /// let callback = "myapp://login?rut=17.951.585%2D7#done";
+    ///
+    /// assert_eq!(Rut::from_url(qr).unwrap().num(), 17_951_585);
+    /// assert_eq!(Rut::from_url(callback).unwrap().num(), 17_951_585);
+    /// assert!(Rut::from_url("https://example.com/?user=1").is_err());
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self, Error> {
+        let query = url.split_once('?').ok_or(Error::InvalidFormat)?.1;
+        let query = query.split('#').next().unwrap_or_default();
+
+        query
+            .split('&')
+            .find_map(|param| {
+                let (name, value) = param.split_once('=')?;
+
+                URL_PARAMS
+                    .iter()
+                    .any(|param| name.eq_ignore_ascii_case(param))
+                    .then_some(value)
+            })
+            .ok_or(Error::InvalidFormat)
+            .and_then(percent_decode)?
+            .parse()
+    }
+}
+
+/// Iterates over the logical lines of a vCard, joining folded lines
+fn unfold(vcard: &str) -> impl Iterator<Item = String> + '_ {
+    let mut lines = vcard.lines().peekable();
+
+    std::iter::from_fn(move || {
+        let mut line = lines.next()?.to_string();
+
+        while let Some(next) = lines.next_if(|next| next.starts_with([' ', '\t'])) {
+            line.push_str(&next[1..]);
+        }
+
+        Some(line)
+    })
+}
+
+/// Decodes a percent-encoded query parameter value
+fn percent_decode(value: &str) -> Result<String, Error> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+
+    while let Some((byte, tail)) = rest.split_first() {
+        match byte {
+            b'%' => {
+                let hex = tail.get(..2).ok_or(Error::InvalidFormat)?;
+                let hex = std::str::from_utf8(hex).map_err(|_| Error::InvalidFormat)?;
+
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| Error::InvalidFormat)?);
+                rest = &tail[2..];
+                continue;
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(*byte),
+        }
+
+        rest = tail;
+    }
+
+    String::from_utf8(bytes).map_err(|_| Error::InvalidFormat)
+}
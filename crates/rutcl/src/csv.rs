@@ -0,0 +1,191 @@
+//! RUT cleaning for CSV files.
+
+use std::io::{self, Read, Write};
+
+use ::csv::{Reader, StringRecord, Writer};
+
+use crate::{Format, Rut, ValidationPolicy};
+
+/// What to do with rows holding an invalid RUT, see [`CleanOptions`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OnInvalid {
+    /// Writes the row leaving the invalid values untouched
+    Keep,
+    /// Leaves the row out of the output
+    Drop,
+    /// Writes the row appending a column with the provided name, holding
+    /// `true` when every RUT in the row is valid and `false` otherwise
+    Flag(String),
+}
+
+/// Options for [`clean`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CleanOptions {
+    /// Names of the columns holding RUTs, as written in the header
+    pub columns: Vec<String>,
+    /// [`Format`] valid RUTs are written in
+    pub format: Format,
+    /// [`ValidationPolicy`] used to parse RUTs
+    pub policy: ValidationPolicy,
+    /// What to do with rows holding an invalid RUT
+    pub on_invalid: OnInvalid,
+}
+
+impl CleanOptions {
+    /// Creates [`CleanOptions`] normalizing the provided columns to
+    /// [`Format::Dots`] and keeping invalid rows
+    pub fn new<I, S>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        CleanOptions {
+            columns: columns.into_iter().map(Into::into).collect(),
+            format: Format::Dots,
+            policy: ValidationPolicy::PERMISSIVE,
+            on_invalid: OnInvalid::Keep,
+        }
+    }
+
+    /// Sets the [`Format`] valid RUTs are written in
+    pub fn format(mut self, fmt: Format) -> Self {
+        self.format = fmt;
+        self
+    }
+
+    /// Sets the [`ValidationPolicy`] used to parse RUTs
+    pub fn policy(mut self, policy: ValidationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets what to do with rows holding an invalid RUT
+    pub fn on_invalid(mut self, on_invalid: OnInvalid) -> Self {
+        self.on_invalid = on_invalid;
+        self
+    }
+}
+
+/// Summary of a [`clean`] run
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CleanReport {
+    /// Rows read, not counting the header
+    pub rows_in: u64,
+    /// Rows written, not counting the header
+    pub rows_out: u64,
+    /// Valid RUTs rewritten because they were not in the target format
+    pub fixed: u64,
+    /// Invalid RUTs found, empty values included
+    pub invalid: u64,
+    /// Rows holding at least one invalid RUT
+    pub invalid_rows: u64,
+}
+
+/// Reads a CSV with headers from `reader`, normalizes the RUT columns
+/// selected in [`CleanOptions`] and writes the result to `writer`.
+///
+/// Fails if the CSV can't be read or written, or if any of the selected
+/// columns is missing from the header.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::csv::{clean, CleanOptions, OnInvalid};
+/// use rutcl::Format;
+///
+/// let input = "name,rut\nAna,179515857\nBeto,17.951.585-0\n";
+/// let mut output = Vec::new();
+/// let options = CleanOptions::new(["rut"])
+///     .format(Format::Dash)
+///     .on_invalid(OnInvalid::Drop);
+/// let report = clean(input.as_bytes(), &mut output, &options).unwrap();
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "name,rut\nAna,17951585-7\n");
+/// assert_eq!((report.rows_in, report.rows_out, report.fixed), (2, 1, 1));
+/// ```
+pub fn clean<R, W>(
+    reader: R,
+    writer: W,
+    options: &CleanOptions,
+) -> Result<CleanReport, ::csv::Error>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = Reader::from_reader(reader);
+    let mut writer = Writer::from_writer(writer);
+    let mut headers = reader.headers()?.clone();
+    let columns = options
+        .columns
+        .iter()
+        .map(|column| {
+            headers
+                .iter()
+                .position(|header| header == column)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing RUT column {column:?}"),
+                    )
+                })
+        })
+        .collect::<Result<Vec<usize>, io::Error>>()?;
+
+    if let OnInvalid::Flag(name) = &options.on_invalid {
+        headers.push_field(name);
+    }
+
+    writer.write_record(&headers)?;
+
+    let mut report = CleanReport::default();
+    let mut row = StringRecord::new();
+    let mut out = StringRecord::new();
+
+    while reader.read_record(&mut row)? {
+        let mut valid = true;
+
+        report.rows_in += 1;
+        out.clear();
+
+        for (idx, field) in row.iter().enumerate() {
+            if !columns.contains(&idx) {
+                out.push_field(field);
+                continue;
+            }
+
+            match Rut::from_str_with(field, options.policy) {
+                Ok(rut) => {
+                    let formatted = rut.format(options.format);
+
+                    if formatted != field {
+                        report.fixed += 1;
+                    }
+
+                    out.push_field(&formatted);
+                }
+                Err(_) => {
+                    valid = false;
+                    report.invalid += 1;
+                    out.push_field(field);
+                }
+            }
+        }
+
+        if !valid {
+            report.invalid_rows += 1;
+        }
+
+        match &options.on_invalid {
+            OnInvalid::Drop if !valid => continue,
+            OnInvalid::Flag(_) => out.push_field(if valid { "true" } else { "false" }),
+            _ => {}
+        }
+
+        writer.write_record(&out)?;
+        report.rows_out += 1;
+    }
+
+    writer.flush()?;
+
+    Ok(report)
+}
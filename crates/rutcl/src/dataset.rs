@@ -0,0 +1,338 @@
+//! Fake RUT datasets with controlled dirtiness, for testing cleansing
+//! pipelines.
+
+use std::io::{self, Write};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::{Error, Format, Rut, RutBand, VerificationDigit};
+
+/// Kind of taxpayer a [`Record`] stands for
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RecordKind {
+    /// A RUT in the [`RutBand::Persons`] band
+    Person,
+    /// A RUT in the [`RutBand::Companies`] band
+    Company,
+}
+
+impl RecordKind {
+    fn name(&self) -> &'static str {
+        match self {
+            RecordKind::Person => "person",
+            RecordKind::Company => "company",
+        }
+    }
+}
+
+/// Dirtiness injected into a [`Record`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Defect {
+    /// The record repeats the RUT of an earlier record of the same kind
+    Collision,
+    /// The verification digit doesn't match the RUT's number
+    WrongVerificationDigit,
+    /// The value is not written in any RUT format
+    Malformed,
+}
+
+impl Defect {
+    fn name(&self) -> &'static str {
+        match self {
+            Defect::Collision => "collision",
+            Defect::WrongVerificationDigit => "wrong_verification_digit",
+            Defect::Malformed => "malformed",
+        }
+    }
+}
+
+/// Single row of a [`RutDataset`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Kind of taxpayer
+    pub kind: RecordKind,
+    /// RUT as written in the dataset, possibly invalid
+    pub value: String,
+    /// RUT the value was generated from, before any defect was injected
+    pub rut: Rut,
+    /// Dirtiness injected into this record, if any
+    pub defect: Option<Defect>,
+}
+
+impl Record {
+    /// Checks whether [`Record::value`] is expected to parse back into
+    /// [`Record::rut`]
+    pub fn is_valid(&self) -> bool {
+        !matches!(
+            self.defect,
+            Some(Defect::WrongVerificationDigit | Defect::Malformed)
+        )
+    }
+}
+
+/// Fake dataset of person and company RUTs, created with
+/// [`RutDataset::builder`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::dataset::RutDataset;
+///
+/// let builder = RutDataset::builder()
+///     .persons(80)
+///     .companies(20)
+///     .invalid_rate(0.1)
+///     .collision_rate(0.05)
+///     .seed(42);
+/// let dataset = builder.clone().build().unwrap();
+///
+/// assert_eq!(dataset.records().len(), 100);
+/// assert_eq!(dataset, builder.build().unwrap());
+///
+/// let mut csv = Vec::new();
+///
+/// dataset.write_csv(&mut csv).unwrap();
+/// assert!(csv.starts_with(b"kind,rut,valid,defect\n"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RutDataset {
+    records: Vec<Record>,
+}
+
+impl RutDataset {
+    /// Creates a [`RutDatasetBuilder`] for an empty dataset
+    pub fn builder() -> RutDatasetBuilder {
+        RutDatasetBuilder::default()
+    }
+
+    /// Retrieves the records in this dataset
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Consumes the dataset, retrieving its records
+    pub fn into_records(self) -> Vec<Record> {
+        self.records
+    }
+
+    /// Writes the dataset as CSV with a `kind,rut,valid,defect` header
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "kind,rut,valid,defect")?;
+
+        for record in &self.records {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                record.kind.name(),
+                record.value,
+                record.is_valid(),
+                record
+                    .defect
+                    .map(|defect| defect.name())
+                    .unwrap_or_default()
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the dataset as a JSON array of objects with `kind`, `rut`,
+    /// `valid` and `defect` (possibly `null`) keys
+    pub fn write_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "[")?;
+
+        for (idx, record) in self.records.iter().enumerate() {
+            if idx > 0 {
+                write!(writer, ",")?;
+            }
+
+            write!(
+                writer,
+                r#"{{"kind":"{}","rut":"{}","valid":{},"defect":"#,
+                record.kind.name(),
+                record.value,
+                record.is_valid()
+            )?;
+
+            match record.defect {
+                Some(defect) => write!(writer, r#""{}"}}"#, defect.name())?,
+                None => write!(writer, "null}}")?,
+            }
+        }
+
+        write!(writer, "]")
+    }
+}
+
+/// Builder for a [`RutDataset`], created with [`RutDataset::builder`]
+#[derive(Clone, Debug)]
+pub struct RutDatasetBuilder {
+    persons: usize,
+    companies: usize,
+    collision_rate: f64,
+    invalid_rate: f64,
+    format: Format,
+    seed: Option<u64>,
+}
+
+impl Default for RutDatasetBuilder {
+    fn default() -> Self {
+        RutDatasetBuilder {
+            persons: 0,
+            companies: 0,
+            collision_rate: 0.0,
+            invalid_rate: 0.0,
+            format: Format::Dots,
+            seed: None,
+        }
+    }
+}
+
+impl RutDatasetBuilder {
+    /// Amount of person records
+    pub fn persons(mut self, n: usize) -> Self {
+        self.persons = n;
+        self
+    }
+
+    /// Amount of company records
+    pub fn companies(mut self, n: usize) -> Self {
+        self.companies = n;
+        self
+    }
+
+    /// Share of records, from `0.0` to `1.0`, repeating the RUT of an
+    /// earlier record of the same kind.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not between `0.0` and `1.0`.
+    pub fn collision_rate(mut self, rate: f64) -> Self {
+        assert!((0.0..=1.0).contains(&rate), "rate must be in 0.0..=1.0");
+        self.collision_rate = rate;
+        self
+    }
+
+    /// Share of records, from `0.0` to `1.0`, holding an invalid RUT.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not between `0.0` and `1.0`.
+    pub fn invalid_rate(mut self, rate: f64) -> Self {
+        assert!((0.0..=1.0).contains(&rate), "rate must be in 0.0..=1.0");
+        self.invalid_rate = rate;
+        self
+    }
+
+    /// [`Format`] valid RUTs are written in, defaults to [`Format::Dots`]
+    pub fn format(mut self, fmt: Format) -> Self {
+        self.format = fmt;
+        self
+    }
+
+    /// Seeds the generator, so the same builder always yields the same
+    /// dataset
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Generates the [`RutDataset`].
+    ///
+    /// Fails with [`Error::OutOfRange`] if a band doesn't hold enough
+    /// distinct RUTs for the requested amount of records.
+    pub fn build(self) -> Result<RutDataset, Error> {
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let persons = Rut::random_many_distinct_in_range_with(
+            &mut rng,
+            RutBand::Persons.range(),
+            self.persons,
+        )?;
+        let companies = Rut::random_many_distinct_in_range_with(
+            &mut rng,
+            RutBand::Companies.range(),
+            self.companies,
+        )?;
+        let mut records = persons
+            .into_iter()
+            .map(|rut| (RecordKind::Person, rut))
+            .chain(companies.into_iter().map(|rut| (RecordKind::Company, rut)))
+            .map(|(kind, rut)| Record {
+                kind,
+                value: rut.format(self.format),
+                rut,
+                defect: None,
+            })
+            .collect::<Vec<Record>>();
+
+        records.shuffle(&mut rng);
+
+        // Clean RUTs generated so far, by kind, to draw collisions from
+        let mut clean_persons = Vec::<Rut>::new();
+        let mut clean_companies = Vec::<Rut>::new();
+
+        for record in records.iter_mut() {
+            let clean = match record.kind {
+                RecordKind::Person => &mut clean_persons,
+                RecordKind::Company => &mut clean_companies,
+            };
+
+            if rng.gen_bool(self.collision_rate) {
+                if let Some(&rut) = clean.choose(&mut rng) {
+                    record.value = rut.format(self.format);
+                    record.rut = rut;
+                    record.defect = Some(Defect::Collision);
+                    continue;
+                }
+            }
+
+            if rng.gen_bool(self.invalid_rate) {
+                let (value, defect) = corrupt(&mut rng, record.rut, self.format);
+
+                record.value = value;
+                record.defect = Some(defect);
+                continue;
+            }
+
+            clean.push(record.rut);
+        }
+
+        Ok(RutDataset { records })
+    }
+}
+
+/// Writes `rut` with either a wrong verification digit or a malformed
+/// layout
+fn corrupt(rng: &mut StdRng, rut: Rut, fmt: Format) -> (String, Defect) {
+    let value = rut.format(fmt);
+
+    if rng.gen_bool(0.5) {
+        let wrong = VerificationDigit::ALL
+            .into_iter()
+            .filter(|vd| *vd != rut.vd())
+            .collect::<Vec<_>>();
+        let mut value = value;
+
+        value.pop();
+        value.push((*wrong.choose(rng).unwrap()).into());
+
+        return (value, Defect::WrongVerificationDigit);
+    }
+
+    let value = match rng.gen_range(0..3) {
+        0 => value.replacen(|c: char| c.is_ascii_digit(), "O", 1),
+        1 => value[..value.len() / 2].to_string(),
+        _ => format!(
+            "{}X{}",
+            &value[..value.len() / 2],
+            &value[value.len() / 2..]
+        ),
+    };
+
+    (value, Defect::Malformed)
+}
@@ -0,0 +1,152 @@
+//! Codecs for storing RUTs in database columns.
+//!
+//! # Recommendations
+//!
+//! - Prefer an `INTEGER` column holding the RUT's number ([`Codec::to_int`]).
+//!   It is compact, sorts naturally and can't hold two spellings of the same
+//!   RUT. The verification digit is derived from the number, compute it when
+//!   reading instead of storing it.
+//! - When a `TEXT` column is required (e.g. to match the SII or another
+//!   system), store a single canonical [`Format`], [`Format::Dash`] with an
+//!   uppercase `K` being the most widely understood, and add a unique index
+//!   over it.
+//! - Before migrating legacy columns, run [`verify`] over the stored values
+//!   to find the rows which are invalid or won't round-trip to the canonical
+//!   representation.
+
+use crate::{Error, Format, Num, Rut, SanitizeOptions};
+
+/// Conversions between [`Rut`] and database column values
+pub trait Codec: Sized {
+    /// Encodes as text using the provided [`Format`]
+    fn to_text(&self, fmt: Format) -> String;
+
+    /// Encodes as the RUT's number, fits in a 32 bits `INTEGER` column
+    fn to_int(&self) -> i32;
+
+    /// Decodes text written in any [`Format`], tolerating surrounding
+    /// whitespace, copy-pasted separators and a lowercase `k`
+    fn from_text_any(value: &str) -> Result<Self, Error>;
+
+    /// Decodes the RUT's number, as stored by [`Codec::to_int`]
+    fn from_int(value: i64) -> Result<Self, Error>;
+}
+
+impl Codec for Rut {
+    fn to_text(&self, fmt: Format) -> String {
+        self.format(fmt)
+    }
+
+    fn to_int(&self) -> i32 {
+        self.num() as i32
+    }
+
+    fn from_text_any(value: &str) -> Result<Self, Error> {
+        Rut::parse_sanitized(value, SanitizeOptions::LENIENT)
+    }
+
+    fn from_int(value: i64) -> Result<Self, Error> {
+        let num = Num::try_from(value).map_err(|_| Error::OutOfRange)?;
+
+        Rut::try_from(num)
+    }
+}
+
+/// A value read from a RUT column
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stored<'a> {
+    /// Value of a `TEXT` column
+    Text(&'a str),
+    /// Value of an `INTEGER` column
+    Int(i64),
+}
+
+impl<'a> From<&'a str> for Stored<'a> {
+    fn from(value: &'a str) -> Self {
+        Stored::Text(value)
+    }
+}
+
+impl From<i64> for Stored<'_> {
+    fn from(value: i64) -> Self {
+        Stored::Int(value)
+    }
+}
+
+impl From<i32> for Stored<'_> {
+    fn from(value: i32) -> Self {
+        Stored::Int(value.into())
+    }
+}
+
+/// Why a stored value won't round-trip
+#[derive(Clone, Debug)]
+pub enum Problem {
+    /// The value is not a valid RUT
+    Invalid(Error),
+    /// The value is a valid RUT, but is stored differently than its
+    /// canonical representation
+    NotCanonical { canonical: String },
+}
+
+/// A stored value which won't round-trip, reported by [`verify`]
+#[derive(Clone, Debug)]
+pub struct Issue<'a> {
+    /// Zero based position of the value in the scanned values
+    pub row: usize,
+    /// Value as stored
+    pub value: Stored<'a>,
+    /// Why the value won't round-trip
+    pub problem: Problem,
+}
+
+/// Scans stored values reporting the ones which are not valid RUTs or
+/// differ from their canonical representation: [`Codec::to_text`] with the
+/// provided [`Format`] for text values and [`Codec::to_int`] for integers.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::db::{verify, Problem, Stored};
+/// use rutcl::Format;
+///
+/// let stored: Vec<Stored> = vec!["17951585-7".into(), " 17.951.585-7".into(), 179515857i64.into()];
+/// let issues = verify(stored, Format::Dash);
+///
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].row, 1);
+/// assert!(matches!(&issues[0].problem, Problem::NotCanonical { canonical } if canonical == "17951585-7"));
+/// assert!(matches!(issues[1].problem, Problem::Invalid(_)));
+/// ```
+pub fn verify<'a, I, V>(values: I, fmt: Format) -> Vec<Issue<'a>>
+where
+    I: IntoIterator<Item = V>,
+    V: Into<Stored<'a>>,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .filter_map(|(row, value)| {
+            let value = value.into();
+            let problem = match value {
+                Stored::Text(text) => match Rut::from_text_any(text) {
+                    Ok(rut) if rut.to_text(fmt) == text => return None,
+                    Ok(rut) => Problem::NotCanonical {
+                        canonical: rut.to_text(fmt),
+                    },
+                    Err(err) => Problem::Invalid(err),
+                },
+                Stored::Int(int) => match Rut::from_int(int) {
+                    Ok(_) => return None,
+                    Err(err) => Problem::Invalid(err),
+                },
+            };
+
+            Some(Issue {
+                row,
+                value,
+                problem,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+use crate::{Format, Rut, SanitizeOptions};
+
+/// Char used to mark an unknown digit on [`similarity`] inputs
+const WILDCARD: char = '?';
+
+impl Rut {
+    /// Retrieves the absolute difference between the numbers ([`Num`]) of
+    /// two RUTs.
+    ///
+    /// [`Num`]: crate::Num
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let a: Rut = "17.951.585-7".parse().unwrap();
+    /// let b: Rut = "17.951.558-K".parse().unwrap();
+    ///
+    /// assert_eq!(a.abs_diff(&b), 27);
+    /// assert_eq!(b.abs_diff(&a), 27);
+    /// ```
+    #[inline]
+    pub fn abs_diff(&self, other: &Rut) -> u32 {
+        self.0.abs_diff(other.0)
+    }
+}
+
+/// Scores how likely it is that the provided input refers to the provided
+/// [`Rut`], from `0.0` (unrelated) to `1.0` (same RUT).
+///
+/// The input doesn't need to be a valid RUT. Separators are stripped with the
+/// default [`SanitizeOptions`] and a `?` matches any digit, so partially
+/// known values such as `17951558-?` can be scored.
+///
+/// The score is based on the [Damerau-Levenshtein][1] distance (optimal
+/// string alignment) between the input and the [`Format::Sans`]
+/// representation of the RUT, so a single-digit typo or a transposition of
+/// two adjacent digits count as one edit each.
+///
+/// [1]: https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{similarity, Rut};
+///
+/// let rut: Rut = "17.951.585-7".parse().unwrap();
+///
+/// assert_eq!(similarity("17.951.585-7", &rut), 1.0);
+/// assert!(similarity("17951558-?", &rut) > 0.8);
+/// assert!(similarity("9.876.543-3", &rut) < 0.5);
+/// ```
+pub fn similarity(input: &str, rut: &Rut) -> f32 {
+    let have = Rut::sans_cow_with(input, SanitizeOptions::default())
+        .to_uppercase()
+        .chars()
+        .collect::<Vec<char>>();
+    let want = rut.format(Format::Sans).chars().collect::<Vec<char>>();
+    let len = have.len().max(want.len());
+
+    1.0 - distance(&have, &want) as f32 / len as f32
+}
+
+/// Optimal string alignment distance between two sequences of chars, where
+/// [`WILDCARD`] on `have` matches any char on `want`.
+fn distance(have: &[char], want: &[char]) -> usize {
+    let eq = |a: char, b: char| a == b || a == WILDCARD;
+    let mut rows = vec![vec![0; want.len() + 1]; have.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=have.len() {
+        for j in 1..=want.len() {
+            let cost = usize::from(!eq(have[i - 1], want[j - 1]));
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && eq(have[i - 1], want[j - 2]) && eq(have[i - 2], want[j - 1]) {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+
+            rows[i][j] = best;
+        }
+    }
+
+    rows[have.len()][want.len()]
+}
@@ -0,0 +1,49 @@
+//! Logging support for embedded targets, writing RUTs without going through
+//! `core::fmt`.
+
+use crate::ascii::MAX_LEN;
+use crate::{Format, Rut, VerificationDigit};
+
+impl Rut {
+    /// Writes this [`Rut`] using the provided [`Format`] into a `ufmt`
+    /// writer, without using `core::fmt`.
+    ///
+    /// Use it from `ufmt::uDisplay` implementations through the
+    /// `ufmt::Formatter` or directly on any `uWrite` implementor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Format, Rut};
+    ///
+    /// let rut: Rut = "92.635.843-K".parse().unwrap();
+    /// let mut out = String::new();
+    ///
+    /// rut.uwrite(&mut out, Format::Dots).unwrap();
+    ///
+    /// assert_eq!(out, "92.635.843-K");
+    /// ```
+    #[cfg(feature = "ufmt")]
+    pub fn uwrite<W>(&self, writer: &mut W, fmt: Format) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        writer.write_str(self.ascii(fmt, &mut [0; MAX_LEN]))
+    }
+}
+
+#[cfg(feature = "defmt")]
+/// Writes the [`Format::Dash`] representation, as the `rut` fields of
+/// other `defmt` logs should read
+impl defmt::Format for Rut {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{=str}", self.ascii(Format::Dash, &mut [0; MAX_LEN]))
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for VerificationDigit {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{=char}", char::from(*self))
+    }
+}
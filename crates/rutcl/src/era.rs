@@ -0,0 +1,66 @@
+use crate::{Num, Rut, RutBand};
+
+/// First RUN number commonly seen among people registered in the 1990s
+const NINETIES_MIN_NUM: Num = 17_000_000;
+
+/// First RUN number commonly seen among people registered in the 2000s
+const TWO_THOUSANDS_MIN_NUM: Num = 20_500_000;
+
+/// First RUN number commonly seen among people registered from 2010 on
+const RECENT_MIN_NUM: Num = 23_500_000;
+
+/// Estimated era in which a RUN was assigned, see
+/// [`Rut::age_band_estimate`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RegistrationEra {
+    /// Assigned before 1990
+    Before1990,
+    /// Assigned from 1990 to 1999
+    Nineties,
+    /// Assigned from 2000 to 2009
+    TwoThousands,
+    /// Assigned from 2010 on
+    Recent,
+}
+
+impl Rut {
+    /// Estimates the era in which this RUN was assigned, which for Chilean
+    /// nationals is usually close to their birth year.
+    ///
+    /// Returns `None` for RUTs outside of the [`RutBand::Persons`] band and
+    /// for the [`RutBand::ForeignInvestors`] band, where numbers carry no
+    /// cohort information.
+    ///
+    /// > This is a coarse heuristic meant for aggregated analytics only. RUNs
+    /// > are assigned sequentially but not strictly at birth: foreigners
+    /// > receive numbers when they become residents, older cohorts were
+    /// > registered late and the cut-offs drift a few hundred thousand
+    /// > numbers in both directions. Expect a noticeable share of RUNs near
+    /// > the cut-offs to land in a neighbouring era, and never use the
+    /// > estimate to make decisions about an individual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{RegistrationEra, Rut};
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert_eq!(rut.age_band_estimate(), Some(RegistrationEra::Nineties));
+    /// assert_eq!("76.086.428-5".parse::<Rut>().unwrap().age_band_estimate(), None);
+    /// ```
+    pub fn age_band_estimate(&self) -> Option<RegistrationEra> {
+        if !RutBand::Persons.contains(self) || RutBand::ForeignInvestors.contains(self) {
+            return None;
+        }
+
+        let era = match self.num() {
+            num if num < NINETIES_MIN_NUM => RegistrationEra::Before1990,
+            num if num < TWO_THOUSANDS_MIN_NUM => RegistrationEra::Nineties,
+            num if num < RECENT_MIN_NUM => RegistrationEra::TwoThousands,
+            _ => RegistrationEra::Recent,
+        };
+
+        Some(era)
+    }
+}
@@ -0,0 +1,118 @@
+//! Step by step trace of the verification digit calculation.
+
+use std::fmt::Display;
+
+use crate::{Num, Rut, VerificationDigit, FACTOR, SYMBOLS};
+
+/// Single digit of the RUT's number in a [`ChecksumTrace`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ChecksumStep {
+    /// Digit of the RUT's number
+    pub digit: u32,
+    /// Factor the digit is multiplied by, cycling from 2 to 7
+    pub factor: u32,
+    /// Product of `digit` and `factor`
+    pub product: u32,
+}
+
+/// Trace of the Modulo 11 calculation of a [`VerificationDigit`].
+///
+/// The number's digits are reversed, each one is multiplied by a factor
+/// cycling from 2 to 7 and the products are added up. The digit is 11 minus
+/// the remainder of dividing the sum by 11, where 11 stands for `0` and 10
+/// stands for `K`.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::explain::ChecksumTrace;
+/// use rutcl::VerificationDigit;
+///
+/// let trace = ChecksumTrace::new(12_345_678);
+///
+/// assert_eq!(trace.reversed_digits(), vec![8, 7, 6, 5, 4, 3, 2, 1]);
+/// assert_eq!(trace.sum, 138);
+/// assert_eq!(trace.remainder, 6);
+/// assert_eq!(trace.result, 5);
+/// assert_eq!(trace.vd, VerificationDigit::Five);
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ChecksumTrace {
+    /// RUT's number the digit is calculated for
+    pub num: Num,
+    /// Digits of the number from right to left, with their factors
+    pub steps: Vec<ChecksumStep>,
+    /// Sum of every product
+    pub sum: u32,
+    /// Remainder of dividing `sum` by 11
+    pub remainder: u32,
+    /// 11 minus `remainder`, from 1 to 11
+    pub result: u32,
+    /// Resulting verification digit
+    pub vd: VerificationDigit,
+}
+
+impl ChecksumTrace {
+    /// Traces the calculation of the [`VerificationDigit`] for `num`
+    pub fn new(num: Num) -> Self {
+        let mut steps = Vec::new();
+        let mut rest = num;
+
+        while rest > 0 {
+            let digit = rest % 10;
+            let factor = FACTOR[steps.len() % FACTOR.len()];
+
+            steps.push(ChecksumStep {
+                digit,
+                factor,
+                product: digit * factor,
+            });
+            rest /= 10;
+        }
+
+        let sum = steps.iter().map(|step| step.product).sum::<u32>();
+        let remainder = sum % SYMBOLS;
+
+        ChecksumTrace {
+            num,
+            steps,
+            sum,
+            remainder,
+            result: SYMBOLS - remainder,
+            vd: VerificationDigit::compute(num),
+        }
+    }
+
+    /// Retrieves the digits of the number from right to left
+    pub fn reversed_digits(&self) -> Vec<u32> {
+        self.steps.iter().map(|step| step.digit).collect()
+    }
+}
+
+impl Display for ChecksumTrace {
+    /// Writes the calculation in a single line, e.g.
+    /// `8×2 + 7×3 + … = 138; 138 mod 11 = 6; 11 - 6 = 5 → 5`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, step) in self.steps.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " + ")?;
+            }
+
+            write!(f, "{}×{}", step.digit, step.factor)?;
+        }
+
+        write!(
+            f,
+            " = {}; {} mod {SYMBOLS} = {}; {SYMBOLS} - {} = {} → {}",
+            self.sum, self.sum, self.remainder, self.remainder, self.result, self.vd
+        )
+    }
+}
+
+impl Rut {
+    /// Traces the calculation of this RUT's [`VerificationDigit`], see
+    /// [`ChecksumTrace`]
+    pub fn explain(&self) -> ChecksumTrace {
+        ChecksumTrace::new(self.0)
+    }
+}
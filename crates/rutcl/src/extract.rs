@@ -0,0 +1,158 @@
+use std::ops::Range;
+
+use crate::{Format, Rut, SanitizeOptions};
+
+/// A RUT found in free text by [`Rut::parse_many_from_text`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RutMatch<'t> {
+    start: usize,
+    end: usize,
+    text: &'t str,
+    rut: Rut,
+}
+
+impl<'t> RutMatch<'t> {
+    /// Creates a [`RutMatch`] for `rut`, written as `text` at `start` in the
+    /// scanned text
+    pub(crate) fn new(text: &'t str, start: usize, rut: Rut) -> Self {
+        RutMatch {
+            start,
+            end: start + text.len(),
+            text,
+            rut,
+        }
+    }
+
+    /// Byte offset where the match starts in the scanned text
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Byte offset right after the match ends in the scanned text
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Byte range of the match in the scanned text
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Raw matched text, as written in the scanned text
+    #[inline]
+    pub fn as_str(&self) -> &'t str {
+        self.text
+    }
+
+    /// Parsed [`Rut`]
+    #[inline]
+    pub fn rut(&self) -> Rut {
+        self.rut
+    }
+}
+
+impl Rut {
+    /// Finds every valid RUT written in the provided text, in the order they
+    /// appear.
+    ///
+    /// RUTs must be written in one of the [`Format`]s and must not be
+    /// surrounded by letters or digits, so `ID179515857` or `179515857123`
+    /// don't match. Candidates with an invalid verification digit are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let text = "Cliente 17.951.585-7 (empresa 76086428-5), ref. 17.951.585-8";
+    /// let matches = Rut::parse_many_from_text(text);
+    ///
+    /// assert_eq!(matches.len(), 2);
+    /// assert_eq!(matches[0].range(), 8..20);
+    /// assert_eq!(matches[0].as_str(), "17.951.585-7");
+    /// assert_eq!(matches[1].rut().to_string(), "760864285");
+    /// ```
+    pub fn parse_many_from_text(text: &str) -> Vec<RutMatch<'_>> {
+        Rut::parse_many_from_bytes(text.as_bytes())
+    }
+
+    /// Same as [`Rut::parse_many_from_text`] for raw byte buffers, which
+    /// don't need to be valid UTF-8.
+    ///
+    /// Matched RUTs are always ASCII, so [`RutMatch::as_str`] is available
+    /// and ranges are byte offsets in `bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let buf = b"\x00\x02rut=17951585-7;\xfe\xffrut=76.086.428-5\n";
+    /// let matches = Rut::parse_many_from_bytes(buf);
+    ///
+    /// assert_eq!(matches.len(), 2);
+    /// assert_eq!(matches[0].range(), 6..16);
+    /// assert_eq!(matches[1].as_str(), "76.086.428-5");
+    /// ```
+    pub fn parse_many_from_bytes(bytes: &[u8]) -> Vec<RutMatch<'_>> {
+        let mut matches = Vec::new();
+        let mut idx = 0;
+
+        while idx < bytes.len() {
+            let starts_token = bytes[idx].is_ascii_digit()
+                && (idx == 0 || !bytes[idx - 1].is_ascii_alphanumeric());
+
+            if !starts_token {
+                idx += 1;
+                continue;
+            }
+
+            let (candidate, next) = candidate(bytes, idx);
+
+            if let Some(end) = candidate {
+                let raw = std::str::from_utf8(&bytes[idx..end]).expect("RUT candidates are ASCII");
+
+                if Format::detect(raw).is_some() {
+                    if let Ok(rut) = Rut::parse_chars(raw, SanitizeOptions::STRICT) {
+                        matches.push(RutMatch::new(raw, idx, rut));
+                    }
+                }
+            }
+
+            idx = next;
+        }
+
+        matches
+    }
+}
+
+/// Retrieves the end of the RUT-looking token starting at `start`, if any,
+/// along with the offset to resume scanning from
+fn candidate(bytes: &[u8], start: usize) -> (Option<usize>, usize) {
+    let run_end = start
+        + bytes[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit() || **b == b'.')
+            .count();
+    let mut body_end = run_end;
+
+    while bytes[body_end - 1] == b'.' {
+        body_end -= 1;
+    }
+
+    let end = match bytes.get(body_end..) {
+        Some([b'-', b'0'..=b'9' | b'k' | b'K', ..]) => body_end + 2,
+        Some([b'k' | b'K', ..]) => body_end + 1,
+        _ => body_end,
+    };
+
+    if bytes.get(end).is_some_and(|b| b.is_ascii_alphanumeric()) {
+        return (None, run_end.max(end));
+    }
+
+    (Some(end), end.max(body_end))
+}
@@ -0,0 +1,213 @@
+use regex::Regex;
+
+use crate::{Rut, RutMatch, SanitizeOptions};
+
+/// Matches RUTs whose groups are separated by dots or spaces and whose
+/// dash may be missing, surrounded by spaces or be a Unicode dash
+const TOLERANT_PATTERN: &str =
+    r"^\d{1,2}(?:[.\s]?\d{3}){2}\s*[-\u{2010}\u{2011}\u{2012}\u{2013}\u{2014}\u{2212}]?\s*[\dkK]";
+
+/// Matches `RUT`, `R.U.T.`, `RUT N°` and alike right before a candidate
+const LABEL_PATTERN: &str = r"(?i)\bR\.?\s?U\.?\s?[TN]\.?\s*(?:N[°º.]?\s*|#\s*)?:?\s*$";
+
+/// Matches phone and fax labels or the Chilean country code right before a
+/// candidate
+const PHONE_PATTERN: &str = r"(?i)(?:\btel(?:[eé]fono)?|\bfono|\bcel(?:ular)?|\bm[oó]vil|\bwhatsapp|\bfax|\+\s*56)[\s.:()]*$";
+
+/// Default amount of bytes before a candidate checked by context rules
+const DEFAULT_WINDOW: usize = 32;
+
+/// How to pick between candidates sharing chars of the text
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Overlap {
+    /// Keeps the candidate which starts first
+    #[default]
+    First,
+    /// Keeps the longest candidate, the one which starts first on ties
+    Longest,
+}
+
+/// Tolerant free-text RUT extractor with context rules.
+///
+/// Unlike [`Rut::parse_many_from_text`], groups may be separated by spaces,
+/// the dash may be surrounded by spaces or be a Unicode dash. To keep false
+/// positives at bay, candidates can be required to follow a label such as
+/// `RUT:` and be discarded when following a phone number label.
+///
+/// Candidates are checked against their verification digit, the ones left
+/// are resolved by the [`Overlap`] policy when they share chars.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Extractor;
+///
+/// let extractor = Extractor::builder()
+///     .require_label()
+///     .exclude_phone_numbers()
+///     .build();
+/// let text = "R.U.T.: 17 951 585 - 7, Fono: 17951585-7, folio 76086428-5";
+/// let matches = extractor.extract(text);
+///
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].as_str(), "17 951 585 - 7");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Extractor {
+    candidate: Regex,
+    require: Vec<Regex>,
+    exclude: Vec<Regex>,
+    window: usize,
+    overlap: Overlap,
+}
+
+impl Extractor {
+    /// Creates an [`ExtractorBuilder`] without context rules
+    pub fn builder() -> ExtractorBuilder {
+        ExtractorBuilder::default()
+    }
+
+    /// Finds every RUT in the provided text honoring the context rules, in
+    /// the order they appear
+    pub fn extract<'t>(&self, text: &'t str) -> Vec<RutMatch<'t>> {
+        let bytes = text.as_bytes();
+        let mut candidates = Vec::new();
+
+        for (start, _) in text.char_indices() {
+            let starts_token = bytes[start].is_ascii_digit()
+                && (start == 0 || !bytes[start - 1].is_ascii_alphanumeric());
+
+            if !starts_token {
+                continue;
+            }
+
+            let Some(found) = self.candidate.find(&text[start..]) else {
+                continue;
+            };
+            let end = start + found.end();
+
+            if bytes.get(end).is_some_and(|b| b.is_ascii_alphanumeric()) {
+                continue;
+            }
+
+            let Ok(rut) = Rut::parse_chars(found.as_str(), SanitizeOptions::LENIENT) else {
+                continue;
+            };
+
+            if self.allows(text, start) {
+                candidates.push(RutMatch::new(&text[start..end], start, rut));
+            }
+        }
+
+        self.resolve(candidates)
+    }
+
+    /// Checks the context rules against the text preceding `start`
+    fn allows(&self, text: &str, start: usize) -> bool {
+        let mut from = start.saturating_sub(self.window);
+
+        while !text.is_char_boundary(from) {
+            from += 1;
+        }
+
+        let context = &text[from..start];
+
+        self.require.iter().all(|rule| rule.is_match(context))
+            && !self.exclude.iter().any(|rule| rule.is_match(context))
+    }
+
+    /// Drops candidates overlapping a preferred one, `candidates` are sorted
+    /// by their start offset
+    fn resolve<'t>(&self, candidates: Vec<RutMatch<'t>>) -> Vec<RutMatch<'t>> {
+        let mut kept: Vec<RutMatch<'t>> = Vec::with_capacity(candidates.len());
+
+        for candidate in candidates {
+            match kept.last() {
+                Some(last) if last.end() > candidate.start() => {
+                    let longer = candidate.range().len() > last.range().len();
+
+                    if self.overlap == Overlap::Longest && longer {
+                        kept.pop();
+                        kept.push(candidate);
+                    }
+                }
+                _ => kept.push(candidate),
+            }
+        }
+
+        kept
+    }
+}
+
+/// Builder for an [`Extractor`], created with [`Extractor::builder`].
+///
+/// Context rules are regular expressions matched against up to
+/// [`ExtractorBuilder::window`] bytes of text preceding each candidate,
+/// anchor them with `$` to require them right before it.
+#[derive(Clone, Debug)]
+pub struct ExtractorBuilder {
+    require: Vec<Regex>,
+    exclude: Vec<Regex>,
+    window: usize,
+    overlap: Overlap,
+}
+
+impl Default for ExtractorBuilder {
+    fn default() -> Self {
+        ExtractorBuilder {
+            require: Vec::new(),
+            exclude: Vec::new(),
+            window: DEFAULT_WINDOW,
+            overlap: Overlap::default(),
+        }
+    }
+}
+
+impl ExtractorBuilder {
+    /// Only keeps candidates preceded by a RUT label such as `RUT:`,
+    /// `R.U.T.`, `RUN` or `RUT N°`
+    pub fn require_label(self) -> Self {
+        self.require(Regex::new(LABEL_PATTERN).expect("Label pattern is valid"))
+    }
+
+    /// Only keeps candidates whose preceding text matches `rule`
+    pub fn require(mut self, rule: Regex) -> Self {
+        self.require.push(rule);
+        self
+    }
+
+    /// Drops candidates preceded by a phone label (`Tel.`, `Fono`,
+    /// `Celular`, `WhatsApp`, ...) or the `+56` country code
+    pub fn exclude_phone_numbers(self) -> Self {
+        self.exclude(Regex::new(PHONE_PATTERN).expect("Phone pattern is valid"))
+    }
+
+    /// Drops candidates whose preceding text matches `rule`
+    pub fn exclude(mut self, rule: Regex) -> Self {
+        self.exclude.push(rule);
+        self
+    }
+
+    /// Sets the amount of bytes before a candidate checked by context rules
+    pub fn window(mut self, bytes: usize) -> Self {
+        self.window = bytes;
+        self
+    }
+
+    /// Sets how to pick between candidates sharing chars of the text
+    pub fn overlap(mut self, overlap: Overlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Builds the [`Extractor`]
+    pub fn build(self) -> Extractor {
+        Extractor {
+            candidate: Regex::new(TOLERANT_PATTERN).expect("Candidate pattern is valid"),
+            require: self.require,
+            exclude: self.exclude,
+            window: self.window,
+            overlap: self.overlap,
+        }
+    }
+}
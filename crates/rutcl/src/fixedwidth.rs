@@ -0,0 +1,196 @@
+//! Fixed-width RUT fields, as found in mainframe and bank batch files
+//! (Previred, payroll and supplier payment files).
+//!
+//! Fields have no separators: the RUT's number is zero-padded to the left
+//! and followed by the verification digit, with an uppercase `K`. For
+//! instance `17.951.585-7` is written as `179515857` in a [`Width::Nine`]
+//! field and as `0179515857` in a [`Width::Ten`] field.
+//!
+//! Reading is strict, fields must have the exact width and padding
+//! conventions, which catches misaligned records instead of silently
+//! reading a shifted RUT.
+//!
+//! # Example
+//!
+//! ```
+//! use rutcl::fixedwidth::{Field, Width};
+//! use rutcl::Rut;
+//!
+//! let rut: Rut = "9.876.543-3".parse().unwrap();
+//! let field = Field::new(6, Width::Ten);
+//! let mut record = *b"000001..........JUAN PEREZ";
+//!
+//! field.write(&rut, &mut record).unwrap();
+//!
+//! assert_eq!(&record, b"0000010098765433JUAN PEREZ");
+//! assert_eq!(field.read(&record).unwrap(), rut);
+//! ```
+
+use crate::{Error, Rut};
+
+/// Width of a fixed-width RUT field, verification digit included
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Width {
+    /// 8 digits for the number and the verification digit
+    Nine,
+    /// 9 digits for the number and the verification digit
+    Ten,
+    /// 11 digits for the number and the verification digit
+    Twelve,
+}
+
+impl Width {
+    /// Amount of bytes taken by the field
+    #[inline]
+    pub fn bytes(&self) -> usize {
+        match self {
+            Width::Nine => 9,
+            Width::Ten => 10,
+            Width::Twelve => 12,
+        }
+    }
+}
+
+/// Writes the [`Rut`] as a field of the provided [`Width`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::fixedwidth::{self, Width};
+/// use rutcl::Rut;
+///
+/// let rut: Rut = "9.876.543-3".parse().unwrap();
+///
+/// assert_eq!(fixedwidth::write(&rut, Width::Nine), "098765433");
+/// assert_eq!(fixedwidth::write(&rut, Width::Ten), "0098765433");
+/// ```
+pub fn write(rut: &Rut, width: Width) -> String {
+    format!("{:0>pad$}{}", rut.num(), rut.vd(), pad = width.bytes() - 1)
+}
+
+/// Reads a field of the provided [`Width`].
+///
+/// Fails with [`Error::InvalidFormat`] unless the field has exactly
+/// `width.bytes()` bytes, all of them digits but the last one, which must be
+/// a digit or an uppercase `K`. Fails as [`Rut::from_str`] does if the RUT
+/// itself is invalid.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::fixedwidth::{self, Width};
+///
+/// assert!(fixedwidth::read(b"0098765433", Width::Ten).is_ok());
+/// assert!(fixedwidth::read(b"098765433", Width::Ten).is_err());
+/// assert!(fixedwidth::read(b" 98765433", Width::Nine).is_err());
+/// ```
+///
+/// [`Rut::from_str`]: std::str::FromStr::from_str
+pub fn read(field: &[u8], width: Width) -> Result<Rut, Error> {
+    match field {
+        [num @ .., b'0'..=b'9' | b'K']
+            if field.len() == width.bytes() && num.iter().all(u8::is_ascii_digit) =>
+        {
+            Rut::from_bytes(field)
+        }
+        _ => Err(Error::InvalidFormat),
+    }
+}
+
+/// A fixed-width RUT field at a given offset of a record
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Field {
+    offset: usize,
+    width: Width,
+}
+
+impl Field {
+    /// Creates a [`Field`] of the provided [`Width`] starting `offset`
+    /// bytes into each record
+    pub fn new(offset: usize, width: Width) -> Self {
+        Field { offset, width }
+    }
+
+    /// Retrieves the bytes the field takes in a record
+    #[inline]
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.offset..self.offset + self.width.bytes()
+    }
+
+    /// Reads the field from the provided record, see [`read`].
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the record is too short to
+    /// hold the field.
+    pub fn read(&self, record: &[u8]) -> Result<Rut, Error> {
+        let field = record.get(self.range()).ok_or(Error::InvalidFormat)?;
+
+        read(field, self.width)
+    }
+
+    /// Writes the [`Rut`] into the field of the provided record, leaving the
+    /// rest of the record untouched, see [`write()`].
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the record is too short to
+    /// hold the field.
+    pub fn write(&self, rut: &Rut, record: &mut [u8]) -> Result<(), Error> {
+        let field = record.get_mut(self.range()).ok_or(Error::InvalidFormat)?;
+
+        field.copy_from_slice(write(rut, self.width).as_bytes());
+
+        Ok(())
+    }
+}
+
+/// Conventions for RUT fields of common Chilean payroll and banking file
+/// layouts, naming the [`Width`] each one uses.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::fixedwidth::Convention;
+/// use rutcl::Rut;
+///
+/// let rut: Rut = "9.876.543-3".parse().unwrap();
+///
+/// assert_eq!(Convention::Previred.write(&rut), "000098765433");
+/// assert_eq!(Convention::BancoEstadoNomina.write(&rut), "0098765433");
+/// assert_eq!(Convention::Previred.read(b"000098765433").unwrap(), rut);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Convention {
+    /// Previred 105-field layout, the RUT's number in an 11 digits field
+    /// followed by the verification digit in a 1 byte field
+    Previred,
+    /// BancoEstado payroll (nómina) files, a 10 bytes field with the
+    /// verification digit
+    BancoEstadoNomina,
+    /// Legacy 9 bytes fields, 8 digits and the verification digit
+    Legacy,
+}
+
+impl Convention {
+    /// Retrieves the [`Width`] of RUT fields in this convention
+    pub fn width(&self) -> Width {
+        match self {
+            Convention::Previred => Width::Twelve,
+            Convention::BancoEstadoNomina => Width::Ten,
+            Convention::Legacy => Width::Nine,
+        }
+    }
+
+    /// Creates a [`Field`] in this convention starting `offset` bytes into
+    /// each record
+    pub fn field(&self, offset: usize) -> Field {
+        Field::new(offset, self.width())
+    }
+
+    /// Reads a field in this convention, see [`read`]
+    pub fn read(&self, field: &[u8]) -> Result<Rut, Error> {
+        read(field, self.width())
+    }
+
+    /// Writes the [`Rut`] as a field in this convention, see [`write()`]
+    pub fn write(&self, rut: &Rut) -> String {
+        write(rut, self.width())
+    }
+}
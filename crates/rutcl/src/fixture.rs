@@ -0,0 +1,136 @@
+//! Deterministic RUT fixtures for tests.
+//!
+//! Fixtures are generated by a seeded [SplitMix64][1] sequence mapped into the
+//! supported RUT range, skipping repeated numbers. The same `n` and `seed`
+//! always produce the same list of RUTs, across platforms and releases.
+//!
+//! [1]: https://prng.di.unimi.it/splitmix64.c
+
+use std::collections::HashSet;
+
+use crate::{Num, Rut, VerificationDigit, MAX_NUM, MIN_NUM};
+
+/// Number of distinct RUTs a [`Fixture`] can generate
+const KEYSPACE: usize = (MAX_NUM - MIN_NUM + 1) as usize;
+
+/// Seed used to build the `SAMPLES_*` static tables
+pub const SAMPLES_SEED: u64 = 0;
+
+#[cfg(feature = "fixtures")]
+/// First 10 RUTs generated by [`Rut::sample_fixture`] with [`SAMPLES_SEED`]
+pub const SAMPLES_10: [Rut; 10] = table::<10>(SAMPLES_SEED);
+
+#[cfg(feature = "fixtures")]
+/// First 100 RUTs generated by [`Rut::sample_fixture`] with [`SAMPLES_SEED`]
+pub const SAMPLES_100: [Rut; 100] = table::<100>(SAMPLES_SEED);
+
+#[cfg(feature = "fixtures")]
+/// First 1000 RUTs generated by [`Rut::sample_fixture`] with [`SAMPLES_SEED`]
+pub const SAMPLES_1000: [Rut; 1000] = table::<1000>(SAMPLES_SEED);
+
+/// Advances the SplitMix64 state and returns the next RUT in the sequence
+const fn next(state: &mut u64) -> Rut {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    let num = MIN_NUM + (z % (MAX_NUM - MIN_NUM + 1) as u64) as Num;
+
+    Rut(num, VerificationDigit::compute(num))
+}
+
+#[cfg(feature = "fixtures")]
+/// Builds a fixture table in a `const` context
+const fn table<const N: usize>(seed: u64) -> [Rut; N] {
+    let mut ruts = [crate::MIN; N];
+    let mut state = seed;
+    let mut len = 0;
+
+    while len < N {
+        let rut = next(&mut state);
+        let mut idx = 0;
+
+        while idx < len && ruts[idx].0 != rut.0 {
+            idx += 1;
+        }
+
+        if idx == len {
+            ruts[len] = rut;
+            len += 1;
+        }
+    }
+
+    ruts
+}
+
+/// Iterator over a deterministic list of distinct [`Rut`] instances.
+///
+/// Created by [`Rut::sample_fixture`].
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    state: u64,
+    remaining: usize,
+    seen: HashSet<Num>,
+}
+
+impl Iterator for Fixture {
+    type Item = Rut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        loop {
+            let rut = next(&mut self.state);
+
+            if self.seen.insert(rut.num()) {
+                self.remaining -= 1;
+                return Some(rut);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Fixture {}
+
+impl Rut {
+    /// Generates a deterministic list of `n` distinct valid [`Rut`] instances
+    /// from the provided `seed`.
+    ///
+    /// Ideal for snapshot tests, the output for a given `n` and `seed` never
+    /// changes. Lists for the same seed share their prefix, so the first `n`
+    /// items of a longer list are equal to the list of size `n`.
+    ///
+    /// Lists are capped at the number of valid RUTs, asking for more yields
+    /// every RUT once and then ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let ruts = Rut::sample_fixture(5, 42).collect::<Vec<Rut>>();
+    ///
+    /// assert_eq!(ruts.len(), 5);
+    /// assert_eq!(ruts, Rut::sample_fixture(5, 42).collect::<Vec<Rut>>());
+    /// ```
+    pub fn sample_fixture(n: usize, seed: u64) -> Fixture {
+        let n = n.min(KEYSPACE);
+
+        Fixture {
+            state: seed,
+            remaining: n,
+            // Large lists grow the set as they go instead of reserving
+            // the whole keyspace upfront
+            seen: HashSet::with_capacity(n.min(1 << 16)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use crate::{Error, SanitizeOptions};
+
+/// Weights applied to the first 10 digits of CUIT and RUC numbers
+const WEIGHTS: [u32; 10] = [5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+/// Prefixes of Argentine CUIT/CUIL numbers
+const CUIT_PREFIXES: [u32; 7] = [20, 23, 24, 27, 30, 33, 34];
+
+/// Prefixes of Peruvian RUC numbers
+const RUC_PREFIXES: [u32; 4] = [10, 15, 17, 20];
+
+/// Tax ID from another country which was provided in place of a RUT, see
+/// [`Error::LooksLikeForeignTaxId`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ForeignTaxId {
+    /// Argentine CUIT (or CUIL), e.g. `20-12345678-6`
+    ArgentineCuit,
+    /// Peruvian RUC, e.g. `20100070970`
+    PeruvianRuc,
+}
+
+impl ForeignTaxId {
+    /// Guesses which foreign tax ID the provided input is written as, if
+    /// any.
+    ///
+    /// Both CUIT and RUC numbers have 11 digits and a mod 11 check digit,
+    /// only inputs with a known prefix and a valid check digit match. As
+    /// prefix `20` is shared, such inputs are guessed as CUITs when written
+    /// as `XX-XXXXXXXX-X` and as RUCs otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::ForeignTaxId;
+    ///
+    /// assert_eq!(ForeignTaxId::guess("20-12345678-6"), Some(ForeignTaxId::ArgentineCuit));
+    /// assert_eq!(ForeignTaxId::guess("20100070970"), Some(ForeignTaxId::PeruvianRuc));
+    /// assert_eq!(ForeignTaxId::guess("17.951.585-7"), None);
+    /// ```
+    pub fn guess(input: &str) -> Option<Self> {
+        let options = SanitizeOptions::default();
+        let digits = options
+            .trim(input)
+            .chars()
+            .filter(|c| !options.is_separator(*c))
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()?;
+
+        if digits.len() != 11 {
+            return None;
+        }
+
+        let (check, body) = digits.split_last()?;
+        let prefix = body[0] * 10 + body[1];
+        let sum = body.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum::<u32>();
+        let cuit = CUIT_PREFIXES.contains(&prefix)
+            && match 11 - sum % 11 {
+                11 => *check == 0,
+                10 => false,
+                digit => *check == digit,
+            };
+        let ruc = RUC_PREFIXES.contains(&prefix) && (11 - sum % 11) % 10 == *check;
+
+        match (cuit, ruc) {
+            (true, false) => Some(ForeignTaxId::ArgentineCuit),
+            (false, true) => Some(ForeignTaxId::PeruvianRuc),
+            (true, true) if is_cuit_layout(input.trim()) => Some(ForeignTaxId::ArgentineCuit),
+            (true, true) => Some(ForeignTaxId::PeruvianRuc),
+            (false, false) => None,
+        }
+    }
+}
+
+impl Display for ForeignTaxId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForeignTaxId::ArgentineCuit => write!(f, "Argentine CUIT"),
+            ForeignTaxId::PeruvianRuc => write!(f, "Peruvian RUC"),
+        }
+    }
+}
+
+/// Checks whether the input is written as `XX-XXXXXXXX-X`
+fn is_cuit_layout(input: &str) -> bool {
+    matches!(input.as_bytes(), [_, _, b'-', .., b'-', _] if input.len() == 13)
+}
+
+/// Replaces range errors for inputs which look like a foreign tax ID with
+/// [`Error::LooksLikeForeignTaxId`]
+pub(crate) fn refine(err: Error, input: &str) -> Error {
+    match err {
+        Error::OutOfRange | Error::NaN(_) => ForeignTaxId::guess(input)
+            .map(|guess| Error::LooksLikeForeignTaxId { guess })
+            .unwrap_or(err),
+        err => err,
+    }
+}
@@ -0,0 +1,45 @@
+use std::str::from_utf8;
+
+use crate::{Format, Rut};
+
+/// Checks the parse → format → parse round trip for arbitrary bytes,
+/// panicking if any invariant is broken. Meant to be called from fuzz
+/// harnesses.
+///
+/// Inputs which are not UTF-8 or not a valid RUT are ignored. For valid
+/// RUTs every [`Format`] must be detected back as itself and parse back to
+/// the same [`Rut`], both leniently and with [`Rut::from_formatted`].
+///
+/// # Example
+///
+/// ```
+/// rutcl::fuzz_check(b"17.951.585-7");
+/// rutcl::fuzz_check(b"\xff not a RUT");
+/// ```
+pub fn fuzz_check(data: &[u8]) {
+    let Ok(input) = from_utf8(data) else {
+        return;
+    };
+
+    let Ok(rut) = input.parse::<Rut>() else {
+        assert!(
+            !Rut::is_valid(input),
+            "{input:?} is valid but fails to parse"
+        );
+        return;
+    };
+
+    assert!(Rut::is_valid(input), "{input:?} parses but is not valid");
+
+    for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+        let formatted = rut.format(fmt);
+
+        assert_eq!(Format::detect(&formatted), Some(fmt), "{formatted:?}");
+        assert_eq!(formatted.parse::<Rut>().ok(), Some(rut), "{formatted:?}");
+        assert_eq!(
+            Rut::from_formatted(&formatted, fmt).ok(),
+            Some(rut),
+            "{formatted:?}"
+        );
+    }
+}
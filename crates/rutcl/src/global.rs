@@ -0,0 +1,47 @@
+use std::sync::OnceLock;
+
+use crate::Format;
+
+/// [`Format`] used by `Display` and serde serialization, set at most once
+static DEFAULT_FORMAT: OnceLock<Format> = OnceLock::new();
+
+/// Sets the [`Format`] used process-wide by [`Rut`]'s `Display` and, with
+/// the `serde` feature, by human readable serialization.
+///
+/// The default format can be set only once, ideally at startup before any
+/// RUT is written. Setting it again to the same format is a no-op, setting
+/// it to a different format fails with the format already in effect.
+///
+/// Until set, [`Format::Sans`] is used.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{default_format, set_default_format, Format, Rut};
+///
+/// let rut: Rut = "179515857".parse().unwrap();
+///
+/// assert_eq!(rut.to_string(), "179515857");
+///
+/// set_default_format(Format::Dots).unwrap();
+///
+/// assert_eq!(default_format(), Format::Dots);
+/// assert_eq!(rut.to_string(), "17.951.585-7");
+/// assert!(set_default_format(Format::Dots).is_ok());
+/// assert_eq!(set_default_format(Format::Dash), Err(Format::Dots));
+/// ```
+///
+/// [`Rut`]: crate::Rut
+pub fn set_default_format(fmt: Format) -> Result<(), Format> {
+    match *DEFAULT_FORMAT.get_or_init(|| fmt) {
+        current if current == fmt => Ok(()),
+        current => Err(current),
+    }
+}
+
+/// Retrieves the [`Format`] set with [`set_default_format`], or
+/// [`Format::Sans`] if none was set
+#[inline]
+pub fn default_format() -> Format {
+    DEFAULT_FORMAT.get().copied().unwrap_or(Format::Sans)
+}
@@ -0,0 +1,187 @@
+//! Formal grammar for RUT strings, written with [`winnow`] combinators.
+//!
+//! ```text
+//! rut  = dots | dash | sans
+//! dots = head 1*( "." 3DIGIT ) "-" vd
+//! dash = num "-" vd
+//! sans = num vd
+//! head = %x31-39 0*2DIGIT
+//! num  = %x31-39 *DIGIT
+//! vd   = DIGIT | "K" | "k"
+//! ```
+//!
+//! The alternative is picked upfront from the separators in the input, so
+//! errors point to where the input diverges from the grammar of its own
+//! format instead of to the start of the last alternative tried.
+//!
+//! Only [`Rut::parse_strict`] enforces the grammar. [`Rut::from_str`] stays
+//! the lenient compatibility parser, see its implementation notes.
+//!
+//! [`Rut::from_str`]: std::str::FromStr::from_str
+
+use winnow::ascii::digit0;
+use winnow::combinator::{cut_err, eof, fail, repeat};
+use winnow::error::{ContextError, ModalResult};
+use winnow::stream::AsChar;
+use winnow::token::{one_of, take_while};
+use winnow::Parser;
+
+use crate::{Error, Expected, Format, Rut, SanitizeOptions};
+
+/// Result of the grammar's parsers, tagging errors with what was expected
+type GrammarResult<O> = ModalResult<O, ContextError<Expected>>;
+
+impl Rut {
+    /// Parses a RUT following the crate's formal grammar: exactly one of the
+    /// [`Format`]s, with no surrounding whitespace, no leading zeros and,
+    /// for [`Format::Dots`], groups of exactly three digits.
+    ///
+    /// Inputs which don't follow the grammar fail with [`Error::Syntax`],
+    /// holding the byte offset where parsing stopped. [`Rut::from_str`]
+    /// remains the lenient compatibility parser which strips separators
+    /// wherever they are.
+    ///
+    /// [`Rut::from_str`]: std::str::FromStr::from_str
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Error, Rut};
+    ///
+    /// assert!(Rut::parse_strict("17.951.585-7").is_ok());
+    /// assert!("1-7.9.5-15857".parse::<Rut>().is_ok());
+    ///
+    /// let err = Rut::parse_strict("1-7.9.5-15857").unwrap_err();
+    ///
+    /// assert!(matches!(err, Error::Syntax { offset: 1, .. }));
+    /// ```
+    pub fn parse_strict(input: &str) -> Result<Self, Error> {
+        let result = Format::parse_grammar(input)
+            .and_then(|_| Rut::parse_chars(input, SanitizeOptions::STRICT));
+
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        crate::telemetry::record(&result);
+
+        result
+    }
+}
+
+impl Format {
+    /// Checks the provided input against the RUT grammar, retrieving the
+    /// [`Format`] it is written in. The verification digit is not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Error, Format};
+    ///
+    /// assert_eq!(Format::parse_grammar("17951585-7").unwrap(), Format::Dash);
+    /// assert!(matches!(
+    ///     Format::parse_grammar("17.95.1585-7"),
+    ///     Err(Error::Syntax { offset: 3, .. })
+    /// ));
+    /// ```
+    pub fn parse_grammar(input: &str) -> Result<Format, Error> {
+        let fmt = if input.contains('.') {
+            Format::Dots
+        } else if input.contains('-') {
+            Format::Dash
+        } else {
+            Format::Sans
+        };
+        let mut parser = match fmt {
+            Format::Sans => sans,
+            Format::Dash => dash,
+            Format::Dots => dots,
+        };
+
+        parser
+            .parse(input)
+            .map(|_| fmt)
+            .map_err(|err| Error::Syntax {
+                offset: err.offset(),
+                expected: err
+                    .inner()
+                    .context()
+                    .next()
+                    .copied()
+                    .unwrap_or(Expected::EndOfInput),
+            })
+    }
+}
+
+/// `dots = head 1*( "." 3DIGIT ) "-" vd`
+fn dots(input: &mut &str) -> GrammarResult<()> {
+    let head = (one_of('1'..='9'), take_while(0..=2, AsChar::is_dec_digit));
+
+    (
+        head.context(Expected::Number),
+        repeat::<_, _, (), _, _>(1.., ('.', cut_err(group))).context(Expected::Dot),
+        cut_err('-').context(Expected::Dash),
+        cut_err(vd),
+        cut_err(eof).context(Expected::EndOfInput),
+    )
+        .void()
+        .parse_next(input)
+}
+
+/// `3DIGIT`
+fn group<'i>(input: &mut &'i str) -> GrammarResult<&'i str> {
+    take_while(3, AsChar::is_dec_digit)
+        .context(Expected::Group)
+        .parse_next(input)
+}
+
+/// `dash = num "-" vd`
+fn dash(input: &mut &str) -> GrammarResult<()> {
+    (
+        num,
+        cut_err('-').context(Expected::Dash),
+        cut_err(vd),
+        cut_err(eof).context(Expected::EndOfInput),
+    )
+        .void()
+        .parse_next(input)
+}
+
+/// `sans = num vd`
+///
+/// The number is greedy, so the verification digit is split from the tail
+/// by hand instead of backtracking.
+fn sans(input: &mut &str) -> GrammarResult<()> {
+    let start = *input;
+    num.parse_next(input)?;
+
+    match input.chars().next() {
+        Some('K' | 'k') => {
+            *input = &input[1..];
+        }
+        Some(_) => {}
+        None if start.len() > 1 => return Ok(()),
+        None => {
+            return cut_err(fail)
+                .context(Expected::VerificationDigit)
+                .parse_next(input);
+        }
+    }
+
+    cut_err(eof)
+        .context(Expected::EndOfInput)
+        .parse_next(input)
+        .map(|_| ())
+}
+
+/// `num = %x31-39 *DIGIT`
+fn num<'i>(input: &mut &'i str) -> GrammarResult<&'i str> {
+    (one_of('1'..='9'), digit0)
+        .take()
+        .context(Expected::Number)
+        .parse_next(input)
+}
+
+/// `vd = DIGIT | "K" | "k"`
+fn vd(input: &mut &str) -> GrammarResult<char> {
+    one_of(('0'..='9', 'K', 'k'))
+        .context(Expected::VerificationDigit)
+        .parse_next(input)
+}
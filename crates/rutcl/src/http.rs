@@ -0,0 +1,260 @@
+//! Mapping of [`Error`]s to HTTP API error payloads.
+//!
+//! [`ErrorResponse`] picks a status code for each error and renders an
+//! [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) "problem details"
+//! body. With the `axum` and `actix` features it can be returned straight
+//! from handlers.
+//!
+//! | Error                                   | Status |
+//! | --------------------------------------- | ------ |
+//! | Malformed input (format, NaN, empty...) | `400`  |
+//! | Well formed but rejected RUTs           | `422`  |
+//! | Environment variable errors             | `500`  |
+
+use std::fmt::Write;
+
+use crate::Error;
+
+/// Media type of the problem details body
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// An HTTP error response built from an [`Error`].
+///
+/// The body holds the RFC 7807 `type`, `title`, `status` and `detail`
+/// members, `instance` when set, and a `kind` extension member with
+/// [`Error::kind`] so clients can branch on it.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::http::ErrorResponse;
+/// use rutcl::Rut;
+///
+/// let err = "17.951.585-8".parse::<Rut>().unwrap_err();
+/// let response = ErrorResponse::new(&err).with_instance("/employees");
+///
+/// assert_eq!(response.status_code(), 422);
+/// assert_eq!(
+///     response.to_json(),
+///     r#"{"type":"about:blank","title":"Unprocessable Content","status":422,"detail":"Invalid verification digit: have 8, want 7","instance":"/employees","kind":"invalid_verification_digit"}"#
+/// );
+///
+/// let localized = ErrorResponse::new(&err).with_detail(err.to_spanish());
+///
+/// assert_eq!(localized.detail(), "Dígito verificador inválido: se esperaba 7");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorResponse {
+    status: u16,
+    problem_type: String,
+    detail: String,
+    instance: Option<String>,
+    kind: &'static str,
+}
+
+impl ErrorResponse {
+    /// Creates the [`ErrorResponse`] for the provided [`Error`], with
+    /// `about:blank` as problem type and the error message as detail
+    pub fn new(err: &Error) -> Self {
+        ErrorResponse {
+            status: status_code(err),
+            problem_type: String::from("about:blank"),
+            detail: err.to_string(),
+            instance: None,
+            kind: err.kind(),
+        }
+    }
+
+    /// Sets the problem `type` URI, which should document the error
+    pub fn with_type(mut self, uri: impl Into<String>) -> Self {
+        self.problem_type = uri.into();
+        self
+    }
+
+    /// Replaces the `detail` message, e.g. with [`Error::to_spanish`]
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+
+    /// Sets the `instance` URI, identifying this occurrence of the problem
+    pub fn with_instance(mut self, uri: impl Into<String>) -> Self {
+        self.instance = Some(uri.into());
+        self
+    }
+
+    /// Retrieves the HTTP status code
+    #[inline]
+    pub fn status_code(&self) -> u16 {
+        self.status
+    }
+
+    /// Retrieves the problem `type` URI
+    #[inline]
+    pub fn problem_type(&self) -> &str {
+        &self.problem_type
+    }
+
+    /// Retrieves the problem `title`, the reason phrase of the status code
+    pub fn title(&self) -> &'static str {
+        match self.status {
+            400 => "Bad Request",
+            422 => "Unprocessable Content",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Retrieves the `detail` message
+    #[inline]
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+
+    /// Retrieves the `instance` URI, if any
+    #[inline]
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+
+    /// Retrieves the `kind` extension member, see [`Error::kind`]
+    #[inline]
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Renders the `application/problem+json` body
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+
+        member(&mut json, "type", &self.problem_type);
+        member(&mut json, "title", self.title());
+        write!(json, r#""status":{},"#, self.status).expect("Writing to a String never fails");
+        member(&mut json, "detail", &self.detail);
+
+        if let Some(instance) = &self.instance {
+            member(&mut json, "instance", instance);
+        }
+
+        member(&mut json, "kind", self.kind);
+        json.pop();
+        json.push('}');
+        json
+    }
+}
+
+impl From<&Error> for ErrorResponse {
+    fn from(err: &Error) -> Self {
+        ErrorResponse::new(err)
+    }
+}
+
+impl From<Error> for ErrorResponse {
+    fn from(err: Error) -> Self {
+        ErrorResponse::new(&err)
+    }
+}
+
+/// Picks the HTTP status code for the provided [`Error`]
+fn status_code(err: &Error) -> u16 {
+    match err {
+        Error::InvalidFormat
+        | Error::NaN(_)
+        | Error::EmptyString
+        | Error::VerificationDigitOutOfBounds(_)
+        | Error::UnsupportedWireVersion(_)
+        | Error::SiiSchema { .. }
+        | Error::Syntax { .. } => 400,
+        Error::InvalidVerificationDigit { .. }
+        | Error::OutOfRange
+        | Error::OutOfBand(_)
+        | Error::RejectedBand(_)
+        | Error::LooksLikeForeignTaxId { .. }
+        | Error::Placeholder => 422,
+        Error::InvalidValue { source, .. } | Error::InvalidPart { source, .. } => {
+            status_code(source)
+        }
+        Error::EnvVar { .. } | Error::InvalidEnvVar { .. } => 500,
+    }
+}
+
+/// Appends a `"name":"value",` JSON member, escaping the value
+fn member(json: &mut String, name: &str, value: &str) {
+    write!(json, r#""{name}":""#).expect("Writing to a String never fails");
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                write!(json, "\\u{:04x}", c as u32).expect("Writing to a String never fails")
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push_str("\",");
+}
+
+#[cfg(feature = "axum")]
+mod axum {
+    use ::axum_core::response::{IntoResponse, Response};
+    use ::http::header::CONTENT_TYPE;
+    use ::http::StatusCode;
+
+    use super::{ErrorResponse, PROBLEM_JSON};
+
+    impl IntoResponse for ErrorResponse {
+        fn into_response(self) -> Response {
+            let status =
+                StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+            (status, [(CONTENT_TYPE, PROBLEM_JSON)], self.to_json()).into_response()
+        }
+    }
+
+    impl IntoResponse for crate::Error {
+        fn into_response(self) -> Response {
+            ErrorResponse::new(&self).into_response()
+        }
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix {
+    use ::actix_web::body::BoxBody;
+    use ::actix_web::http::StatusCode;
+    use ::actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
+
+    use super::{ErrorResponse, PROBLEM_JSON};
+    use crate::Error;
+
+    impl Responder for ErrorResponse {
+        type Body = BoxBody;
+
+        fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+            let status =
+                StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+            HttpResponse::build(status)
+                .content_type(PROBLEM_JSON)
+                .body(self.to_json())
+        }
+    }
+
+    /// Lets handlers return `Result<_, rutcl::Error>` and use `?`
+    impl ResponseError for Error {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::from_u16(super::status_code(self))
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            HttpResponse::build(ResponseError::status_code(self))
+                .content_type(PROBLEM_JSON)
+                .body(ErrorResponse::new(self).to_json())
+        }
+    }
+}
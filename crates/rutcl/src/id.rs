@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Error, Num, Rut, VerificationDigit, RANGE};
+
+/// RFC 4648 base32 alphabet, lowercase
+const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Chars taken by an encoded [`RutId`], enough for the 32 bits of a [`Num`]
+const LEN: usize = 7;
+
+/// Compact, URL-safe ID for a [`Rut`], meant for API identifiers such as
+/// Relay-style global IDs or GraphQL federation `@key` fields.
+///
+/// A [`RutId`] is written as the RUT's number encoded in lowercase base32
+/// (RFC 4648 alphabet, no padding), always 7 chars long, so IDs don't read
+/// as national IDs in URLs, logs or client caches. Decoding accepts
+/// uppercase chars as well.
+///
+/// > The encoding keeps RUTs from showing at a glance but anyone can
+/// > decode it, use `Pseudonym` or `JoinKeyer` (`blake3` feature) when the
+/// > RUT must not be recoverable.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutId};
+///
+/// let rut: Rut = "17.951.585-7".parse().unwrap();
+/// let id = RutId::from(rut);
+///
+/// assert_eq!(id.to_string(), "aard23b");
+/// assert_eq!(Rut::from("aard23b".parse::<RutId>().unwrap()), rut);
+/// assert!("17951585".parse::<RutId>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RutId(Num);
+
+impl RutId {
+    /// Retrieves the [`Rut`] this ID stands for
+    #[inline]
+    pub fn rut(&self) -> Rut {
+        Rut(self.0, VerificationDigit::compute(self.0))
+    }
+}
+
+impl From<Rut> for RutId {
+    fn from(rut: Rut) -> Self {
+        RutId(rut.num())
+    }
+}
+
+impl From<RutId> for Rut {
+    fn from(id: RutId) -> Self {
+        id.rut()
+    }
+}
+
+impl Display for RutId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut encoded = [0u8; LEN];
+
+        for (idx, byte) in encoded.iter_mut().enumerate() {
+            let shift = 5 * (LEN - 1 - idx);
+
+            *byte = ALPHABET[(u64::from(self.0) >> shift) as usize & 0x1f];
+        }
+
+        f.pad(std::str::from_utf8(&encoded).expect("The alphabet is ASCII"))
+    }
+}
+
+/// Fails with [`Error::InvalidFormat`] for inputs which are not 7 base32
+/// chars and with [`Error::OutOfRange`] for numbers which are not valid RUT
+/// numbers
+impl FromStr for RutId {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.len() != LEN {
+            return Err(Error::InvalidFormat);
+        }
+
+        let mut value: u64 = 0;
+
+        for byte in input.bytes() {
+            let digit = ALPHABET
+                .iter()
+                .position(|c| *c == byte.to_ascii_lowercase())
+                .ok_or(Error::InvalidFormat)?;
+
+            value = value << 5 | digit as u64;
+        }
+
+        let num = Num::try_from(value).map_err(|_| Error::OutOfRange)?;
+
+        if !RANGE.contains(&num) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(RutId(num))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::RutId;
+
+    /// Serializes the encoded ID as a string
+    impl Serialize for RutId {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RutId {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(RutIdVisitor)
+        }
+    }
+
+    struct RutIdVisitor;
+
+    impl Visitor<'_> for RutIdVisitor {
+        type Value = RutId;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a base32 encoded RutId")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            RutId::from_str(v).map_err(|err| E::custom(err.to_string()))
+        }
+    }
+}
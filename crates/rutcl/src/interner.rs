@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::{Num, Rut};
+
+/// Maps [`Rut`]s to dense `u32` ids and back.
+///
+/// Ids are assigned in insertion order starting at `0`, so they can index
+/// plain vectors or columnar arrays when joining large datasets on RUT.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutInterner};
+///
+/// let a: Rut = "17.951.585-7".parse().unwrap();
+/// let b: Rut = "76.086.428-5".parse().unwrap();
+/// let mut interner = RutInterner::new();
+///
+/// assert_eq!(interner.intern(a), 0);
+/// assert_eq!(interner.intern(b), 1);
+/// assert_eq!(interner.intern(a), 0);
+/// assert_eq!(interner.resolve(1), Some(b));
+/// assert_eq!(interner.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RutInterner {
+    ids: HashMap<Num, u32>,
+    ruts: Vec<Rut>,
+}
+
+impl RutInterner {
+    /// Creates an empty [`RutInterner`]
+    pub fn new() -> Self {
+        RutInterner::default()
+    }
+
+    /// Creates an empty [`RutInterner`] with room for `capacity` RUTs
+    pub fn with_capacity(capacity: usize) -> Self {
+        RutInterner {
+            ids: HashMap::with_capacity(capacity),
+            ruts: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Retrieves the id of the provided [`Rut`], assigning the next one if
+    /// it was not interned yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` RUTs are interned, which can't happen
+    /// as there are less RUTs than that.
+    pub fn intern(&mut self, rut: Rut) -> u32 {
+        let next = u32::try_from(self.ruts.len()).expect("Less RUTs than u32::MAX");
+        let id = *self.ids.entry(rut.num()).or_insert(next);
+
+        if id == next {
+            self.ruts.push(rut);
+        }
+
+        id
+    }
+
+    /// Retrieves the id of the provided [`Rut`] if it was interned
+    pub fn get(&self, rut: &Rut) -> Option<u32> {
+        self.ids.get(&rut.num()).copied()
+    }
+
+    /// Retrieves the [`Rut`] interned with the provided id
+    pub fn resolve(&self, id: u32) -> Option<Rut> {
+        self.ruts.get(id as usize).copied()
+    }
+
+    /// Retrieves the interned RUTs, indexed by their id
+    pub fn as_slice(&self) -> &[Rut] {
+        &self.ruts
+    }
+
+    /// Amount of interned RUTs
+    pub fn len(&self) -> usize {
+        self.ruts.len()
+    }
+
+    /// Checks whether no RUT was interned
+    pub fn is_empty(&self) -> bool {
+        self.ruts.is_empty()
+    }
+}
+
+impl Extend<Rut> for RutInterner {
+    fn extend<T: IntoIterator<Item = Rut>>(&mut self, iter: T) {
+        for rut in iter {
+            self.intern(rut);
+        }
+    }
+}
+
+impl FromIterator<Rut> for RutInterner {
+    fn from_iter<T: IntoIterator<Item = Rut>>(iter: T) -> Self {
+        let mut interner = RutInterner::new();
+
+        interner.extend(iter);
+        interner
+    }
+}
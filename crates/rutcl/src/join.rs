@@ -0,0 +1,178 @@
+use crate::{Error, Format, Rut};
+
+/// Context used to derive the BLAKE3 keys from the shared secrets, changing
+/// it would change every join key
+const JOIN_KEY_CONTEXT: &str = "rutcl 2026-10-16 RUT join key v1";
+
+/// Keyed hashing of RUTs for private set matching between organizations.
+///
+/// Both parties build a [`JoinKeyer`] from the same secret and key ID (`kid`)
+/// and exchange join keys instead of RUTs: matching join keys mean matching
+/// RUTs, while the RUTs can't be recovered without the secret. Join keys are
+/// written as `kid:hash`, `hash` being a keyed BLAKE3 hash in lowercase
+/// hexadecimal, so either side can tell which key produced them.
+///
+/// Keys are rotated with [`JoinKeyer::rotate`]: new join keys use the newest
+/// key, while join keys produced with retired keys still verify until the
+/// retired key is removed with [`JoinKeyer::remove`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{JoinKeyer, Rut};
+///
+/// let ours = JoinKeyer::new("2026q3", b"secret shared by both parties").unwrap();
+/// let theirs = JoinKeyer::new("2026q3", b"secret shared by both parties").unwrap();
+/// let rut: Rut = "17.951.585-7".parse().unwrap();
+///
+/// assert_eq!(ours.join_key(&rut), theirs.join_key(&rut));
+/// assert!(ours.join_key(&rut).starts_with("2026q3:"));
+/// ```
+#[derive(Clone)]
+pub struct JoinKeyer {
+    /// Keys from oldest to newest, the last one being the active key
+    keys: Vec<(String, [u8; 32])>,
+}
+
+impl JoinKeyer {
+    /// Creates a [`JoinKeyer`] whose active key is derived from `secret`.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if `kid` is empty or contains a
+    /// `:`.
+    pub fn new(kid: &str, secret: &[u8]) -> Result<Self, Error> {
+        let mut keyer = JoinKeyer { keys: Vec::new() };
+
+        keyer.rotate(kid, secret)?;
+
+        Ok(keyer)
+    }
+
+    /// Makes a new key, derived from `secret`, the active one. Retired keys
+    /// are kept to verify join keys produced with them.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if `kid` is empty, contains a `:`
+    /// or is already in use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{JoinKeyer, Rut};
+    ///
+    /// let mut keyer = JoinKeyer::new("v1", b"old secret").unwrap();
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let old = keyer.join_key(&rut);
+    ///
+    /// keyer.rotate("v2", b"new secret").unwrap();
+    ///
+    /// assert_eq!(keyer.active_kid(), "v2");
+    /// assert!(keyer.join_key(&rut).starts_with("v2:"));
+    /// assert!(keyer.verify(&rut, &old));
+    ///
+    /// keyer.remove("v1");
+    ///
+    /// assert!(!keyer.verify(&rut, &old));
+    /// ```
+    pub fn rotate(&mut self, kid: &str, secret: &[u8]) -> Result<(), Error> {
+        if kid.is_empty() || kid.contains(':') || self.kids().any(|used| used == kid) {
+            return Err(Error::InvalidFormat);
+        }
+
+        let key = blake3::derive_key(JOIN_KEY_CONTEXT, secret);
+
+        self.keys.push((kid.to_string(), key));
+
+        Ok(())
+    }
+
+    /// Removes a retired key, join keys produced with it no longer verify.
+    ///
+    /// Returns `false` if there is no such key or if it is the active one,
+    /// which can't be removed.
+    pub fn remove(&mut self, kid: &str) -> bool {
+        match self.keys.iter().position(|(used, _)| used == kid) {
+            Some(idx) if idx + 1 < self.keys.len() => {
+                self.keys.remove(idx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Retrieves the ID of the active key
+    pub fn active_kid(&self) -> &str {
+        &self.active().0
+    }
+
+    /// Iterates over the IDs of every key, from oldest to newest
+    pub fn kids(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(|(kid, _)| kid.as_str())
+    }
+
+    /// Produces the `kid:hash` join key of a [`Rut`] with the active key
+    pub fn join_key(&self, rut: &Rut) -> String {
+        let (kid, key) = self.active();
+
+        format!("{kid}:{}", hash(key, rut))
+    }
+
+    /// Produces the join keys of a stream of RUTs with the active key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{JoinKeyer, Rut};
+    ///
+    /// let keyer = JoinKeyer::new("v1", b"secret").unwrap();
+    /// let ruts = ["17.951.585-7", "76.086.428-5"].map(|rut| rut.parse::<Rut>().unwrap());
+    ///
+    /// assert_eq!(keyer.join_keys(ruts).count(), 2);
+    /// ```
+    pub fn join_keys<'a, I>(&'a self, ruts: I) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator<Item = Rut>,
+        I::IntoIter: 'a,
+    {
+        ruts.into_iter().map(|rut| self.join_key(&rut))
+    }
+
+    /// Checks whether a `kid:hash` join key was produced from `rut` by any
+    /// of the keys held, retired ones included
+    pub fn verify(&self, rut: &Rut, join_key: &str) -> bool {
+        let Some((kid, want)) = join_key.split_once(':') else {
+            return false;
+        };
+
+        self.keys
+            .iter()
+            .find(|(used, _)| used == kid)
+            .is_some_and(|(_, key)| {
+                // Hex encoded hashes have a fixed length, comparing the
+                // decoded hashes keeps the comparison in constant time
+                blake3::Hash::from_hex(want).is_ok_and(|want| want == keyed(key, rut))
+            })
+    }
+
+    /// Retrieves the active key
+    fn active(&self) -> &(String, [u8; 32]) {
+        self.keys.last().expect("A JoinKeyer always holds a key")
+    }
+}
+
+/// Key material is left out on purpose
+impl std::fmt::Debug for JoinKeyer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JoinKeyer")
+            .field("kids", &self.kids().collect::<Vec<&str>>())
+            .finish()
+    }
+}
+
+/// Keyed BLAKE3 hash of the RUT written in [`Format::Sans`]
+fn keyed(key: &[u8; 32], rut: &Rut) -> blake3::Hash {
+    blake3::keyed_hash(key, rut.format(Format::Sans).as_bytes())
+}
+
+/// Lowercase hexadecimal [`keyed`] hash
+fn hash(key: &[u8; 32], rut: &Rut) -> String {
+    keyed(key, rut).to_hex().to_string()
+}
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use ::serde_json::Value;
+
+use crate::{Error, Format, Num, Rut};
+
+impl Rut {
+    /// Encodes the RUT as a JSON string in the provided [`Format`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Format, Rut};
+    /// use serde_json::json;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert_eq!(rut.to_json_value(Format::Dots), json!("17.951.585-7"));
+    /// ```
+    pub fn to_json_value(&self, fmt: Format) -> Value {
+        Value::String(self.format(fmt))
+    }
+
+    /// Decodes and verifies a RUT from a JSON value, for codepaths handling
+    /// untyped JSON.
+    ///
+    /// Strings are parsed as in [`Rut::from_str`] and numbers are read as
+    /// the RUT's number ([`Num`]), as written by compact serializers.
+    /// Negative, fractional and too large numbers fail with
+    /// [`Error::OutOfRange`], any other value with [`Error::InvalidFormat`].
+    ///
+    /// For every [`Rut`] and [`Format`] the following holds:
+    /// `Rut::from_json_value(&rut.to_json_value(fmt)) == Ok(rut)`.
+    ///
+    /// [`Rut::from_str`]: std::str::FromStr::from_str
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    /// use serde_json::json;
+    ///
+    /// let payload = json!({ "rut": "17.951.585-7", "legacy": 17951585 });
+    ///
+    /// assert_eq!(
+    ///     Rut::from_json_value(&payload["rut"]).unwrap(),
+    ///     Rut::from_json_value(&payload["legacy"]).unwrap()
+    /// );
+    /// assert!(Rut::from_json_value(&json!("17.951.585-8")).is_err());
+    /// assert!(Rut::from_json_value(&payload["missing"]).is_err());
+    /// ```
+    pub fn from_json_value(value: &Value) -> Result<Self, Error> {
+        match value {
+            Value::String(value) => Rut::from_str(value),
+            Value::Number(value) => {
+                let num = value
+                    .as_u64()
+                    .ok_or(Error::OutOfRange)
+                    .and_then(|value| Num::try_from(value).map_err(|_| Error::OutOfRange))?;
+
+                Rut::try_from(num)
+            }
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+}
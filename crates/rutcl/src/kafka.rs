@@ -0,0 +1,99 @@
+//! Helpers to produce Kafka records keyed by [`Rut`].
+//!
+//! Kafka's default partitioner assigns keyed records to the partition
+//! `toPositive(murmur2(key)) % partitions`. [`partition_for`] and
+//! [`partition_for_key`] reproduce it, so producers written in Rust and JVM
+//! producers using the default partitioner send records with the same key
+//! to the same partition, preserving per-RUT ordering.
+//!
+//! # Example
+//!
+//! ```
+//! use rutcl::{kafka, Rut};
+//!
+//! let rut: Rut = "17.951.585-7".parse().unwrap();
+//!
+//! // JVM producers keying by `IntegerSerializer` over the RUT's number
+//! assert_eq!(rut.to_kafka_key(), 17_951_585_i32.to_be_bytes());
+//! assert!(kafka::partition_for(&rut, 12) < 12);
+//!
+//! // JVM producers keying by `StringSerializer` over the sans format
+//! assert!(kafka::partition_for_key(b"179515857", 12) < 12);
+//! ```
+
+use crate::Rut;
+
+/// Seed used by Kafka's murmur2 implementation
+const SEED: u32 = 0x9747_b28c;
+
+/// Mixing constant of the murmur2 hash
+const M: u32 = 0x5bd1_e995;
+
+/// Shift applied by the murmur2 hash
+const R: u32 = 24;
+
+impl Rut {
+    /// Retrieves the compact Kafka record key for this [`Rut`], the RUT's
+    /// number as 4 big endian bytes.
+    ///
+    /// The key matches the bytes Kafka's `IntegerSerializer` writes for the
+    /// same number, the verification digit is omitted as it is derived from
+    /// the number.
+    pub fn to_kafka_key(&self) -> [u8; 4] {
+        self.num().to_be_bytes()
+    }
+}
+
+/// Retrieves the partition Kafka's default partitioner assigns to records
+/// keyed by [`Rut::to_kafka_key`].
+///
+/// # Panics
+///
+/// Panics if `partitions` is `0`.
+pub fn partition_for(rut: &Rut, partitions: u32) -> u32 {
+    partition_for_key(&rut.to_kafka_key(), partitions)
+}
+
+/// Retrieves the partition Kafka's default partitioner assigns to records
+/// with the provided key bytes.
+///
+/// # Panics
+///
+/// Panics if `partitions` is `0`.
+pub fn partition_for_key(key: &[u8], partitions: u32) -> u32 {
+    assert!(partitions > 0, "partitions must be greater than 0");
+
+    (murmur2(key) & 0x7fff_ffff) as u32 % partitions
+}
+
+/// Hashes the provided bytes as Kafka's `Utils.murmur2` does
+pub fn murmur2(data: &[u8]) -> i32 {
+    let mut h = SEED ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+
+    if !tail.is_empty() {
+        for (idx, byte) in tail.iter().enumerate() {
+            h ^= (*byte as u32) << (8 * idx);
+        }
+
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    h as i32
+}
@@ -0,0 +1,94 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use crate::{Num, Rut, RutBand};
+
+/// RUT numbers made of a single repeated digit, or counting up, commonly
+/// typed as placeholders
+pub(crate) const PLACEHOLDER_NUMS: [Num; 18] = [
+    1_111_111, 2_222_222, 3_333_333, 4_444_444, 5_555_555, 6_666_666, 7_777_777, 8_888_888,
+    9_999_999, 11_111_111, 22_222_222, 33_333_333, 44_444_444, 55_555_555, 66_666_666, 77_777_777,
+    88_888_888, 12_345_678,
+];
+
+/// Series some institutions assign to foreigners without a RUN ("extranjero
+/// sin RUN"), such as `44.444.446-0`
+pub(crate) const NO_RUN_NUMS: RangeInclusive<Num> = 44_444_440..=44_444_449;
+
+/// Kind of taxpayer a [`Rut`] stands for, as told by its number.
+///
+/// Placeholders are numbers which don't identify a taxpayer: the RUTs
+/// commonly typed when the real one is unknown (e.g. `11.111.111-1` or
+/// `12.345.678-5`) and the `44.444.44X` series some institutions assign to
+/// foreigners without a RUN. Every other RUT is classified by its
+/// [`RutBand`].
+///
+/// > As with bands, the classification follows conventions, it is not a
+/// > legal guarantee of the taxpayer kind.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutKind};
+///
+/// let kind = |rut: &str| rut.parse::<Rut>().unwrap().kind();
+///
+/// assert_eq!(kind("17.951.585-7"), RutKind::Person);
+/// assert_eq!(kind("76.086.428-5"), RutKind::Company);
+/// assert_eq!(kind("46.000.000-9"), RutKind::ForeignInvestor);
+/// assert_eq!(kind("44.444.446-0"), RutKind::Placeholder);
+/// assert_eq!(kind("11.111.111-1"), RutKind::Placeholder);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RutKind {
+    /// Natural person, in the [`RutBand::Persons`] band
+    Person,
+    /// Company, in the [`RutBand::Companies`] band
+    Company,
+    /// Foreign investor, in the [`RutBand::ForeignInvestors`] band
+    ForeignInvestor,
+    /// Placeholder not identifying a taxpayer
+    Placeholder,
+}
+
+impl RutKind {
+    /// Retrieves the kind's name, as used by its [`Display`] implementation
+    pub const fn name(&self) -> &'static str {
+        match self {
+            RutKind::Person => "person",
+            RutKind::Company => "company",
+            RutKind::ForeignInvestor => "foreign_investor",
+            RutKind::Placeholder => "placeholder",
+        }
+    }
+}
+
+impl Display for RutKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl Rut {
+    /// Classifies this RUT, see [`RutKind`]
+    pub fn kind(&self) -> RutKind {
+        if self.is_placeholder() {
+            return RutKind::Placeholder;
+        }
+
+        if self.in_foreign_investor_band() {
+            return RutKind::ForeignInvestor;
+        }
+
+        if RutBand::Persons.contains(self) {
+            RutKind::Person
+        } else {
+            RutKind::Company
+        }
+    }
+
+    /// Checks whether this RUT is a placeholder, see [`RutKind`]
+    pub fn is_placeholder(&self) -> bool {
+        PLACEHOLDER_NUMS.contains(&self.num()) || NO_RUN_NUMS.contains(&self.num())
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use crate::{Format, Num, Rut};
+
+/// Multiplier spreading consecutive RUT numbers over buckets (Fibonacci
+/// hashing)
+const SPREAD: u64 = 0x9e37_79b9_7f4a_7c15;
+
+impl Rut {
+    /// Retrieves a metric label value for this [`Rut`], written in
+    /// [`Format::Sans`] so every spelling of a RUT yields the same label.
+    ///
+    /// Labeling metrics by RUT creates a time series per customer, use a
+    /// [`LabelBudget`] to cap the cardinality.
+    pub fn to_metric_label(&self) -> String {
+        self.format(Format::Sans)
+    }
+}
+
+/// Caps the amount of distinct RUT label values given to metrics.
+///
+/// The first `max_tracked` distinct RUTs are labeled with
+/// [`Rut::to_metric_label`], every other RUT is labeled with one of
+/// `buckets` bucket labels (`bucket-0`, `bucket-1`, ...), picked by hashing
+/// the RUT's number so a RUT always lands in the same bucket.
+///
+/// The budget can be shared between threads.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{LabelBudget, Rut};
+///
+/// let budget = LabelBudget::new(1, 4);
+/// let a: Rut = "17.951.585-7".parse().unwrap();
+/// let b: Rut = "76.086.428-5".parse().unwrap();
+///
+/// assert_eq!(budget.label(&a), "179515857");
+/// assert!(budget.label(&b).starts_with("bucket-"));
+/// assert_eq!(budget.label(&a), "179515857");
+/// assert_eq!(budget.tracked(), 1);
+/// ```
+#[derive(Debug)]
+pub struct LabelBudget {
+    max_tracked: usize,
+    buckets: u32,
+    tracked: Mutex<HashSet<Num>>,
+}
+
+impl LabelBudget {
+    /// Creates a [`LabelBudget`] labeling up to `max_tracked` distinct RUTs
+    /// individually, and the rest in `buckets` buckets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buckets` is `0`.
+    pub fn new(max_tracked: usize, buckets: u32) -> Self {
+        assert!(buckets > 0, "buckets must be greater than 0");
+
+        LabelBudget {
+            max_tracked,
+            buckets,
+            tracked: Mutex::new(HashSet::with_capacity(max_tracked)),
+        }
+    }
+
+    /// Retrieves the label for the provided [`Rut`], tracking it if the
+    /// budget allows it
+    pub fn label(&self, rut: &Rut) -> String {
+        let mut tracked = self.tracked.lock().unwrap_or_else(|err| err.into_inner());
+
+        if tracked.contains(&rut.num()) || tracked.len() < self.max_tracked {
+            tracked.insert(rut.num());
+
+            return rut.to_metric_label();
+        }
+
+        format!("bucket-{}", self.bucket(rut))
+    }
+
+    /// Retrieves the bucket the provided [`Rut`] is labeled with once the
+    /// budget is exhausted
+    pub fn bucket(&self, rut: &Rut) -> u32 {
+        ((rut.num() as u64).wrapping_mul(SPREAD) >> 32) as u32 % self.buckets
+    }
+
+    /// Retrieves the amount of RUTs labeled individually
+    pub fn tracked(&self) -> usize {
+        self.tracked
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .len()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Format, Rut, VerificationDigit};
+
+/// Controls how [`Rut::format_custom`] writes a RUT, for layouts not covered
+/// by [`Format`] such as space or apostrophe separators, a lowercase `k` or
+/// zero padded numbers.
+///
+/// Options mirroring each [`Format`] are available as constants and through
+/// `From<Format>`, use struct update syntax to tweak them.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{FormatOptions, Rut};
+///
+/// let rut: Rut = "9.263.584-8".parse().unwrap();
+/// let options = FormatOptions {
+///     thousands_sep: Some('\''),
+///     pad_width: 8,
+///     ..FormatOptions::DOTS
+/// };
+///
+/// assert_eq!(rut.format_custom(&options), "09'263'584-8");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Separator written between groups of three digits of the number
+    pub thousands_sep: Option<char>,
+    /// Separator written before the verification digit
+    pub dash: Option<char>,
+    /// Writes [`VerificationDigit::K`] as `K` when `true`, as `k` otherwise
+    pub uppercase_k: bool,
+    /// Minimum amount of digits of the number, padded with leading zeros
+    pub pad_width: usize,
+}
+
+impl FormatOptions {
+    /// Same output as [`Format::Sans`], e.g. `179515857`
+    pub const SANS: Self = FormatOptions {
+        thousands_sep: None,
+        dash: None,
+        uppercase_k: true,
+        pad_width: 0,
+    };
+
+    /// Same output as [`Format::Dash`], e.g. `17951585-7`
+    pub const DASH: Self = FormatOptions {
+        dash: Some('-'),
+        ..FormatOptions::SANS
+    };
+
+    /// Same output as [`Format::Dots`], e.g. `17.951.585-7`
+    pub const DOTS: Self = FormatOptions {
+        thousands_sep: Some('.'),
+        ..FormatOptions::DASH
+    };
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::DOTS
+    }
+}
+
+impl From<Format> for FormatOptions {
+    fn from(fmt: Format) -> Self {
+        match fmt {
+            Format::Sans => FormatOptions::SANS,
+            Format::Dash => FormatOptions::DASH,
+            Format::Dots => FormatOptions::DOTS,
+        }
+    }
+}
+
+impl Rut {
+    /// Formats the RUT following the provided [`FormatOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{FormatOptions, Rut};
+    ///
+    /// let rut: Rut = "92.635.843-K".parse().unwrap();
+    /// let legacy = FormatOptions {
+    ///     thousands_sep: Some(' '),
+    ///     uppercase_k: false,
+    ///     ..FormatOptions::DOTS
+    /// };
+    ///
+    /// assert_eq!(rut.format_custom(&legacy), "92 635 843-k");
+    /// assert_eq!(rut.format_custom(&FormatOptions::DASH), "92635843-K");
+    /// ```
+    pub fn format_custom(&self, options: &FormatOptions) -> String {
+        let digits = format!("{:0width$}", self.num(), width = options.pad_width);
+        let mut result = String::with_capacity(digits.len() * 2 + 2);
+
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx) % 3 == 0 {
+                result.extend(options.thousands_sep);
+            }
+
+            result.push(digit);
+        }
+
+        result.extend(options.dash);
+
+        match self.vd() {
+            VerificationDigit::K if !options.uppercase_k => result.push('k'),
+            vd => result.push(vd.into()),
+        }
+
+        result
+    }
+}
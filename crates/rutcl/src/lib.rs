@@ -1,52 +1,513 @@
+#[cfg(feature = "tokio")]
+pub mod aio;
+
+#[cfg(feature = "anonymize")]
+pub mod anonymize;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+mod ascii;
+
+mod band;
+
+pub mod barcode;
+
+mod batch;
+
+mod borrowed;
+
+pub mod codegen;
+
+mod correcting;
+
+pub mod credential;
+
+#[cfg(feature = "dataset")]
+pub mod dataset;
+
+pub mod db;
+
+mod distance;
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+mod embedded;
+
+#[cfg(feature = "heuristics")]
+mod era;
+
+pub mod explain;
+
+mod extract;
+
+#[cfg(feature = "regex")]
+mod extractor;
+
+pub mod fixedwidth;
+
+pub mod fixture;
+
+mod foreign;
+
+mod fuzz;
+
+mod global;
+
+#[cfg(feature = "winnow")]
+mod grammar;
+
+pub mod http;
+
+mod id;
+
+mod interner;
+
+#[cfg(feature = "blake3")]
+mod join;
+
+#[cfg(feature = "serde_json")]
+mod json;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+mod kind;
+
+mod label;
+
+mod layout;
+
+mod literal;
+
+#[cfg(feature = "loadgen")]
+pub mod loadgen;
+
+mod national_id;
+
+mod parser;
+
+mod parts;
+
+mod partition;
+
+#[cfg(feature = "polars")]
+pub mod polars;
+
+mod policy;
+
+mod prefix;
+
+#[cfg(any(feature = "subtle", feature = "blake3"))]
+mod privacy;
+
+mod query;
+
+#[cfg(feature = "rand")]
+mod random;
+
+mod range;
+
+#[cfg(feature = "blake3")]
+mod rotating;
+
+#[cfg(feature = "rand")]
+mod sample;
+
+mod sanitize;
+
+mod shuffle;
+
+#[cfg(feature = "smallstr")]
+mod smol;
+
+mod span;
+
+mod spanish;
+
+pub mod sii;
+
+mod slice;
+
+#[cfg(feature = "async")]
+pub mod stream;
+
+pub mod store;
+
+mod subject;
+
+mod title;
+
+mod unverified;
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+pub mod telemetry;
+
+#[cfg(feature = "bson")]
+mod bson;
+
+#[cfg(feature = "calamine")]
+pub mod calamine;
+
+#[cfg(feature = "clap")]
+mod clap;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "miette")]
+mod miette;
+
+#[cfg(feature = "redis")]
+mod redis;
+
+#[cfg(feature = "roaring")]
+mod roaring;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "wire")]
+pub mod wire;
+
 #[cfg(test)]
 mod tests;
 
+#[cfg(all(test, feature = "derive"))]
+extern crate self as rutcl;
+
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::hash_map::RandomState;
-use std::fmt::Display;
-use std::hash::{BuildHasher, Hasher};
-use std::num::ParseIntError;
+use std::env::VarError;
+use std::fmt::{Debug, Display};
+use std::num::{IntErrorKind, ParseIntError};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 #[cfg(feature = "serde")]
 use std::fmt;
 
-use thiserror::Error;
+pub use band::{
+    CompanyRut, CustomBand, PersonRut, RutBand, COMPANY_MIN_NUM, FOREIGN_INVESTOR_MAX_NUM,
+    FOREIGN_INVESTOR_MIN_NUM, PERSON_MAX_NUM,
+};
+pub use batch::BatchLine;
+pub use borrowed::RutRef;
+pub use correcting::CorrectingRut;
+pub use distance::similarity;
+pub use extract::RutMatch;
+pub use foreign::ForeignTaxId;
+pub use fuzz::fuzz_check;
+pub use global::{default_format, set_default_format};
+pub use id::RutId;
+pub use interner::RutInterner;
+pub use kind::RutKind;
+pub use label::LabelBudget;
+pub use layout::FormatOptions;
+pub use national_id::{Checksum, Mod11Checksum, NationalId, RutChecksum};
+pub use parser::{RutParser, RutParserBuilder};
+pub use partition::RutPartitioner;
+pub use parts::{RutPart, RutParts};
+pub use policy::ValidationPolicy;
+pub use query::RutQuery;
+pub use range::{RutRange, RutRangeExt};
+pub use shuffle::RutShuffle;
+pub use slice::{RutSliceExt, RutVecExt};
+pub use title::{LabeledRut, Language, RutTitle};
+pub use unverified::UnverifiedRut;
+
+#[cfg(feature = "rand")]
+pub use random::RandomOptions;
+
+#[cfg(feature = "heuristics")]
+pub use era::RegistrationEra;
+
+#[cfg(feature = "roaring")]
+pub use roaring::RutBitmapSet;
+
+#[cfg(feature = "regex")]
+pub use extractor::{Extractor, ExtractorBuilder, Overlap};
+
+#[cfg(feature = "clap")]
+pub use clap::RutValueParser;
+
+#[cfg(feature = "miette")]
+pub use miette::InputError;
+
+#[cfg(feature = "blake3")]
+pub use join::JoinKeyer;
+#[cfg(feature = "blake3")]
+pub use privacy::Pseudonym;
+#[cfg(feature = "blake3")]
+pub use rotating::{ROTATING_CODE_DIGITS, ROTATING_CODE_STEP};
+pub use sanitize::SanitizeOptions;
+
+/// Derives `FromStr`, `Display` and conversions from and into [`Rut`] for
+/// domain newtypes wrapping a [`Rut`].
+///
+/// Use `#[rut(serde)]` to also derive `Serialize` and `Deserialize` when the
+/// `serde` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutNewtype};
+///
+/// #[derive(RutNewtype)]
+/// struct EmployerRut(Rut);
+///
+/// let employer: EmployerRut = "76.086.428-5".parse().unwrap();
+///
+/// assert_eq!(employer.to_string(), "760864285");
+/// ```
+#[cfg(feature = "derive")]
+pub use rutcl_derive::RutNewtype;
+
+/// Dependencies referenced by the code [`RutNewtype`] expands to, so
+/// deriving crates don't need to depend on them. Not part of the public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+
+    #[cfg(feature = "sqlx")]
+    pub use ::sqlx;
+}
 
 #[cfg(feature = "rand")]
 use rand::distributions::uniform::SampleRange;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "serde")]
-use serde::de::Visitor;
+use ::serde::de::Visitor;
 
-#[derive(Clone, Debug, Error)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum Error {
-    #[error("Invalid verification digit: have {have}, want {want}")]
-    InvalidVerificationDigit { have: char, want: char },
-    #[error("Verification digit out of bounds found: {0}")]
+    InvalidVerificationDigit {
+        have: char,
+        want: char,
+    },
     VerificationDigitOutOfBounds(String),
-    #[error("Invalid format")]
     InvalidFormat,
-    #[error("Provided string is not a number. {0}")]
     NaN(ParseIntError),
-    #[error("Out of range")]
     OutOfRange,
-    #[error("The provided string is empty")]
     EmptyString,
+    OutOfBand(RutBand),
+    UnsupportedWireVersion(u8),
+    EnvVar {
+        var: String,
+        source: VarError,
+    },
+    InvalidEnvVar {
+        var: String,
+        value: String,
+        source: Box<Error>,
+    },
+    InvalidValue {
+        value: String,
+        source: Box<Error>,
+    },
+    SiiSchema {
+        rule: sii::Rule,
+        value: String,
+    },
+    RejectedBand(RutBand),
+    LooksLikeForeignTaxId {
+        guess: ForeignTaxId,
+    },
+    InvalidPart {
+        part: RutPart,
+        source: Box<Error>,
+    },
+    Syntax {
+        offset: usize,
+        expected: Expected,
+    },
+    Placeholder,
+}
+
+/// What the RUT grammar expected where parsing stopped, reported by
+/// [`Error::Syntax`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Expected {
+    /// A number without leading zeros
+    Number,
+    /// A group of 3 digits, after a `.`
+    Group,
+    /// A `.` separator
+    Dot,
+    /// A `-` separator
+    Dash,
+    /// The RUT's [`VerificationDigit`]
+    VerificationDigit,
+    /// The end of the input
+    EndOfInput,
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number => f.write_str("number without leading zeros"),
+            Expected::Group => f.write_str("group of 3 digits"),
+            Expected::Dot => f.write_str("`.`"),
+            Expected::Dash => f.write_str("`-`"),
+            Expected::VerificationDigit => f.write_str("verification digit"),
+            Expected::EndOfInput => f.write_str("end of input"),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidVerificationDigit { have, want } => {
+                write!(f, "Invalid verification digit: have {have}, want {want}")
+            }
+            Error::VerificationDigitOutOfBounds(vd) => {
+                write!(f, "Verification digit out of bounds found: {vd}")
+            }
+            Error::InvalidFormat => f.write_str("Invalid format"),
+            Error::NaN(err) => write!(f, "Provided string is not a number. {err}"),
+            Error::OutOfRange => f.write_str("Out of range"),
+            Error::EmptyString => f.write_str("The provided string is empty"),
+            Error::OutOfBand(band) => write!(f, "Out of band {band:?}"),
+            Error::UnsupportedWireVersion(version) => {
+                write!(f, "Unsupported wire encoding version: {version}")
+            }
+            Error::EnvVar { var, .. } => write!(f, "Environment variable {var} is not available"),
+            Error::InvalidEnvVar { var, value, .. } => {
+                write!(f, "Invalid RUT {value:?} in environment variable {var}")
+            }
+            Error::InvalidValue { value, .. } => write!(f, "Invalid RUT {value:?}"),
+            Error::SiiSchema { rule, value } => {
+                write!(f, "RUT {value:?} violates SII schema rule {rule}")
+            }
+            Error::RejectedBand(band) => write!(f, "RUT in rejected band {band:?}"),
+            Error::LooksLikeForeignTaxId { guess } => {
+                write!(f, "Not a RUT, looks like a foreign tax ID: {guess}")
+            }
+            Error::InvalidPart { part, source } => write!(f, "Invalid RUT {part}: {source}"),
+            Error::Syntax { offset, expected } => {
+                write!(f, "Syntax error at offset {offset}: expected {expected}")
+            }
+            Error::Placeholder => f.write_str("Placeholder RUT"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::EnvVar { source, .. } => Some(source),
+            Error::InvalidEnvVar { source, .. }
+            | Error::InvalidValue { source, .. }
+            | Error::InvalidPart { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// Retrieves a stable, machine friendly name for this error's variant
+    /// (e.g. `invalid_verification_digit`), suitable for metric labels.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::InvalidVerificationDigit { .. } => "invalid_verification_digit",
+            Error::VerificationDigitOutOfBounds(_) => "verification_digit_out_of_bounds",
+            Error::InvalidFormat => "invalid_format",
+            Error::NaN(_) => "nan",
+            Error::OutOfRange => "out_of_range",
+            Error::EmptyString => "empty_string",
+            Error::OutOfBand(_) => "out_of_band",
+            Error::UnsupportedWireVersion(_) => "unsupported_wire_version",
+            Error::EnvVar { .. } => "env_var",
+            Error::InvalidEnvVar { .. } => "invalid_env_var",
+            Error::InvalidValue { .. } => "invalid_value",
+            Error::SiiSchema { .. } => "sii_schema",
+            Error::RejectedBand(_) => "rejected_band",
+            Error::LooksLikeForeignTaxId { .. } => "looks_like_foreign_tax_id",
+            Error::InvalidPart { .. } => "invalid_part",
+            Error::Syntax { .. } => "syntax",
+            Error::Placeholder => "placeholder",
+        }
+    }
+}
+
+/// Builds an [`Error::NaN`] holding the same [`ParseIntError`] that parsing
+/// the RUT's number with [`str::parse`] would produce.
+fn nan(kind: IntErrorKind) -> Error {
+    let src = match kind {
+        IntErrorKind::Empty => "",
+        IntErrorKind::PosOverflow => "4294967296",
+        _ => "NaN",
+    };
+
+    Error::NaN(
+        src.parse::<Num>()
+            .expect_err("Source is not a valid number"),
+    )
+}
+
+/// Splits the RUT-like identifier written by `chars` into its number and
+/// its verification digit char, skipping separators
+pub(crate) fn split_chars<I>(chars: I, options: SanitizeOptions) -> Result<(Num, char), Error>
+where
+    I: Iterator<Item = char>,
+{
+    let mut num: Option<Num> = None;
+    let mut last: Option<char> = None;
+
+    for c in chars.filter(|c| !options.is_separator(*c)) {
+        // The previous char was not the last one, so it belongs to the
+        // RUT's number
+        if let Some(prev) = last.replace(c) {
+            let Some(digit) = prev.to_digit(10) else {
+                return Err(nan(IntErrorKind::InvalidDigit));
+            };
+
+            num = Some(
+                num.unwrap_or_default()
+                    .checked_mul(10)
+                    .and_then(|num| num.checked_add(digit))
+                    .ok_or_else(|| nan(IntErrorKind::PosOverflow))?,
+            );
+        }
+    }
+
+    let Some(input_vd) = last else {
+        return Err(Error::EmptyString);
+    };
+
+    let Some(num) = num else {
+        return Err(nan(IntErrorKind::Empty));
+    };
+
+    Ok((num, input_vd))
 }
 
 /// RUT's Number without the [`VerificationDigit`]
 pub type Num = u32;
 
 /// Max number for a RUT without the Verification Digit
-const MAX_NUM: u32 = 99_999_999;
+pub const MAX_NUM: Num = 99_999_999;
 
 /// Min number for a RUT without the Verification Digit
-const MIN_NUM: u32 = 1_000_000;
+pub const MIN_NUM: Num = 1_000_000;
 
 /// Min value for a RUT
 pub const MIN: Rut = Rut(MIN_NUM, VerificationDigit::Nine);
@@ -54,8 +515,15 @@ pub const MIN: Rut = Rut(MIN_NUM, VerificationDigit::Nine);
 /// Max value for a RUT
 pub const MAX: Rut = Rut(MAX_NUM, VerificationDigit::Nine);
 
-/// RUT value range
-const RANGE: RangeInclusive<u32> = MIN_NUM..=MAX_NUM;
+/// Range of valid RUT numbers, from [`MIN_NUM`] to [`MAX_NUM`]
+///
+/// # Example
+///
+/// ```
+/// assert!(rutcl::RANGE.contains(&17_951_585));
+/// assert!(!rutcl::RANGE.contains(&999_999));
+/// ```
+pub const RANGE: RangeInclusive<Num> = MIN_NUM..=MAX_NUM;
 
 /// Product factor for RUT's Verification Digit Calculation
 const FACTOR: [u32; 6] = [2, 3, 4, 5, 6, 7];
@@ -67,22 +535,69 @@ const SYMBOLS: u32 = 11;
 /// Chilean RUT's Verification Digit
 ///
 /// Refer: https://es.wikipedia.org/wiki/Rol_Único_Tributario
+///
+/// With the `strum` feature this derives `strum::EnumIter`,
+/// `strum::EnumCount` and `strum::IntoStaticStr`, the latter yielding the
+/// digit as written in a RUT. `FromStr` is implemented by hand, accepting a
+/// lowercase `k` as well.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "strum",
+    derive(strum::EnumIter, strum::EnumCount, strum::IntoStaticStr)
+)]
 pub enum VerificationDigit {
+    #[cfg_attr(feature = "strum", strum(serialize = "0"))]
     Zero,
+    #[cfg_attr(feature = "strum", strum(serialize = "1"))]
     One,
+    #[cfg_attr(feature = "strum", strum(serialize = "2"))]
     Two,
+    #[cfg_attr(feature = "strum", strum(serialize = "3"))]
     Three,
+    #[cfg_attr(feature = "strum", strum(serialize = "4"))]
     Four,
+    #[cfg_attr(feature = "strum", strum(serialize = "5"))]
     Five,
+    #[cfg_attr(feature = "strum", strum(serialize = "6"))]
     Six,
+    #[cfg_attr(feature = "strum", strum(serialize = "7"))]
     Seven,
+    #[cfg_attr(feature = "strum", strum(serialize = "8"))]
     Eight,
+    #[cfg_attr(feature = "strum", strum(serialize = "9"))]
     Nine,
     /// `K` Represent the Verification Digit `K`, which is equivalent to `10`
     K,
 }
 
+impl VerificationDigit {
+    /// Every [`VerificationDigit`], from [`VerificationDigit::Zero`] to
+    /// [`VerificationDigit::K`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::VerificationDigit;
+    ///
+    /// let options = VerificationDigit::ALL.map(|vd| vd.to_string());
+    ///
+    /// assert_eq!(options.concat(), "0123456789K");
+    /// ```
+    pub const ALL: [VerificationDigit; 11] = [
+        VerificationDigit::Zero,
+        VerificationDigit::One,
+        VerificationDigit::Two,
+        VerificationDigit::Three,
+        VerificationDigit::Four,
+        VerificationDigit::Five,
+        VerificationDigit::Six,
+        VerificationDigit::Seven,
+        VerificationDigit::Eight,
+        VerificationDigit::Nine,
+        VerificationDigit::K,
+    ];
+}
+
 impl Ord for VerificationDigit {
     fn cmp(&self, other: &Self) -> Ordering {
         self.to_u32().cmp(&other.to_u32())
@@ -109,28 +624,39 @@ impl VerificationDigit {
     ///
     /// The result is the Verification Digit.
     pub fn new(num: Num) -> Result<Self, Error> {
-        let mut digits = num
-            .to_string()
-            .chars()
-            .rev()
-            .map(|c| c.to_digit(10).expect("This code is unrachable"))
-            .collect::<Vec<u32>>();
+        Ok(Self::compute(num))
+    }
+
+    /// Computes the [`VerificationDigit`] for a RUT's body in a `const`
+    /// context. Refer to [`VerificationDigit::new`] for details.
+    pub(crate) const fn compute(num: Num) -> Self {
+        let mut rest = num;
         let mut factor: usize = 0;
         let mut sum = 0;
 
         // Pop each digit from the backwards representation of RUT's body
         // and multiply it by the corresponding factor
-        for digit in digits.iter_mut() {
-            sum += *digit * (FACTOR[factor]);
+        while rest > 0 {
+            sum += (rest % 10) * FACTOR[factor];
+            rest /= 10;
             factor = (factor + 1) % 6;
         }
 
-        // let remaining = (sum % SYMBOLS) as u32;
-        let whole = sum / SYMBOLS;
-        let base = sum - (SYMBOLS * whole);
-        let digit = SYMBOLS - base;
-
-        Self::from_u32(digit)
+        // The remainder is always lower than `SYMBOLS`, so the digit is
+        // always in the `1..=11` range
+        match SYMBOLS - (sum % SYMBOLS) {
+            1 => VerificationDigit::One,
+            2 => VerificationDigit::Two,
+            3 => VerificationDigit::Three,
+            4 => VerificationDigit::Four,
+            5 => VerificationDigit::Five,
+            6 => VerificationDigit::Six,
+            7 => VerificationDigit::Seven,
+            8 => VerificationDigit::Eight,
+            9 => VerificationDigit::Nine,
+            10 => VerificationDigit::K,
+            _ => VerificationDigit::Zero,
+        }
     }
 
     pub fn from_u32(value: u32) -> Result<Self, Error> {
@@ -165,6 +691,45 @@ impl VerificationDigit {
             VerificationDigit::K => 10,
         }
     }
+
+    /// Checks whether this is the [`VerificationDigit::K`] digit
+    #[inline]
+    pub const fn is_k(&self) -> bool {
+        matches!(self, VerificationDigit::K)
+    }
+}
+
+impl TryFrom<u8> for VerificationDigit {
+    type Error = Error;
+
+    /// Maps values from `0` to `9` to their digit and `10` to
+    /// [`VerificationDigit::K`], the inverse of `From<VerificationDigit> for
+    /// u8`.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        VerificationDigit::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or_else(|| Error::VerificationDigitOutOfBounds(value.to_string()))
+    }
+}
+
+impl TryFrom<u64> for VerificationDigit {
+    type Error = Error;
+
+    /// Same as `TryFrom<u8>`
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| VerificationDigit::try_from(value).ok())
+            .ok_or_else(|| Error::VerificationDigitOutOfBounds(value.to_string()))
+    }
+}
+
+impl From<VerificationDigit> for u8 {
+    /// Maps digits to their value and [`VerificationDigit::K`] to `10`
+    fn from(vd: VerificationDigit) -> Self {
+        vd.to_u32() as u8
+    }
 }
 
 impl TryFrom<char> for VerificationDigit {
@@ -221,7 +786,7 @@ impl FromStr for VerificationDigit {
             "7" => Ok(VerificationDigit::Seven),
             "8" => Ok(VerificationDigit::Eight),
             "9" => Ok(VerificationDigit::Nine),
-            "K" => Ok(VerificationDigit::K),
+            "K" | "k" => Ok(VerificationDigit::K),
             _ => Err(Error::VerificationDigitOutOfBounds(input.to_string())),
         }
     }
@@ -243,12 +808,21 @@ impl Display for VerificationDigit {
             VerificationDigit::K => "K",
         };
 
-        write!(f, "{value}")
+        f.pad(value)
     }
 }
 
 /// Format for RUT's string representation
-#[derive(Copy, Clone, Debug)]
+///
+/// With the `strum` feature this derives `strum::EnumIter`,
+/// `strum::EnumCount` and `strum::IntoStaticStr`, the latter yielding
+/// [`Format::name`]. `FromStr` is implemented by hand and ignores case.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "strum",
+    derive(strum::EnumIter, strum::EnumCount, strum::IntoStaticStr),
+    strum(serialize_all = "lowercase")
+)]
 pub enum Format {
     /// No special characters. the RUT is formatted as a continuous set of
     /// digits followed by the verification digit without dash or dots.
@@ -261,7 +835,109 @@ pub enum Format {
     Dots,
 }
 
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+impl Format {
+    /// Detects the [`Format`] a RUT string is written in, without parsing
+    /// it.
+    ///
+    /// Returns `None` if the input mixes formats, has misplaced separators or
+    /// doesn't end with a verification digit. A `Some` result doesn't mean
+    /// the RUT is valid, use [`Rut::from_formatted`] to parse it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Format;
+    ///
+    /// assert_eq!(Format::detect("179515857"), Some(Format::Sans));
+    /// assert_eq!(Format::detect("17951585-7"), Some(Format::Dash));
+    /// assert_eq!(Format::detect("17.951.585-7"), Some(Format::Dots));
+    /// assert_eq!(Format::detect("17.951585-7"), None);
+    /// ```
+    pub fn detect(input: &str) -> Option<Format> {
+        let vd = input.chars().last()?;
+
+        if !matches!(vd, '0'..='9' | 'K' | 'k') {
+            return None;
+        }
+
+        [Format::Sans, Format::Dash, Format::Dots]
+            .into_iter()
+            .find(|fmt| fmt.matches(input))
+    }
+
+    /// Name of this format, as accepted by its [`FromStr`] implementation
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Sans => "sans",
+            Format::Dash => "dash",
+            Format::Dots => "dots",
+        }
+    }
+
+    /// Checks whether the separators and digit grouping of the provided
+    /// string follow this format exactly. The RUT's number and verification
+    /// digit are not validated.
+    fn matches(&self, input: &str) -> bool {
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let is_num = |s: &str| is_digits(s) && !s.starts_with('0');
+
+        match self {
+            Format::Sans => input
+                .char_indices()
+                .last()
+                .is_some_and(|(idx, _)| is_num(&input[..idx])),
+            Format::Dash => input
+                .rsplit_once('-')
+                .is_some_and(|(num, vd)| is_num(num) && vd.chars().count() == 1),
+            Format::Dots => input.rsplit_once('-').is_some_and(|(num, vd)| {
+                let mut groups = num.split('.');
+                let head = groups.next().unwrap_or_default();
+
+                vd.chars().count() == 1
+                    && num.contains('.')
+                    && is_num(head)
+                    && head.len() <= 3
+                    && groups.all(|group| group.len() == 3 && is_digits(group))
+            }),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    /// Parses a [`Format`] from its name: `sans`, `dash` or `dots`, ignoring
+    /// case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Format;
+    ///
+    /// assert_eq!("dots".parse::<Format>().unwrap(), Format::Dots);
+    /// assert_eq!("DASH".parse::<Format>().unwrap(), Format::Dash);
+    /// assert!("spaces".parse::<Format>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        [Format::Sans, Format::Dash, Format::Dots]
+            .into_iter()
+            .find(|fmt| fmt.name().eq_ignore_ascii_case(input))
+            .ok_or(Error::InvalidFormat)
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Chilean RUT, made of a number ([`Num`]) and its [`VerificationDigit`].
+///
+/// RUTs are ordered by their number. Since the verification digit is
+/// derived from the number, `Ord` is consistent with `PartialEq` and
+/// `Hash`: two RUTs compare as equal only when they are equal.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Rut(Num, VerificationDigit);
 
 impl Rut {
@@ -282,7 +958,27 @@ impl Rut {
     }
 
     /// Generates a random [`Rut`] instance.
+    ///
+    /// With the `rand` feature the number is drawn from `rand`'s thread
+    /// local generator. Enable `wasm-rand` instead when targeting
+    /// `wasm32-unknown-unknown`, where it is seeded from the browser's
+    /// `crypto.getRandomValues` and the fallback below would yield the same
+    /// RUT on every run.
+    #[cfg(feature = "rand")]
+    pub fn random() -> Result<Self, Error> {
+        Rut::random_in_range(RANGE)
+    }
+
+    /// Generates a random [`Rut`] instance.
+    ///
+    /// Without the `rand` feature the number is derived from the standard
+    /// library's randomly keyed hasher, enable `rand` or `wasm-rand` for
+    /// proper randomness.
+    #[cfg(not(feature = "rand"))]
     pub fn random() -> Result<Self, Error> {
+        use std::collections::hash_map::RandomState;
+        use std::hash::{BuildHasher, Hasher};
+
         let hasher = RandomState::new().build_hasher();
         let num = hasher.finish() as u32 % MAX_NUM;
         let vd = VerificationDigit::new(num)?;
@@ -338,49 +1034,180 @@ impl Rut {
         }
     }
 
-    /// Retrieves a "sans" RUT version.
+    /// Parses a RUT which must be written exactly in the provided [`Format`].
+    ///
+    /// Unlike [`Rut::from_str`], which strips every separator before parsing,
+    /// this fails with [`Error::InvalidFormat`] if the input is written in a
+    /// different format or has misplaced separators.
+    ///
+    /// For every [`Rut`] and [`Format`] the following holds:
+    /// `Rut::from_formatted(&rut.format(fmt), fmt) == Ok(rut)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Format, Rut};
+    ///
+    /// let rut = Rut::from_formatted("17.951.585-7", Format::Dots).unwrap();
+    ///
+    /// assert_eq!(rut.format(Format::Dots), "17.951.585-7");
+    /// assert!(Rut::from_formatted("17951585-7", Format::Dots).is_err());
+    /// assert!(Rut::from_formatted("1.79.51.585-7", Format::Dots).is_err());
+    /// ```
+    pub fn from_formatted(input: &str, fmt: Format) -> Result<Self, Error> {
+        if !fmt.matches(input) {
+            let result = Err(Error::InvalidFormat);
+
+            #[cfg(any(feature = "tracing", feature = "metrics"))]
+            telemetry::record(&result);
+
+            return result;
+        }
+
+        Rut::parse_sanitized(input, SanitizeOptions::STRICT)
+    }
+
+    /// Reads a [`Rut`] from the provided environment variable.
+    ///
+    /// Fails with [`Error::EnvVar`] if the variable is missing and with
+    /// [`Error::InvalidEnvVar`] if its value is not a valid RUT, both holding
+    /// the variable name for descriptive error messages.
     ///
     /// # Example
     ///
     /// ```
     /// use rutcl::Rut;
     ///
-    /// let rut = Rut::sans("17.951.585-7");
+    /// std::env::set_var("INSTITUTION_RUT", "76.086.428-5");
     ///
-    /// assert_eq!(rut, "179515857");
+    /// let rut = Rut::from_env("INSTITUTION_RUT").unwrap();
+    ///
+    /// assert_eq!(rut.num(), 76_086_428);
     /// ```
-    pub fn sans<S: AsRef<str>>(input: S) -> String {
-        input.as_ref().replace(['.', '-'], "")
+    pub fn from_env(var: &str) -> Result<Self, Error> {
+        let value = std::env::var(var).map_err(|source| Error::EnvVar {
+            var: var.to_string(),
+            source,
+        })?;
+
+        Rut::from_str(&value).map_err(|err| Error::InvalidEnvVar {
+            var: var.to_string(),
+            value,
+            source: Box::new(err),
+        })
     }
-}
 
-impl Display for Rut {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let sans = self.format(Format::Sans);
-        write!(f, "{sans}")
+    /// Checks whether the provided string is a valid RUT.
+    ///
+    /// Runs a single pass over the input without allocating, so it is
+    /// suitable for hot paths which only need a yes/no answer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// assert!(Rut::is_valid("17.951.585-7"));
+    /// assert!(!Rut::is_valid("17.951.585-K"));
+    /// ```
+    #[inline]
+    pub fn is_valid(input: &str) -> bool {
+        Self::parse_sanitized(input, SanitizeOptions::default()).is_ok()
     }
-}
 
-impl FromStr for Rut {
-    type Err = Error;
+    /// Validates the provided string as a RUT, reporting the same [`Error`]
+    /// as [`Rut::from_str`] would without building the [`Rut`] instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Error, Rut};
+    ///
+    /// assert!(Rut::validate("17951585-7").is_ok());
+    /// assert!(matches!(
+    ///     Rut::validate("17951585-K"),
+    ///     Err(Error::InvalidVerificationDigit { have: 'K', want: '7' })
+    /// ));
+    /// ```
+    #[inline]
+    pub fn validate(input: &str) -> Result<(), Error> {
+        Self::parse_sanitized(input, SanitizeOptions::default()).map(|_| ())
+    }
 
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let sans = Rut::sans(input);
+    /// Parses a RUT stripping the separators allowed by the provided
+    /// [`SanitizeOptions`].
+    ///
+    /// Runs a single pass over the input chars, taking the last char which
+    /// is not a separator as the verification digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Rut, SanitizeOptions};
+    ///
+    /// let input = "17.951.585\u{2013}7";
+    ///
+    /// assert!(Rut::parse_sanitized(input, SanitizeOptions::LENIENT).is_ok());
+    /// assert!(Rut::parse_sanitized(input, SanitizeOptions::STRICT).is_err());
+    /// ```
+    pub fn parse_sanitized(input: &str, options: SanitizeOptions) -> Result<Self, Error> {
+        let result = Self::parse_chars(input, options);
 
-        let mut chars = sans.chars().collect::<Vec<char>>();
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        telemetry::record(&result);
 
-        // Discards the last char, assuming it is the verification digit
-        let Some(input_vd) = chars.pop() else {
-            return Err(Error::EmptyString);
-        };
+        result
+    }
 
-        let num = chars
-            .into_iter()
-            .map(String::from)
-            .collect::<Vec<String>>()
-            .join("")
-            .parse::<Num>()
-            .map_err(Error::NaN)?;
+    /// Parses a RUT from raw bytes, such as a network record, without
+    /// building a `String` first.
+    ///
+    /// Separators are stripped as in [`Rut::from_str`], bytes outside of the
+    /// ASCII range are rejected inline instead of decoding the input as
+    /// UTF-8, so for ASCII inputs the result is the same as parsing the
+    /// equivalent `str`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut = Rut::from_bytes(b"17.951.585-7").unwrap();
+    ///
+    /// assert_eq!(rut.num(), 17_951_585);
+    /// assert!(Rut::from_bytes(b"17.951.585-8").is_err());
+    /// assert!(Rut::from_bytes(b"17.951.585-\xff").is_err());
+    /// ```
+    pub fn from_bytes(input: &[u8]) -> Result<Self, Error> {
+        let chars = input.trim_ascii().iter().map(|b| match b {
+            0..=0x7f => char::from(*b),
+            _ => char::REPLACEMENT_CHARACTER,
+        });
+        let result = Self::parse_iter(chars, SanitizeOptions::default()).map_err(|err| {
+            match std::str::from_utf8(input) {
+                Ok(input) => foreign::refine(err, input),
+                Err(_) => err,
+            }
+        });
+
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        telemetry::record(&result);
+
+        result
+    }
+
+    /// Single pass parser behind [`Rut::parse_sanitized`]
+    fn parse_chars(input: &str, options: SanitizeOptions) -> Result<Self, Error> {
+        Self::parse_iter(options.trim(input).chars(), options)
+            .map_err(|err| foreign::refine(err, input))
+    }
+
+    /// Parses the RUT written by `chars`, skipping separators
+    fn parse_iter<I>(chars: I, options: SanitizeOptions) -> Result<Self, Error>
+    where
+        I: Iterator<Item = char>,
+    {
+        let (num, input_vd) = split_chars(chars, options)?;
 
         let want = Rut::try_from(num)?;
 
@@ -393,6 +1220,139 @@ impl FromStr for Rut {
             want: want.vd().into(),
         })
     }
+
+    /// Retrieves a "sans" RUT version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut = Rut::sans("17.951.585-7");
+    ///
+    /// assert_eq!(rut, "179515857");
+    /// ```
+    pub fn sans<S: AsRef<str>>(input: S) -> String {
+        Rut::sans_with(input, SanitizeOptions::default())
+    }
+
+    /// Retrieves a "sans" RUT version stripping the separators allowed by
+    /// the provided [`SanitizeOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Rut, SanitizeOptions};
+    ///
+    /// let rut = Rut::sans_with("17\u{00B7}951\u{00B7}585 \u{2014} 7", SanitizeOptions::LENIENT);
+    ///
+    /// assert_eq!(rut, "179515857");
+    /// ```
+    pub fn sans_with<S: AsRef<str>>(input: S, options: SanitizeOptions) -> String {
+        Rut::sans_cow_with(input.as_ref(), options).into_owned()
+    }
+
+    /// Same as [`Rut::sans`] borrowing the input when it has no separators
+    /// to strip, which is the common case for bulk exports.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use rutcl::Rut;
+    ///
+    /// assert!(matches!(Rut::sans_cow("179515857"), Cow::Borrowed("179515857")));
+    /// assert_eq!(Rut::sans_cow("17.951.585-7"), "179515857");
+    /// ```
+    pub fn sans_cow(input: &str) -> Cow<'_, str> {
+        Rut::sans_cow_with(input, SanitizeOptions::default())
+    }
+
+    /// Same as [`Rut::sans_with`] borrowing the input when it has no
+    /// separators to strip.
+    pub fn sans_cow_with(input: &str, options: SanitizeOptions) -> Cow<'_, str> {
+        let input = options.trim(input);
+        let Some(idx) = input.find(|c| options.is_separator(c)) else {
+            return Cow::Borrowed(input);
+        };
+        let mut sans = String::with_capacity(input.len());
+
+        sans.push_str(&input[..idx]);
+        sans.extend(input[idx..].chars().filter(|c| !options.is_separator(*c)));
+
+        Cow::Owned(sans)
+    }
+}
+
+impl Display for Rut {
+    /// Writes the representation in the [`default_format`], [`Format::Sans`]
+    /// unless set otherwise, honoring width, fill and alignment flags (e.g.
+    /// `{:>15}` or `{:0>12}`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatted = self.format(default_format());
+        f.pad(&formatted)
+    }
+}
+
+impl Debug for Rut {
+    /// Writes the raw tuple followed by the [`Format::Dash`] representation,
+    /// e.g. `Rut(17951585, Seven) 17951585-7`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Rut")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()?;
+        write!(f, " {}", self.format(Format::Dash))
+    }
+}
+
+/// Parses a RUT leniently, stripping the separators allowed by the default
+/// [`SanitizeOptions`] wherever they are, so inputs such as `1-7.9.5-15857`
+/// are accepted.
+///
+/// This is the compatibility mode kept next to the formal grammar: parsing
+/// copy-pasted separators, hinting foreign tax IDs and the errors reported
+/// by [`Rut::validate`] build on it.
+#[cfg_attr(
+    feature = "winnow",
+    doc = "Use [`Rut::parse_strict`] where inputs must follow the grammar."
+)]
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Rut;
+///
+/// assert!("1-7.9.5-15857".parse::<Rut>().is_ok());
+///
+/// #[cfg(feature = "winnow")]
+/// assert!(Rut::parse_strict("1-7.9.5-15857").is_err());
+/// ```
+impl FromStr for Rut {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Rut::parse_sanitized(input, SanitizeOptions::default())
+    }
+}
+
+/// Parses a RUT in any of the supported formats, keeping the raw value in
+/// the [`Error::InvalidValue`] error.
+///
+/// Useful for configuration structs using `#[serde(try_from = "String")]`.
+impl TryFrom<String> for Rut {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match Rut::from_str(&value) {
+            Ok(rut) => Ok(rut),
+            Err(err) => Err(Error::InvalidValue {
+                value,
+                source: Box::new(err),
+            }),
+        }
+    }
 }
 
 impl TryFrom<Num> for Rut {
@@ -408,33 +1368,24 @@ impl TryFrom<Num> for Rut {
     }
 }
 
-impl Ord for Rut {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.0 > other.0 {
-            return Ordering::Greater;
-        }
-
-        if other.0 > self.0 {
-            return Ordering::Less;
-        }
-
-        Ordering::Equal
-    }
-}
-
-impl PartialOrd for Rut {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 #[cfg(feature = "serde")]
+/// Human readable formats (JSON, YAML, ...) get the representation in the
+/// [`default_format`], [`Format::Sans`] unless set otherwise. Compact
+/// formats (bincode, CBOR, MessagePack, ...) get the RUT's number ([`Num`])
+/// as a `u32`, the [`VerificationDigit`] is derived from it when
+/// deserializing.
+///
+/// Enable the `serde-always-str` feature to always use strings.
 impl Serialize for Rut {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.format(Format::Sans))
+        if cfg!(feature = "serde-always-str") || serializer.is_human_readable() {
+            return serializer.serialize_str(&self.format(default_format()));
+        }
+
+        serializer.serialize_u32(self.num())
     }
 }
 
@@ -446,22 +1397,42 @@ impl<'de> Visitor<'de> for RutVisitor {
     type Value = Rut;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a Rut String instance formatted using the Sans format")
+        formatter.write_str("a Rut String instance formatted using the Sans format or its number")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
-        E: serde::de::Error,
+        E: ::serde::de::Error,
     {
         Rut::from_str(v).map_err(|err| E::custom(err.to_string()))
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
     where
-        E: serde::de::Error,
+        E: ::serde::de::Error,
     {
         Rut::from_str(v.as_str()).map_err(|err| E::custom(err.to_string()))
     }
+
+    /// Compact formats and legacy BSON documents store the RUT's number
+    /// ([`Num`]) as an integer
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error,
+    {
+        let num = Num::try_from(v).map_err(|_| E::custom(Error::OutOfRange.to_string()))?;
+
+        Rut::try_from(num).map_err(|err| E::custom(err.to_string()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error,
+    {
+        let num = Num::try_from(v).map_err(|_| E::custom(Error::OutOfRange.to_string()))?;
+
+        Rut::try_from(num).map_err(|err| E::custom(err.to_string()))
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -470,6 +1441,10 @@ impl<'de> Deserialize<'de> for Rut {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(RutVisitor)
+        if cfg!(feature = "serde-always-str") || deserializer.is_human_readable() {
+            return deserializer.deserialize_str(RutVisitor);
+        }
+
+        deserializer.deserialize_u32(RutVisitor)
     }
 }
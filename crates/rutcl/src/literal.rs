@@ -0,0 +1,105 @@
+use crate::{Num, Rut, VerificationDigit, MAX_NUM, MIN_NUM};
+
+/// Creates a [`Rut`] from a string literal, validated at compile time.
+///
+/// The literal may use any of the [`Format`](crate::Format)s. The resulting
+/// value is a constant, so it can be used to define `const`s and `static`s.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{rut, Rut};
+///
+/// const COMPANY: Rut = rut!("76.086.428-5");
+///
+/// assert_eq!(COMPANY.num(), 76_086_428);
+/// assert_eq!(rut!("92635843-k").to_string(), "92635843K");
+/// ```
+///
+/// A wrong verification digit fails the build:
+///
+/// ```compile_fail
+/// const COMPANY: rutcl::Rut = rutcl::rut!("76.086.428-K");
+/// ```
+#[macro_export]
+macro_rules! rut {
+    ($literal:literal) => {{
+        const RUT: $crate::Rut = match $crate::Rut::__from_literal($literal) {
+            Ok(rut) => rut,
+            Err(reason) => panic!("{}", reason),
+        };
+
+        RUT
+    }};
+}
+
+impl Rut {
+    /// Parses a RUT literal in a `const` context, backing the [`rut!`]
+    /// macro. Only ASCII dots and dashes are accepted as separators.
+    #[doc(hidden)]
+    pub const fn __from_literal(input: &str) -> Result<Rut, &'static str> {
+        let bytes = input.as_bytes();
+        let mut end = bytes.len();
+
+        while end > 0 && matches!(bytes[end - 1], b'.' | b'-') {
+            end -= 1;
+        }
+
+        if end == 0 {
+            return Err("RUT literal is empty");
+        }
+
+        let have = bytes[end - 1];
+        let mut num: Num = 0;
+        let mut digits = 0;
+        let mut idx = 0;
+
+        while idx < end - 1 {
+            match bytes[idx] {
+                b'.' | b'-' => {}
+                digit @ b'0'..=b'9' => {
+                    num = match num.checked_mul(10) {
+                        Some(num) => match num.checked_add((digit - b'0') as Num) {
+                            Some(num) => num,
+                            None => return Err("RUT literal is out of range"),
+                        },
+                        None => return Err("RUT literal is out of range"),
+                    };
+                    digits += 1;
+                }
+                _ => return Err("RUT literal has a non-digit char in its number"),
+            }
+
+            idx += 1;
+        }
+
+        if digits == 0 {
+            return Err("RUT literal has no number");
+        }
+
+        if num < MIN_NUM || num > MAX_NUM {
+            return Err("RUT literal is out of range");
+        }
+
+        let vd = VerificationDigit::compute(num);
+        let want = match vd {
+            VerificationDigit::K => b'K',
+            VerificationDigit::Zero => b'0',
+            VerificationDigit::One => b'1',
+            VerificationDigit::Two => b'2',
+            VerificationDigit::Three => b'3',
+            VerificationDigit::Four => b'4',
+            VerificationDigit::Five => b'5',
+            VerificationDigit::Six => b'6',
+            VerificationDigit::Seven => b'7',
+            VerificationDigit::Eight => b'8',
+            VerificationDigit::Nine => b'9',
+        };
+
+        if have.to_ascii_uppercase() != want {
+            return Err("RUT literal has an invalid verification digit");
+        }
+
+        Ok(Rut(num, vd))
+    }
+}
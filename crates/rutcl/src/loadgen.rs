@@ -0,0 +1,212 @@
+//! Random RUT generation for load testing.
+
+#[cfg(feature = "futures")]
+use std::future::Future;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+#[cfg(feature = "futures")]
+use std::task::{Context, Poll};
+
+use std::collections::HashSet;
+use std::num::NonZeroU32;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::{CustomBand, Error, Num, Rut, RutBand, VerificationDigit};
+
+/// Distribution followed by the RUTs yielded by a [`RutStream`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Pattern {
+    /// Every RUT in the band is equally likely and is yielded at most once
+    Uniform,
+    /// RUTs are drawn from a fixed subset of `subset` distinct RUTs, where
+    /// the RUT with rank `k` is drawn with a probability proportional to
+    /// `1 / k^exponent`, resembling real traffic with a few hot identities.
+    ///
+    /// `subset` must be greater than zero and `exponent` finite and not
+    /// negative.
+    Zipf { subset: usize, exponent: f64 },
+}
+
+/// Iterator of random RUTs yielded at a configurable rate and [`Pattern`].
+///
+/// When a rate is set, [`Iterator::next`] blocks the current thread until the
+/// next RUT is due. With the `futures` feature, [`RutStream`] also implements
+/// `futures::Stream`, which waits without blocking.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use rutcl::loadgen::{Pattern, RutStream};
+///
+/// let ruts = RutStream::new(Pattern::Uniform)
+///     .unwrap()
+///     .with_seed(42)
+///     .with_rate(NonZeroU32::new(10_000).unwrap())
+///     .take(100)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(ruts.len(), 100);
+/// ```
+#[derive(Debug)]
+pub struct RutStream {
+    rng: StdRng,
+    band: RutBand,
+    pattern: Pattern,
+    interval: Option<Duration>,
+    next_at: Option<Instant>,
+    seen: HashSet<Num>,
+    hot: Vec<Rut>,
+    cdf: Vec<f64>,
+    #[cfg(feature = "futures")]
+    delay: Option<futures_timer::Delay>,
+}
+
+impl RutStream {
+    /// Creates an unlimited [`RutStream`] over the whole RUT range following
+    /// the provided [`Pattern`].
+    ///
+    /// Fails with [`Error::OutOfRange`] for a [`Pattern::Zipf`] with an empty
+    /// subset or a negative or non finite exponent.
+    pub fn new(pattern: Pattern) -> Result<Self, Error> {
+        if let Pattern::Zipf { subset, exponent } = pattern {
+            if subset == 0 || !exponent.is_finite() || exponent < 0.0 {
+                return Err(Error::OutOfRange);
+            }
+        }
+
+        Ok(RutStream {
+            rng: StdRng::from_entropy(),
+            band: RutBand::Custom(CustomBand::ALL),
+            pattern,
+            interval: None,
+            next_at: None,
+            seen: HashSet::new(),
+            hot: Vec::new(),
+            cdf: Vec::new(),
+            #[cfg(feature = "futures")]
+            delay: None,
+        })
+    }
+
+    /// Draws RUTs from the provided [`RutBand`] only
+    pub fn with_band(mut self, band: RutBand) -> Self {
+        self.band = band;
+        self.reset();
+        self
+    }
+
+    /// Seeds the random number generator, so the same RUTs are yielded on
+    /// every run
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.reset();
+        self
+    }
+
+    /// Limits the stream to `per_second` RUTs per second, streams are
+    /// unlimited unless a rate is set
+    pub fn with_rate(mut self, per_second: NonZeroU32) -> Self {
+        self.interval = Some(Duration::from_secs(1) / per_second.get());
+        self
+    }
+
+    /// Clears the state drawn from the previous band or seed
+    fn reset(&mut self) {
+        self.seen.clear();
+        self.hot.clear();
+        self.cdf.clear();
+    }
+
+    /// Draws the next RUT without waiting for the rate limit
+    fn draw(&mut self) -> Option<Rut> {
+        match self.pattern {
+            Pattern::Uniform => self.draw_unique(),
+            Pattern::Zipf { subset, exponent } => {
+                if self.hot.is_empty() {
+                    self.hot = (0..subset).map_while(|_| self.draw_unique()).collect();
+                    self.cdf = (1..=self.hot.len())
+                        .scan(0.0, |sum, rank| {
+                            *sum += 1.0 / (rank as f64).powf(exponent);
+                            Some(*sum)
+                        })
+                        .collect();
+                }
+
+                let total = self.cdf.last()?;
+                let target = self.rng.gen::<f64>() * total;
+                let rank = self.cdf.partition_point(|sum| *sum < target);
+
+                self.hot.get(rank.min(self.hot.len() - 1)).copied()
+            }
+        }
+    }
+
+    /// Draws a RUT which was never drawn before, `None` once the band is
+    /// exhausted
+    fn draw_unique(&mut self) -> Option<Rut> {
+        let range = self.band.range();
+
+        if self.seen.len() as u64 > u64::from(range.end() - range.start()) {
+            return None;
+        }
+
+        loop {
+            let num = self.rng.gen_range(range.clone());
+
+            if self.seen.insert(num) {
+                return Some(Rut(num, VerificationDigit::compute(num)));
+            }
+        }
+    }
+
+    /// Retrieves how long to wait for the next RUT and schedules the
+    /// following one
+    fn wait(&mut self) -> Option<Duration> {
+        let interval = self.interval?;
+        let now = Instant::now();
+        let next_at = self.next_at.unwrap_or(now);
+
+        self.next_at = Some(next_at.max(now) + interval);
+        next_at.checked_duration_since(now)
+    }
+}
+
+impl Iterator for RutStream {
+    type Item = Rut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(wait) = self.wait() {
+            std::thread::sleep(wait);
+        }
+
+        self.draw()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for RutStream {
+    type Item = Rut;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.delay.is_none() {
+            if let Some(wait) = self.wait().filter(|wait| !wait.is_zero()) {
+                self.delay = Some(futures_timer::Delay::new(wait));
+            }
+        }
+
+        if let Some(delay) = self.delay.as_mut() {
+            if Pin::new(delay).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            self.delay = None;
+        }
+
+        Poll::Ready(self.draw())
+    }
+}
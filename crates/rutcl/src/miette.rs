@@ -0,0 +1,121 @@
+use std::fmt::{self, Display};
+
+use ::miette::{Diagnostic, LabeledSpan, SourceCode, SourceSpan};
+
+use crate::Error;
+
+impl Diagnostic for Error {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("rutcl::{}", self.kind())))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help: Box<dyn Display + 'a> = match self {
+            Error::InvalidVerificationDigit { want, .. } => Box::new(format!(
+                "the verification digit for this number is {want}, check for typos in the number"
+            )),
+            Error::VerificationDigitOutOfBounds(_) => {
+                Box::new("verification digits are numbers from 0 to 9 or the letter K")
+            }
+            Error::NaN(_) => Box::new("a RUT is made of digits, dots and a dash"),
+            Error::OutOfRange => {
+                Box::new("RUT numbers go from 1.000.000 to 99.999.999, without the digit")
+            }
+            Error::SiiSchema { .. } => Box::new(
+                "the SII expects RUTs without dots and with an uppercase K, e.g. 12345678-5",
+            ),
+            _ => return None,
+        };
+
+        Some(help)
+    }
+}
+
+/// An [`Error`] along with the input which caused it, rendering labeled
+/// spans over the offending chars with `miette`.
+///
+/// Built with [`Error::with_input`].
+#[derive(Clone, Debug)]
+pub struct InputError {
+    input: String,
+    span: SourceSpan,
+    label: String,
+    error: Error,
+}
+
+impl InputError {
+    /// Retrieves the underlying [`Error`]
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// Retrieves the input which caused the error
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl Diagnostic for InputError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_primary_with_span(Some(self.label.clone()), self.span);
+
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+impl Error {
+    /// Attaches the input which caused this error, so it can be rendered
+    /// as a `miette` diagnostic pointing to the offending chars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use miette::Diagnostic;
+    /// use rutcl::Rut;
+    ///
+    /// let input = "17.951.585-K";
+    /// let err = input.parse::<Rut>().unwrap_err().with_input(input);
+    /// let label = err.labels().unwrap().next().unwrap();
+    ///
+    /// assert_eq!(label.offset(), 11);
+    /// assert_eq!(label.label(), Some("expected 7"));
+    /// ```
+    pub fn with_input(self, input: &str) -> InputError {
+        let span = SourceSpan::from(self.span(input));
+        let label = match &self {
+            Error::InvalidVerificationDigit { want, .. } => format!("expected {want}"),
+            Error::VerificationDigitOutOfBounds(_) => String::from("not a verification digit"),
+            Error::NaN(_) => String::from("not a number"),
+            Error::OutOfRange => String::from("out of range"),
+            Error::EmptyString => String::from("empty"),
+            _ => String::from("invalid RUT"),
+        };
+
+        InputError {
+            input: input.to_string(),
+            span,
+            label,
+            error: self,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::{split_chars, Error, Num, Rut, SanitizeOptions, VerificationDigit, FACTOR, RANGE};
+
+/// Check digit scheme of a RUT-like identifier, see [`NationalId`].
+///
+/// Implement [`Mod11Checksum`] instead for schemes using RUT's weighted
+/// mod 11 algorithm, which provides this trait.
+pub trait Checksum {
+    /// Numbers (without the check digit) assigned in this scheme
+    const RANGE: RangeInclusive<Num>;
+
+    /// Computes the check digit for the provided number
+    fn check_digit(num: Num) -> VerificationDigit;
+}
+
+/// Weighted mod 11 check digit scheme, as used by RUTs.
+///
+/// Digits are multiplied by [`Mod11Checksum::FACTORS`] from right to left,
+/// cycling over them, and the check digit is `11` minus the remainder of
+/// the sum by `11`, writing `11` as `0` and `10` as `K`.
+pub trait Mod11Checksum {
+    /// Factors applied to the digits, starting from the rightmost one
+    const FACTORS: &'static [u32];
+
+    /// Numbers (without the check digit) assigned in this scheme
+    const RANGE: RangeInclusive<Num>;
+}
+
+impl<C: Mod11Checksum> Checksum for C {
+    const RANGE: RangeInclusive<Num> = <C as Mod11Checksum>::RANGE;
+
+    fn check_digit(num: Num) -> VerificationDigit {
+        let mut rest = num;
+        let mut sum = 0;
+
+        for factor in C::FACTORS.iter().cycle() {
+            if rest == 0 {
+                break;
+            }
+
+            sum += (rest % 10) * factor;
+            rest /= 10;
+        }
+
+        match 11 - sum % 11 {
+            11 => VerificationDigit::Zero,
+            digit => VerificationDigit::ALL[digit as usize],
+        }
+    }
+}
+
+/// The [`Mod11Checksum`] used by [`Rut`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RutChecksum {}
+
+impl Mod11Checksum for RutChecksum {
+    const FACTORS: &'static [u32] = &FACTOR;
+    const RANGE: RangeInclusive<Num> = RANGE;
+}
+
+/// Identifier written as a number followed by a check digit computed with
+/// the [`Checksum`] `C`, for schemes closely related to RUTs.
+///
+/// [`Rut`] remains the type to use for RUTs, `NationalId<RutChecksum>`
+/// converts from and into it.
+///
+/// # Example
+///
+/// ```
+/// use std::ops::RangeInclusive;
+///
+/// use rutcl::{Mod11Checksum, NationalId};
+///
+/// /// Provisional identifiers, assigned above the RUT range
+/// enum Provisional {}
+///
+/// impl Mod11Checksum for Provisional {
+///     const FACTORS: &'static [u32] = &[2, 3, 4, 5, 6, 7];
+///     const RANGE: RangeInclusive<u32> = 100_000_000..=199_999_999;
+/// }
+///
+/// let id: NationalId<Provisional> = "100.000.004-K".parse().unwrap();
+///
+/// assert_eq!(id.num(), 100_000_004);
+/// assert_eq!(id.to_string(), "100000004-K");
+/// assert!("17.951.585-7".parse::<NationalId<Provisional>>().is_err());
+/// ```
+pub struct NationalId<C> {
+    num: Num,
+    vd: VerificationDigit,
+    checksum: PhantomData<fn() -> C>,
+}
+
+impl<C: Checksum> NationalId<C> {
+    /// Creates a [`NationalId`] from its number, computing the check digit.
+    ///
+    /// Fails with [`Error::OutOfRange`] if `num` is not in
+    /// [`Checksum::RANGE`].
+    pub fn new(num: Num) -> Result<Self, Error> {
+        if !C::RANGE.contains(&num) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(NationalId {
+            num,
+            vd: C::check_digit(num),
+            checksum: PhantomData,
+        })
+    }
+}
+
+impl<C> NationalId<C> {
+    /// Retrieves the identifier's number, without the check digit
+    #[inline]
+    pub fn num(&self) -> Num {
+        self.num
+    }
+
+    /// Retrieves the identifier's check digit
+    #[inline]
+    pub fn vd(&self) -> VerificationDigit {
+        self.vd
+    }
+}
+
+impl<C: Checksum> FromStr for NationalId<C> {
+    type Err = Error;
+
+    /// Parses the identifier as [`Rut::from_str`] does, stripping separators
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let options = SanitizeOptions::default();
+        let (num, input_vd) = split_chars(options.trim(input).chars(), options)?;
+        let want = NationalId::<C>::new(num)?;
+
+        if want.vd == VerificationDigit::try_from(input_vd)? {
+            return Ok(want);
+        }
+
+        Err(Error::InvalidVerificationDigit {
+            have: input_vd,
+            want: want.vd.into(),
+        })
+    }
+}
+
+impl<C> Display for NationalId<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.num, self.vd)
+    }
+}
+
+impl<C> Debug for NationalId<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NationalId")
+            .field(&self.num)
+            .field(&self.vd)
+            .finish()
+    }
+}
+
+impl<C> Clone for NationalId<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for NationalId<C> {}
+
+impl<C> PartialEq for NationalId<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num
+    }
+}
+
+impl<C> Eq for NationalId<C> {}
+
+impl<C> Hash for NationalId<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num.hash(state)
+    }
+}
+
+impl<C> PartialOrd for NationalId<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for NationalId<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.num.cmp(&other.num)
+    }
+}
+
+impl From<Rut> for NationalId<RutChecksum> {
+    fn from(rut: Rut) -> Self {
+        NationalId {
+            num: rut.num(),
+            vd: rut.vd(),
+            checksum: PhantomData,
+        }
+    }
+}
+
+impl From<NationalId<RutChecksum>> for Rut {
+    fn from(id: NationalId<RutChecksum>) -> Self {
+        Rut(id.num, id.vd)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{Error, Rut, SanitizeOptions, ValidationPolicy};
+
+/// Reusable RUT parser holding a resolved [`ValidationPolicy`], the stateful
+/// counterpart of [`Rut::from_str_with`].
+///
+/// Configuration is resolved once by [`RutParserBuilder::build`], so
+/// [`RutParser::parse`] is cheap to call in hot loops. Parsers are `Copy`
+/// and can be shared across threads.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{RutParser, ValidationPolicy};
+///
+/// let parser = RutParser::builder()
+///     .policy(ValidationPolicy::STRICT)
+///     .lenient(true)
+///     .build();
+///
+/// let ruts = ["17.951.585-7", "76 086 428-5", "92.635.843-k"]
+///     .into_iter()
+///     .map(|input| parser.parse(input))
+///     .collect::<Vec<_>>();
+///
+/// assert!(ruts[0].is_ok());
+/// assert!(ruts[1].is_ok());
+/// assert!(ruts[2].is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RutParser {
+    policy: ValidationPolicy,
+}
+
+impl RutParser {
+    /// Creates a [`RutParserBuilder`] starting from the default
+    /// [`ValidationPolicy`]
+    pub fn builder() -> RutParserBuilder {
+        RutParserBuilder::default()
+    }
+
+    /// Retrieves the resolved [`ValidationPolicy`]
+    #[inline]
+    pub fn policy(&self) -> &ValidationPolicy {
+        &self.policy
+    }
+
+    /// Parses a [`Rut`] honoring this parser's configuration
+    #[inline]
+    pub fn parse(&self, input: &str) -> Result<Rut, Error> {
+        self.policy.validate(input)
+    }
+}
+
+/// Builder for a [`RutParser`], created with [`RutParser::builder`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RutParserBuilder {
+    policy: ValidationPolicy,
+    lenient: Option<bool>,
+}
+
+impl RutParserBuilder {
+    /// Strips every supported separator ([`SanitizeOptions::LENIENT`]) when
+    /// `true`, only ASCII dots and dashes ([`SanitizeOptions::STRICT`]) when
+    /// `false`. Takes precedence over the policy's
+    /// [`ValidationPolicy::sanitize`] options.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = Some(lenient);
+        self
+    }
+
+    /// Sets the [`ValidationPolicy`] to apply
+    pub fn policy(mut self, policy: ValidationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Builds the [`RutParser`]
+    pub fn build(self) -> RutParser {
+        let mut policy = self.policy;
+
+        match self.lenient {
+            Some(true) => policy.sanitize = SanitizeOptions::LENIENT,
+            Some(false) => policy.sanitize = SanitizeOptions::STRICT,
+            None => {}
+        }
+
+        RutParser { policy }
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::RangeInclusive;
+
+use crate::{Num, Rut, MAX_NUM, MIN_NUM};
+
+/// Amount of RUT numbers in [`RANGE`](crate::RANGE)
+const KEYSPACE: u64 = (MAX_NUM - MIN_NUM) as u64 + 1;
+
+impl Rut {
+    /// Retrieves the shard this [`Rut`] belongs to when splitting the whole
+    /// RUT keyspace into `n_shards` contiguous ranges.
+    ///
+    /// The shard is computed over the RUT's number as:
+    ///
+    /// ```text
+    /// (num - 1.000.000) * n_shards / 99.000.000
+    /// ```
+    ///
+    /// So shard `0` holds the lowest RUTs and shard `n_shards - 1` the highest
+    /// ones, every shard holding roughly the same amount of numbers. The
+    /// result is stable across versions and matches the ranges produced by
+    /// [`RutPartitioner`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_shards` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// assert_eq!(rutcl::MIN.shard(4), 0);
+    /// assert_eq!("49.999.999-2".parse::<Rut>().unwrap().shard(4), 1);
+    /// assert_eq!(rutcl::MAX.shard(4), 3);
+    /// ```
+    pub fn shard(&self, n_shards: u32) -> u32 {
+        assert!(n_shards > 0, "n_shards must be greater than 0");
+
+        ((self.num() - MIN_NUM) as u64 * n_shards as u64 / KEYSPACE) as u32
+    }
+}
+
+/// Splits the whole RUT keyspace into contiguous ranges of RUT numbers
+/// ([`Num`]), one per shard.
+///
+/// Ranges are ascending, don't overlap and cover every RUT number, a
+/// [`Rut`] always belongs to the range at index [`Rut::shard`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutPartitioner};
+///
+/// let partitioner = RutPartitioner::new(3);
+/// let rut: Rut = "76.086.428-5".parse().unwrap();
+///
+/// assert_eq!(partitioner.range(0), Some(1_000_000..=33_999_999));
+/// assert_eq!(partitioner.shard_of(&rut), 2);
+/// assert!(partitioner.range(2).unwrap().contains(&rut.num()));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RutPartitioner {
+    n_shards: u32,
+}
+
+impl RutPartitioner {
+    /// Creates a [`RutPartitioner`] splitting the keyspace in `n_shards`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_shards` is `0`.
+    pub fn new(n_shards: u32) -> Self {
+        assert!(n_shards > 0, "n_shards must be greater than 0");
+
+        RutPartitioner { n_shards }
+    }
+
+    /// Retrieves the amount of shards
+    #[inline]
+    pub fn n_shards(&self) -> u32 {
+        self.n_shards
+    }
+
+    /// Retrieves the shard the provided [`Rut`] belongs to
+    #[inline]
+    pub fn shard_of(&self, rut: &Rut) -> u32 {
+        rut.shard(self.n_shards)
+    }
+
+    /// Retrieves the range of RUT numbers held by `shard`, or `None` if
+    /// `shard` is not lower than [`RutPartitioner::n_shards`].
+    ///
+    /// When there are more shards than RUT numbers some ranges are empty.
+    pub fn range(&self, shard: u32) -> Option<RangeInclusive<Num>> {
+        if shard >= self.n_shards {
+            return None;
+        }
+
+        let start = self.offset(shard);
+        let end = self.offset(shard + 1);
+
+        Some(MIN_NUM + start..=MIN_NUM + end - 1)
+    }
+
+    /// Iterates over the ranges of every shard in ascending order
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = RangeInclusive<Num>> + '_ {
+        (0..self.n_shards).map(|shard| {
+            self.range(shard)
+                .expect("Shard is lower than the amount of shards")
+        })
+    }
+
+    /// Offset from [`MIN_NUM`] of the first number in `shard`, this is the
+    /// lowest offset `k` for which `k * n_shards / KEYSPACE == shard`
+    fn offset(&self, shard: u32) -> Num {
+        (shard as u64 * KEYSPACE).div_ceil(self.n_shards as u64) as Num
+    }
+}
@@ -0,0 +1,321 @@
+use std::fmt::Display;
+use std::num::IntErrorKind;
+use std::str::FromStr;
+
+use crate::{nan, Error, Num, Rut, SanitizeOptions, VerificationDigit};
+
+/// A RUT split in its number and verification digit, as stored by APIs and
+/// databases keeping them in separate fields or columns.
+///
+/// [`RutParts`] is plain data: it can hold any number and digit, converting
+/// it into a [`Rut`] with [`TryFrom`] verifies it.
+///
+/// With the `serde` feature it (de)serializes as a struct with `num` and
+/// `vd` fields, so it can be `#[serde(flatten)]`-ed into records. The digit
+/// is serialized as a char. Human readable formats deserialize it from a
+/// char, a string or an integer (`10` meaning `K`), compact formats from a
+/// char only. Use `rutcl::serde::parts` to flatten a [`Rut`] field directly.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutParts};
+///
+/// let parts = RutParts { num: 17_951_585, vd: '7' };
+/// let rut = Rut::try_from(parts).unwrap();
+///
+/// assert_eq!(RutParts::from(rut), parts);
+/// assert!(Rut::try_from(RutParts { num: 17_951_585, vd: '8' }).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RutParts {
+    /// The RUT's number ([`Num`])
+    pub num: Num,
+    /// The RUT's verification digit, `0` to `9` or `K`
+    pub vd: char,
+}
+
+impl From<Rut> for RutParts {
+    fn from(rut: Rut) -> Self {
+        RutParts {
+            num: rut.num(),
+            vd: rut.vd().into(),
+        }
+    }
+}
+
+/// Verifies the parts, failing as [`Rut::from_str`] would for the same
+/// number and digit
+///
+/// [`Rut::from_str`]: std::str::FromStr::from_str
+impl TryFrom<RutParts> for Rut {
+    type Error = Error;
+
+    fn try_from(parts: RutParts) -> Result<Self, Self::Error> {
+        let want = Rut::try_from(parts.num)?;
+
+        if want.vd() == VerificationDigit::try_from(parts.vd)? {
+            return Ok(want);
+        }
+
+        Err(Error::InvalidVerificationDigit {
+            have: parts.vd,
+            want: want.vd().into(),
+        })
+    }
+}
+
+/// Part of a RUT which failed to parse, reported by [`Error::InvalidPart`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RutPart {
+    /// The RUT's number ([`Num`])
+    Num,
+    /// The RUT's [`VerificationDigit`]
+    VerificationDigit,
+}
+
+impl Display for RutPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RutPart::Num => f.write_str("number"),
+            RutPart::VerificationDigit => f.write_str("verification digit"),
+        }
+    }
+}
+
+impl Rut {
+    /// Builds a [`Rut`] from its number and verification digit written as
+    /// separate strings, as found in exports keeping them in different
+    /// columns.
+    ///
+    /// Separators and surrounding whitespace are ignored, as in
+    /// [`Rut::from_str`]. Failures are wrapped in [`Error::InvalidPart`],
+    /// holding the [`RutPart`] to blame: the number when it is not a valid
+    /// RUT number, the verification digit otherwise.
+    ///
+    /// [`Rut::from_str`]: std::str::FromStr::from_str
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Error, Rut, RutPart};
+    ///
+    /// let rut = Rut::try_from_parts("17.951.585", "7").unwrap();
+    ///
+    /// assert_eq!(rut.to_string(), "179515857");
+    /// assert!(matches!(
+    ///     Rut::try_from_parts("17.951.585", "8"),
+    ///     Err(Error::InvalidPart { part: RutPart::VerificationDigit, .. })
+    /// ));
+    /// assert!(matches!(
+    ///     Rut::try_from_parts("17951585x", "7"),
+    ///     Err(Error::InvalidPart { part: RutPart::Num, .. })
+    /// ));
+    /// ```
+    pub fn try_from_parts(num: &str, vd: &str) -> Result<Self, Error> {
+        let want = parse_num(num)
+            .and_then(Rut::try_from)
+            .map_err(|err| invalid(RutPart::Num, err))?;
+        let have = VerificationDigit::from_str(vd.trim())
+            .map_err(|err| invalid(RutPart::VerificationDigit, err))?;
+
+        if want.vd() == have {
+            return Ok(want);
+        }
+
+        Err(invalid(
+            RutPart::VerificationDigit,
+            Error::InvalidVerificationDigit {
+                have: have.into(),
+                want: want.vd().into(),
+            },
+        ))
+    }
+}
+
+/// Wraps an error caused by the provided [`RutPart`]
+fn invalid(part: RutPart, source: Error) -> Error {
+    Error::InvalidPart {
+        part,
+        source: Box::new(source),
+    }
+}
+
+/// Parses a RUT number, skipping separators
+fn parse_num(input: &str) -> Result<Num, Error> {
+    let options = SanitizeOptions::default();
+    let mut num: Num = 0;
+    let mut empty = true;
+
+    for c in options
+        .trim(input)
+        .chars()
+        .filter(|c| !options.is_separator(*c))
+    {
+        let digit = c
+            .to_digit(10)
+            .ok_or_else(|| nan(IntErrorKind::InvalidDigit))?;
+
+        num = num
+            .checked_mul(10)
+            .and_then(|num| num.checked_add(digit))
+            .ok_or_else(|| nan(IntErrorKind::PosOverflow))?;
+        empty = false;
+    }
+
+    if empty {
+        return Err(nan(IntErrorKind::Empty));
+    }
+
+    Ok(num)
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+
+    use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::RutParts;
+    use crate::{Num, VerificationDigit};
+
+    const FIELDS: &[&str] = &["num", "vd"];
+
+    impl Serialize for RutParts {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("RutParts", 2)?;
+            state.serialize_field("num", &self.num)?;
+            state.serialize_field("vd", &self.vd)?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RutParts {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("RutParts", FIELDS, RutPartsVisitor)
+        }
+    }
+
+    struct RutPartsVisitor;
+
+    impl<'de> Visitor<'de> for RutPartsVisitor {
+        type Value = RutParts;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a struct with the RUT's num and vd")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let num = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            let Digit(vd) = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+            Ok(RutParts { num, vd })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut num: Option<Num> = None;
+            let mut vd: Option<char> = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "num" if num.is_some() => return Err(A::Error::duplicate_field("num")),
+                    "num" => num = Some(map.next_value()?),
+                    "vd" if vd.is_some() => return Err(A::Error::duplicate_field("vd")),
+                    "vd" => vd = Some(map.next_value::<Digit>()?.0),
+                    _ => {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+            }
+
+            Ok(RutParts {
+                num: num.ok_or_else(|| A::Error::missing_field("num"))?,
+                vd: vd.ok_or_else(|| A::Error::missing_field("vd"))?,
+            })
+        }
+    }
+
+    /// Verification digit stored as a char, a string or an integer
+    struct Digit(char);
+
+    impl<'de> Deserialize<'de> for Digit {
+        /// Compact formats are usually not self-describing, so the digit is
+        /// expected as the char it is serialized as
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(DigitVisitor)
+            } else {
+                deserializer.deserialize_char(DigitVisitor)
+            }
+        }
+    }
+
+    struct DigitVisitor;
+
+    impl<'de> Visitor<'de> for DigitVisitor {
+        type Value = Digit;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a verification digit char, string or integer")
+        }
+
+        fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Digit(v))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let mut chars = v.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Digit(c)),
+                _ => Err(E::invalid_length(v.chars().count(), &self)),
+            }
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            VerificationDigit::try_from(v)
+                .map(|vd| Digit(vd.into()))
+                .map_err(|err| E::custom(err.to_string()))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self)),
+            }
+        }
+    }
+}
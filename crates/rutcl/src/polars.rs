@@ -0,0 +1,109 @@
+//! Polars expressions for RUT columns.
+//!
+//! Bring [`RutExprExt`] into scope to access the `rut` namespace on
+//! [`Expr`], which validates and normalizes `String` columns without leaving
+//! the Polars engine.
+//!
+//! Every expression is element-wise and maps both nulls and invalid RUTs to
+//! `null`, except for [`RutNameSpace::is_valid`] which yields `false` for
+//! invalid RUTs.
+
+use std::str::FromStr;
+
+use ::polars::prelude::*;
+
+use crate::{Format, Rut};
+
+/// Extends [`Expr`] with the `rut` namespace.
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::*;
+/// use rutcl::polars::RutExprExt;
+/// use rutcl::Format;
+///
+/// let df = df!("rut" => ["179515857", "17.951.585-0"]).unwrap();
+/// let out = df
+///     .lazy()
+///     .select([
+///         col("rut").rut().is_valid().alias("valid"),
+///         col("rut").rut().normalize(Format::Dots).alias("normalized"),
+///         col("rut").rut().num().alias("num"),
+///     ])
+///     .collect()
+///     .unwrap();
+///
+/// let expected = df!(
+///     "valid" => [true, false],
+///     "normalized" => [Some("17.951.585-7"), None],
+///     "num" => [Some(17_951_585u32), None],
+/// )
+/// .unwrap();
+///
+/// assert!(out.equals_missing(&expected));
+/// ```
+pub trait RutExprExt {
+    /// Accesses the expressions operating on RUT columns
+    fn rut(self) -> RutNameSpace;
+}
+
+impl RutExprExt for Expr {
+    fn rut(self) -> RutNameSpace {
+        RutNameSpace(self)
+    }
+}
+
+/// Expressions operating on `String` columns holding RUTs, created with
+/// [`RutExprExt::rut`]
+pub struct RutNameSpace(Expr);
+
+impl RutNameSpace {
+    /// Checks whether each value is a valid RUT, see [`Rut::from_str`]
+    pub fn is_valid(self) -> Expr {
+        self.0.map(
+            |column| {
+                let ca = column.str()?;
+                let out: BooleanChunked = ca
+                    .into_iter()
+                    .map(|value| value.map(|value| Rut::from_str(value).is_ok()))
+                    .collect();
+
+                Ok(Some(out.with_name(ca.name().clone()).into_column()))
+            },
+            GetOutput::from_type(DataType::Boolean),
+        )
+    }
+
+    /// Writes each valid RUT in the provided [`Format`]
+    pub fn normalize(self, fmt: Format) -> Expr {
+        self.0.map(
+            move |column| {
+                let ca = column.str()?;
+                let out: StringChunked = ca
+                    .into_iter()
+                    .map(|value| Some(Rut::from_str(value?).ok()?.format(fmt)))
+                    .collect();
+
+                Ok(Some(out.with_name(ca.name().clone()).into_column()))
+            },
+            GetOutput::from_type(DataType::String),
+        )
+    }
+
+    /// Extracts the number of each valid RUT, as a `UInt32` column
+    pub fn num(self) -> Expr {
+        self.0.map(
+            |column| {
+                let ca = column.str()?;
+                let out: UInt32Chunked = ca
+                    .into_iter()
+                    .map(|value| Some(Rut::from_str(value?).ok()?.num()))
+                    .collect();
+
+                Ok(Some(out.with_name(ca.name().clone()).into_column()))
+            },
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+}
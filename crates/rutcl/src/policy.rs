@@ -0,0 +1,144 @@
+use crate::{CustomBand, Error, Rut, RutBand, SanitizeOptions};
+
+/// Acceptance rules applied by [`Rut::from_str_with`] on top of the RUT's
+/// verification digit.
+///
+/// The [`Default`] policy is [`ValidationPolicy::PERMISSIVE`], which accepts
+/// the same inputs as [`Rut::from_str`](std::str::FromStr::from_str).
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutBand, ValidationPolicy};
+///
+/// let bank = ValidationPolicy {
+///     reject_companies: true,
+///     ..ValidationPolicy::STRICT
+/// };
+///
+/// assert!(Rut::from_str_with("17.951.585-7", bank).is_ok());
+/// assert!(Rut::from_str_with("92.635.843-k", bank).is_err());
+/// assert!(Rut::from_str_with("76.086.428-5", bank).is_err());
+/// assert_eq!(bank.max().num(), 99_999_999);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationPolicy {
+    /// Band of accepted RUT numbers
+    pub band: RutBand,
+    /// Accepts a lowercase `k` as verification digit, otherwise only `K`
+    pub allow_lowercase_k: bool,
+    /// Accepts zeros before the RUT's number (e.g. `017.951.585-7`)
+    pub allow_leading_zeros: bool,
+    /// Rejects RUTs in the [`RutBand::Persons`] band
+    pub reject_persons: bool,
+    /// Rejects RUTs in the [`RutBand::Companies`] band
+    pub reject_companies: bool,
+    /// Rejects RUTs in the [`RutBand::ForeignInvestors`] band
+    pub reject_foreign_investors: bool,
+    /// Rejects placeholder RUTs, see
+    /// [`RutKind::Placeholder`](crate::RutKind::Placeholder)
+    pub reject_placeholders: bool,
+    /// Separators stripped before parsing
+    pub sanitize: SanitizeOptions,
+}
+
+impl ValidationPolicy {
+    /// Accepts every valid RUT, written in any supported way
+    pub const PERMISSIVE: Self = ValidationPolicy {
+        band: RutBand::Custom(CustomBand::ALL),
+        allow_lowercase_k: true,
+        allow_leading_zeros: true,
+        reject_persons: false,
+        reject_companies: false,
+        reject_foreign_investors: false,
+        reject_placeholders: false,
+        sanitize: SanitizeOptions::LENIENT,
+    };
+
+    /// Accepts every valid RUT written with ASCII separators, an uppercase
+    /// `K` and no leading zeros
+    pub const STRICT: Self = ValidationPolicy {
+        allow_lowercase_k: false,
+        allow_leading_zeros: false,
+        sanitize: SanitizeOptions::STRICT,
+        ..ValidationPolicy::PERMISSIVE
+    };
+
+    /// Retrieves the lowest [`Rut`] accepted by the policy's band
+    pub fn min(&self) -> Rut {
+        self.band.min()
+    }
+
+    /// Retrieves the highest [`Rut`] accepted by the policy's band
+    pub fn max(&self) -> Rut {
+        self.band.max()
+    }
+
+    /// Checks the input against the policy's rules, recording the outcome
+    /// once
+    pub(crate) fn validate(&self, input: &str) -> Result<Rut, Error> {
+        let result = self.check(input);
+
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        crate::telemetry::record(&result);
+
+        result
+    }
+
+    /// Checks the input against the policy's rules without recording
+    fn check(&self, input: &str) -> Result<Rut, Error> {
+        let mut chars = self
+            .sanitize
+            .trim(input)
+            .chars()
+            .filter(|c| !self.sanitize.is_separator(*c));
+
+        if !self.allow_leading_zeros && chars.next() == Some('0') {
+            return Err(Error::InvalidFormat);
+        }
+
+        if !self.allow_lowercase_k && chars.next_back() == Some('k') {
+            return Err(Error::InvalidFormat);
+        }
+
+        let rut = Rut::parse_chars(input, self.sanitize)?;
+
+        if !self.band.contains(&rut) {
+            return Err(Error::OutOfBand(self.band));
+        }
+
+        for (reject, band) in [
+            (self.reject_persons, RutBand::Persons),
+            (self.reject_companies, RutBand::Companies),
+            (self.reject_foreign_investors, RutBand::ForeignInvestors),
+        ] {
+            if reject && band.contains(&rut) {
+                return Err(Error::RejectedBand(band));
+            }
+        }
+
+        if self.reject_placeholders && rut.is_placeholder() {
+            return Err(Error::Placeholder);
+        }
+
+        Ok(rut)
+    }
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        ValidationPolicy::PERMISSIVE
+    }
+}
+
+impl Rut {
+    /// Parses a [`Rut`] honoring the provided [`ValidationPolicy`].
+    ///
+    /// Fails with [`Error::InvalidFormat`] for disallowed leading zeros or
+    /// lowercase `k`, with [`Error::OutOfBand`] for RUTs outside of the
+    /// policy's band, with [`Error::RejectedBand`] for RUTs in a rejected
+    /// band and with [`Error::Placeholder`] for rejected placeholders.
+    pub fn from_str_with(input: &str, policy: ValidationPolicy) -> Result<Self, Error> {
+        policy.validate(input)
+    }
+}
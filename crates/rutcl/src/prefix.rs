@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use crate::{Format, Rut};
+
+/// Amount of RUT numbers sharing a millions prefix
+const MILLION: u32 = 1_000_000;
+
+impl Rut {
+    /// Retrieves the millions prefix of this [`Rut`], the digits before the
+    /// first dot in [`Format::Dots`] (e.g. `17` for `17.951.585-7`).
+    ///
+    /// Prefixes go from `1` to `99`.
+    #[inline]
+    pub fn millions_prefix(&self) -> u8 {
+        (self.num() / MILLION) as u8
+    }
+
+    /// Buckets the provided RUTs by [`Rut::millions_prefix`], keeping their
+    /// order inside each bucket.
+    ///
+    /// Only prefixes with at least one RUT are present, in ascending order,
+    /// which makes them suitable for reports and for splitting exports into
+    /// a file per bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let ruts = ["17.951.585-7", "76.086.428-5", "17.000.000-5"]
+    ///     .map(|rut| rut.parse::<Rut>().unwrap());
+    /// let buckets = Rut::group_by_prefix(ruts);
+    ///
+    /// assert_eq!(buckets.keys().copied().collect::<Vec<u8>>(), [17, 76]);
+    /// assert_eq!(buckets[&17], [ruts[0], ruts[2]]);
+    /// ```
+    pub fn group_by_prefix<I>(ruts: I) -> BTreeMap<u8, Vec<Rut>>
+    where
+        I: IntoIterator<Item = Rut>,
+    {
+        let mut buckets = BTreeMap::<u8, Vec<Rut>>::new();
+
+        for rut in ruts {
+            buckets.entry(rut.millions_prefix()).or_default().push(rut);
+        }
+
+        buckets
+    }
+
+    /// Counts the provided RUTs by [`Rut::millions_prefix`], as
+    /// [`Rut::group_by_prefix`] without keeping the RUTs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let ruts = ["17.951.585-7", "76.086.428-5", "17.000.000-5"]
+    ///     .map(|rut| rut.parse::<Rut>().unwrap());
+    /// let counts = Rut::count_by_prefix(ruts);
+    ///
+    /// assert_eq!(counts.into_iter().collect::<Vec<_>>(), [(17, 2), (76, 1)]);
+    /// ```
+    pub fn count_by_prefix<I>(ruts: I) -> BTreeMap<u8, usize>
+    where
+        I: IntoIterator<Item = Rut>,
+    {
+        let mut counts = BTreeMap::new();
+
+        for rut in ruts {
+            *counts.entry(rut.millions_prefix()).or_default() += 1;
+        }
+
+        counts
+    }
+
+    /// Checks whether this RUT could be what the user is typing, for
+    /// autocomplete widgets.
+    ///
+    /// Dots and whitespace are ignored wherever they are typed and a
+    /// lowercase `k` is accepted. A dash ends the number, so what comes
+    /// before it must be the whole number. An empty input matches every
+    /// RUT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    ///
+    /// assert!(rut.matches_prefix("17.95"));
+    /// assert!(rut.matches_prefix("1795158"));
+    /// assert!(rut.matches_prefix("17.951.585-"));
+    /// assert!(rut.matches_prefix("179515857"));
+    /// assert!(!rut.matches_prefix("1795-"));
+    /// assert!(!rut.matches_prefix("17.951.585-8"));
+    /// ```
+    pub fn matches_prefix(&self, typed: &str) -> bool {
+        self.untyped_len(typed).is_some()
+    }
+
+    /// Keeps the candidates matching what the user is typing, see
+    /// [`Rut::matches_prefix`], ranked for autocomplete suggestions.
+    ///
+    /// Candidates with fewer chars left to type come first, so an exact
+    /// match leads the list, ties are broken by ascending number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let candidates = ["17.951.585-7", "1.795.000-2", "76.086.428-5"]
+    ///     .map(|rut| rut.parse::<Rut>().unwrap());
+    ///
+    /// assert_eq!(
+    ///     Rut::rank_prefix_matches("1.795", candidates),
+    ///     [candidates[1], candidates[0]]
+    /// );
+    /// ```
+    pub fn rank_prefix_matches<I>(typed: &str, candidates: I) -> Vec<Rut>
+    where
+        I: IntoIterator<Item = Rut>,
+    {
+        let mut ranked = candidates
+            .into_iter()
+            .filter_map(|rut| Some((rut.untyped_len(typed)?, rut)))
+            .collect::<Vec<(usize, Rut)>>();
+
+        ranked.sort_unstable_by_key(|(untyped, rut)| (*untyped, rut.num()));
+        ranked.dedup_by_key(|(_, rut)| *rut);
+        ranked.into_iter().map(|(_, rut)| rut).collect()
+    }
+
+    /// Amount of chars of this RUT, written in [`Format::Sans`], left to
+    /// type after `typed`, or `None` if `typed` doesn't lead to this RUT
+    fn untyped_len(&self, typed: &str) -> Option<usize> {
+        let sans = self.format(Format::Sans);
+        let (num, vd) = sans.split_at(sans.len() - 1);
+        let typed = typed
+            .chars()
+            .filter(|c| *c != '.' && !c.is_whitespace())
+            .collect::<String>();
+
+        match typed.split_once('-') {
+            Some((body, "")) if body == num => Some(1),
+            Some((body, typed_vd)) if body == num && typed_vd.eq_ignore_ascii_case(vd) => Some(0),
+            Some(_) => None,
+            None if sans
+                .get(..typed.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(&typed)) =>
+            {
+                Some(sans.len() - typed.len())
+            }
+            None => None,
+        }
+    }
+}
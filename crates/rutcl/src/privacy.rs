@@ -0,0 +1,93 @@
+//! Privacy-sensitive comparison and pseudonymization helpers.
+
+#[cfg(feature = "blake3")]
+use std::fmt::{self, Display};
+
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "blake3")]
+use crate::Format;
+use crate::Rut;
+
+#[cfg(feature = "blake3")]
+/// Context used to derive the BLAKE3 key from the salt, changing it would
+/// change every pseudonym
+const PSEUDONYM_CONTEXT: &str = "rutcl 2024-03-01 RUT pseudonym v1";
+
+#[cfg(feature = "subtle")]
+impl ConstantTimeEq for Rut {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0) & (self.1 as u8).ct_eq(&(other.1 as u8))
+    }
+}
+
+impl Rut {
+    #[cfg(feature = "subtle")]
+    /// Compares two RUTs in constant time, so the time taken doesn't leak
+    /// how many digits are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let a: Rut = "17.951.585-7".parse().unwrap();
+    /// let b: Rut = "17951585-7".parse().unwrap();
+    ///
+    /// assert!(a.ct_eq(&b));
+    /// ```
+    pub fn ct_eq(&self, other: &Rut) -> bool {
+        ConstantTimeEq::ct_eq(self, other).into()
+    }
+
+    #[cfg(feature = "blake3")]
+    /// Produces a salted, keyed BLAKE3 pseudonym for this RUT.
+    ///
+    /// The pseudonym is not reversible without brute forcing the RUT space
+    /// with the salt, so the salt must be kept secret. The same RUT and salt
+    /// always produce the same [`Pseudonym`], which allows joining datasets
+    /// without storing RUTs in the clear.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let pseudonym = rut.hash_pseudonym(b"analytics secret salt");
+    ///
+    /// assert_eq!(pseudonym, rut.hash_pseudonym(b"analytics secret salt"));
+    /// assert_ne!(pseudonym, rut.hash_pseudonym(b"another salt"));
+    /// assert_eq!(pseudonym.to_string().len(), 64);
+    /// ```
+    pub fn hash_pseudonym(&self, salt: &[u8]) -> Pseudonym {
+        let key = blake3::derive_key(PSEUDONYM_CONTEXT, salt);
+        let hash = blake3::keyed_hash(&key, self.format(Format::Sans).as_bytes());
+
+        Pseudonym(*hash.as_bytes())
+    }
+}
+
+#[cfg(feature = "blake3")]
+/// Non-reversible identifier produced by [`Rut::hash_pseudonym`].
+///
+/// Displayed as lowercase hexadecimal.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Pseudonym([u8; 32]);
+
+#[cfg(feature = "blake3")]
+impl Pseudonym {
+    /// Retrieves the raw bytes of this pseudonym
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl Display for Pseudonym {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(blake3::Hash::from(self.0).to_hex().as_str())
+    }
+}
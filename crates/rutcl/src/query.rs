@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use crate::{Error, Num, Rut, MAX_NUM, RANGE};
+
+/// A single condition of a [`RutQuery`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Condition {
+    /// The RUT's number is in any of the ranges
+    Ranges(Vec<RangeInclusive<Num>>),
+    /// The RUT's number is in the set
+    Set(BTreeSet<Num>),
+}
+
+impl Condition {
+    fn matches(&self, num: Num) -> bool {
+        match self {
+            Condition::Ranges(ranges) => ranges.iter().any(|range| range.contains(&num)),
+            Condition::Set(set) => set.contains(&num),
+        }
+    }
+
+    fn write_sql(&self, sql: &mut String, column: &str) {
+        match self {
+            Condition::Ranges(ranges) if ranges.is_empty() => sql.push_str("1 = 0"),
+            Condition::Ranges(ranges) => {
+                sql.push('(');
+
+                for (idx, range) in ranges.iter().enumerate() {
+                    if idx > 0 {
+                        sql.push_str(" OR ");
+                    }
+
+                    let _ = write!(
+                        sql,
+                        "{column} BETWEEN {} AND {}",
+                        range.start(),
+                        range.end()
+                    );
+                }
+
+                sql.push(')');
+            }
+            Condition::Set(set) if set.is_empty() => sql.push_str("1 = 0"),
+            Condition::Set(set) => {
+                let _ = write!(sql, "{column} IN (");
+
+                for (idx, num) in set.iter().enumerate() {
+                    if idx > 0 {
+                        sql.push_str(", ");
+                    }
+
+                    let _ = write!(sql, "{num}");
+                }
+
+                sql.push(')');
+            }
+        }
+    }
+}
+
+/// Search filter over RUTs, such as "customers whose RUT starts with what
+/// was typed so far", evaluated in memory or compiled into SQL.
+///
+/// Conditions are combined with `AND`, a query without conditions matches
+/// every RUT. SQL fragments target an `INTEGER` column holding the RUT's
+/// number, as recommended in [`db`](crate::db), and only hold numeric
+/// literals, so prefixes typed by users are safe to pass along.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutQuery};
+///
+/// let query = RutQuery::new()
+///     .starts_with_prefix("17.95")
+///     .unwrap()
+///     .in_range("10.000.000-8".parse().unwrap()..="19.999.999-0".parse().unwrap());
+///
+/// assert!(query.matches(&"17.951.585-7".parse().unwrap()));
+/// assert!(!query.matches(&"1.795.000-2".parse().unwrap()));
+/// assert_eq!(
+///     query.to_sql("rut"),
+///     "(rut BETWEEN 1795000 AND 1795999 OR rut BETWEEN 17950000 AND 17959999) \
+///      AND (rut BETWEEN 10000000 AND 19999999)"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RutQuery {
+    conditions: Vec<Condition>,
+}
+
+impl RutQuery {
+    /// Creates a [`RutQuery`] without conditions, matching every RUT
+    pub fn new() -> Self {
+        RutQuery::default()
+    }
+
+    /// Matches RUTs whose number starts with the digits of a partially
+    /// typed RUT, ignoring dots and whitespace (e.g. `17.95` matches
+    /// `1.795.000-2` and `17.951.585-7`).
+    ///
+    /// The prefix covers the RUT's number only, the verification digit is
+    /// not part of it.
+    ///
+    /// Fails with [`Error::EmptyString`] if the prefix has no digits and with
+    /// [`Error::InvalidFormat`] if it holds other chars or starts with `0`.
+    pub fn starts_with_prefix(mut self, prefix: &str) -> Result<Self, Error> {
+        let digits = prefix
+            .chars()
+            .filter(|c| *c != '.' && !c.is_whitespace())
+            .map(|c| c.to_digit(10).ok_or(Error::InvalidFormat))
+            .collect::<Result<Vec<u32>, Error>>()?;
+
+        match digits.first() {
+            None => return Err(Error::EmptyString),
+            Some(0) => return Err(Error::InvalidFormat),
+            _ => {}
+        }
+
+        let max_len = MAX_NUM.ilog10() as usize + 1;
+        let mut ranges = Vec::new();
+
+        // A prefix longer than any number matches nothing
+        if digits.len() <= max_len {
+            let value = digits.iter().fold(0u64, |acc, d| acc * 10 + u64::from(*d));
+
+            for len in digits.len()..=max_len {
+                let scale = 10u64.pow((len - digits.len()) as u32);
+                let start = (value * scale).max(u64::from(*RANGE.start()));
+                let end = ((value + 1) * scale - 1).min(u64::from(*RANGE.end()));
+
+                if start <= end {
+                    ranges.push(start as Num..=end as Num);
+                }
+            }
+        }
+
+        self.conditions.push(Condition::Ranges(ranges));
+
+        Ok(self)
+    }
+
+    /// Matches RUTs in the provided range, both bounds included
+    pub fn in_range(mut self, range: RangeInclusive<Rut>) -> Self {
+        let range = range.start().num()..=range.end().num();
+        let ranges = if range.is_empty() {
+            Vec::new()
+        } else {
+            vec![range]
+        };
+
+        self.conditions.push(Condition::Ranges(ranges));
+        self
+    }
+
+    /// Matches the provided RUTs only
+    pub fn in_set<I>(mut self, ruts: I) -> Self
+    where
+        I: IntoIterator<Item = Rut>,
+    {
+        let set = ruts.into_iter().map(|rut| rut.num()).collect();
+
+        self.conditions.push(Condition::Set(set));
+        self
+    }
+
+    /// Checks whether the provided [`Rut`] satisfies every condition
+    pub fn matches(&self, rut: &Rut) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(rut.num()))
+    }
+
+    /// Turns this query into a predicate, for [`Iterator::filter`] and
+    /// similar APIs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Rut, RutQuery};
+    ///
+    /// let customers = ["17.951.585-7", "76.086.428-5"].map(|rut| rut.parse::<Rut>().unwrap());
+    /// let found = customers
+    ///     .into_iter()
+    ///     .filter(RutQuery::new().starts_with_prefix("76").unwrap().into_predicate())
+    ///     .collect::<Vec<Rut>>();
+    ///
+    /// assert_eq!(found, [customers[1]]);
+    /// ```
+    pub fn into_predicate(self) -> impl Fn(&Rut) -> bool {
+        move |rut| self.matches(rut)
+    }
+
+    /// Compiles this query into a SQL `WHERE` clause fragment over the
+    /// provided column, which must hold the RUT's number.
+    ///
+    /// The column name is written verbatim, it must not come from user
+    /// input.
+    pub fn to_sql(&self, column: &str) -> String {
+        if self.conditions.is_empty() {
+            return "1 = 1".to_string();
+        }
+
+        let mut sql = String::new();
+
+        for (idx, condition) in self.conditions.iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(" AND ");
+            }
+
+            condition.write_sql(&mut sql, column);
+        }
+
+        sql
+    }
+}
@@ -0,0 +1,195 @@
+use std::ops::RangeInclusive;
+
+use rand::{thread_rng, Rng};
+
+use crate::kind::{NO_RUN_NUMS, PLACEHOLDER_NUMS};
+use crate::{Error, Num, Rut, RutBand, VerificationDigit, RANGE};
+
+/// RUT of the SII, used in certification environments
+const SII_NUM: Num = 60_803_000;
+
+/// Controls which RUT numbers are never yielded by the random generators
+/// taking options, such as [`Rut::random_with`] and [`RutBand::random_with`].
+///
+/// Skipping institutional and well-known RUTs prevents demo data from
+/// colliding with real taxpayers or with placeholders already present in
+/// the data.
+///
+/// The [`Default`] options are [`RandomOptions::SAFE`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{RandomOptions, Rut};
+///
+/// let options = RandomOptions::SAFE.skip_range(1_000_000..=9_999_999);
+/// let rut = Rut::random_with(&options).unwrap();
+///
+/// assert!(rut.num() >= 10_000_000);
+/// assert!(!(46_000_000..=47_999_999).contains(&rut.num()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomOptions {
+    /// Skips the [`RutBand::ForeignInvestors`] band
+    pub skip_foreign_investors: bool,
+    /// Skips placeholder RUTs such as `11.111.111-1` or `12.345.678-5`, see
+    /// [`RutKind::Placeholder`](crate::RutKind::Placeholder), and the SII's
+    /// own RUT, used in certification environments
+    pub skip_well_known: bool,
+    /// Additional ranges of RUT numbers to skip, bounds are inclusive
+    pub skip: Vec<RangeInclusive<Num>>,
+}
+
+impl RandomOptions {
+    /// Every RUT can be yielded
+    pub const UNRESTRICTED: Self = RandomOptions {
+        skip_foreign_investors: false,
+        skip_well_known: false,
+        skip: Vec::new(),
+    };
+
+    /// Skips foreign investors and well-known RUTs
+    pub const SAFE: Self = RandomOptions {
+        skip_foreign_investors: true,
+        skip_well_known: true,
+        skip: Vec::new(),
+    };
+
+    /// Skips the provided range of RUT numbers as well
+    pub fn skip_range(mut self, range: RangeInclusive<Num>) -> Self {
+        self.skip.push(range);
+        self
+    }
+
+    /// Retrieves the sorted and merged ranges of RUT numbers to skip
+    fn skipped(&self) -> Vec<RangeInclusive<Num>> {
+        let mut skipped = self
+            .skip
+            .iter()
+            .filter(|range| !range.is_empty())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if self.skip_foreign_investors {
+            skipped.push(RutBand::ForeignInvestors.range());
+        }
+
+        if self.skip_well_known {
+            skipped.extend(PLACEHOLDER_NUMS.iter().map(|num| *num..=*num));
+            skipped.push(NO_RUN_NUMS);
+            skipped.push(SII_NUM..=SII_NUM);
+        }
+
+        skipped.sort_unstable_by_key(|range| *range.start());
+        skipped.into_iter().fold(Vec::new(), |mut merged, range| {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => merged.push(range),
+            }
+
+            merged
+        })
+    }
+
+    /// Picks a random number in `range` which is not skipped, every allowed
+    /// number being equally likely
+    fn pick<G: Rng + ?Sized>(&self, rng: &mut G, range: RangeInclusive<Num>) -> Option<Num> {
+        let (min, max) = range.into_inner();
+        let mut allowed = Vec::new();
+        let mut next = min as u64;
+
+        for skipped in self.skipped() {
+            let start = *skipped.start() as u64;
+            let end = *skipped.end() as u64;
+
+            if end < next {
+                continue;
+            }
+
+            if start > max as u64 {
+                break;
+            }
+
+            if start > next {
+                allowed.push(next..=start - 1);
+            }
+
+            next = end + 1;
+        }
+
+        if next <= max as u64 {
+            allowed.push(next..=max as u64);
+        }
+
+        let total = allowed
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum::<u64>();
+
+        if total == 0 {
+            return None;
+        }
+
+        let mut idx = rng.gen_range(0..total);
+
+        for range in allowed {
+            let len = range.end() - range.start() + 1;
+
+            if idx < len {
+                return Some((range.start() + idx) as Num);
+            }
+
+            idx -= len;
+        }
+
+        None
+    }
+}
+
+impl Default for RandomOptions {
+    fn default() -> Self {
+        RandomOptions::SAFE
+    }
+}
+
+impl Rut {
+    /// Generates a random [`Rut`] instance which is not skipped by the
+    /// provided [`RandomOptions`].
+    ///
+    /// Fails with [`Error::OutOfRange`] if every RUT is skipped.
+    pub fn random_with(options: &RandomOptions) -> Result<Self, Error> {
+        random_in(&mut thread_rng(), RANGE, options)
+    }
+}
+
+impl RutBand {
+    /// Generates a random [`Rut`] instance inside this band which is not
+    /// skipped by the provided [`RandomOptions`].
+    ///
+    /// Fails with [`Error::OutOfRange`] if every RUT in the band is skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{RandomOptions, RutBand};
+    ///
+    /// assert!(RutBand::ForeignInvestors.random_with(&RandomOptions::SAFE).is_err());
+    /// assert!(RutBand::Persons.random_with(&RandomOptions::SAFE).is_ok());
+    /// ```
+    pub fn random_with(&self, options: &RandomOptions) -> Result<Rut, Error> {
+        random_in(&mut thread_rng(), self.range(), options)
+    }
+}
+
+/// Draws a random [`Rut`] in `range` honoring the provided `options`
+fn random_in<G: Rng + ?Sized>(
+    rng: &mut G,
+    range: RangeInclusive<Num>,
+    options: &RandomOptions,
+) -> Result<Rut, Error> {
+    let num = options.pick(rng, range).ok_or(Error::OutOfRange)?;
+
+    Ok(Rut(num, VerificationDigit::compute(num)))
+}
@@ -0,0 +1,202 @@
+use std::iter::FusedIterator;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{Num, Rut, VerificationDigit};
+
+/// Iterator over consecutive [`Rut`]s in ascending order.
+///
+/// `Range<Rut>` can't be iterated on stable Rust because `Rut` doesn't
+/// implement the unstable `Step` trait, use [`RutRangeExt::ruts`] to turn
+/// a range of RUTs into a [`RutRange`] instead.
+///
+/// Besides iterating, `count`, `nth`, `last` and `size_hint` are computed in
+/// constant time.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutRangeExt};
+///
+/// let end: Rut = "1.000.002-5".parse().unwrap();
+/// let ruts = (rutcl::MIN..=end).ruts().map(|rut| rut.to_string()).collect::<Vec<_>>();
+///
+/// assert_eq!(ruts, vec!["10000009", "10000017", "10000025"]);
+/// assert_eq!((rutcl::MIN..=rutcl::MAX).ruts().count(), 99_000_000);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RutRange {
+    start: Num,
+    end: Num,
+    exhausted: bool,
+}
+
+impl RutRange {
+    /// Creates a [`RutRange`] from `start` to `end` (inclusive)
+    pub fn new(start: Rut, end: Rut) -> Self {
+        RutRange {
+            start: start.num(),
+            end: end.num(),
+            exhausted: start > end,
+        }
+    }
+
+    /// Builds a [`RutRange`] over numbers already known to be valid
+    pub(crate) fn from_nums(range: RangeInclusive<Num>) -> Self {
+        let (start, end) = range.into_inner();
+
+        RutRange {
+            start,
+            end,
+            exhausted: start > end,
+        }
+    }
+
+    /// Checks whether there are no [`Rut`]s left in this range
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Checks whether the provided [`Rut`] is yet to be yielded
+    pub fn contains(&self, rut: &Rut) -> bool {
+        !self.exhausted && (self.start..=self.end).contains(&rut.num())
+    }
+
+    /// Amount of [`Rut`]s left
+    fn remaining(&self) -> usize {
+        if self.exhausted {
+            return 0;
+        }
+
+        (self.end - self.start) as usize + 1
+    }
+}
+
+/// Builds a [`Rut`] from a number already known to be valid
+fn rut(num: Num) -> Rut {
+    Rut(num, VerificationDigit::compute(num))
+}
+
+impl Iterator for RutRange {
+    type Item = Rut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let num = self.start;
+
+        if num == self.end {
+            self.exhausted = true;
+        } else {
+            self.start += 1;
+        }
+
+        Some(rut(num))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+
+        (remaining, Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining() {
+            self.exhausted = true;
+            return None;
+        }
+
+        self.start += n as Num;
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for RutRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let num = self.end;
+
+        if num == self.start {
+            self.exhausted = true;
+        } else {
+            self.end -= 1;
+        }
+
+        Some(rut(num))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining() {
+            self.exhausted = true;
+            return None;
+        }
+
+        self.end -= n as Num;
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for RutRange {}
+
+impl FusedIterator for RutRange {}
+
+impl From<RangeInclusive<Rut>> for RutRange {
+    fn from(range: RangeInclusive<Rut>) -> Self {
+        let (start, end) = range.into_inner();
+
+        RutRange::new(start, end)
+    }
+}
+
+impl From<Range<Rut>> for RutRange {
+    fn from(range: Range<Rut>) -> Self {
+        if range.start >= range.end {
+            return RutRange {
+                start: range.start.num(),
+                end: range.start.num(),
+                exhausted: true,
+            };
+        }
+
+        RutRange::from_nums(range.start.num()..=range.end.num() - 1)
+    }
+}
+
+/// Iteration over ranges of [`Rut`]s on stable Rust
+pub trait RutRangeExt {
+    /// Turns this range into a [`RutRange`] iterator
+    fn ruts(self) -> RutRange;
+}
+
+impl RutRangeExt for RangeInclusive<Rut> {
+    fn ruts(self) -> RutRange {
+        RutRange::from(self)
+    }
+}
+
+impl RutRangeExt for Range<Rut> {
+    fn ruts(self) -> RutRange {
+        RutRange::from(self)
+    }
+}
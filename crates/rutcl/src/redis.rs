@@ -0,0 +1,30 @@
+use ::redis::{ErrorKind, FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::{Format, Rut};
+
+/// Writes the [`Rut`] as a [`Format::Sans`] string, so it can be used as
+/// both key and value.
+impl ToRedisArgs for Rut {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.format(Format::Sans).as_bytes())
+    }
+}
+
+/// Reads a [`Rut`] from a string value in any of the supported formats.
+impl FromRedisValue for Rut {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let value = String::from_redis_value(v)?;
+
+        value.parse::<Rut>().map_err(|err| {
+            (
+                ErrorKind::TypeError,
+                "Response was of incompatible type",
+                format!("{err} (response was {v:?})"),
+            )
+                .into()
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+use ::roaring::RoaringBitmap;
+
+use crate::{Error, Rut, VerificationDigit, RANGE};
+
+/// A set of [`Rut`]s backed by a [`RoaringBitmap`] keyed by the RUT's number.
+///
+/// Roaring bitmaps compress dense runs of numbers, so sets of millions of
+/// RUTs take a few bytes per RUT at most, and set algebra between them runs
+/// over compressed containers. The set operators `|`, `&`, `-` and `^` are
+/// implemented for references.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, RutBitmapSet};
+///
+/// let parse = |ruts: &[&str]| {
+///     ruts.iter()
+///         .map(|rut| rut.parse::<Rut>().unwrap())
+///         .collect::<RutBitmapSet>()
+/// };
+/// let customers = parse(&["17.951.585-7", "76.086.428-5", "92.635.843-K"]);
+/// let newsletter = parse(&["17.951.585-7", "92.635.843-K"]);
+///
+/// let targets = &customers - &newsletter;
+///
+/// assert_eq!(targets.len(), 1);
+/// assert!(targets.contains(&"76.086.428-5".parse().unwrap()));
+/// assert_eq!((&customers & &newsletter).len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RutBitmapSet(RoaringBitmap);
+
+impl RutBitmapSet {
+    /// Creates an empty [`RutBitmapSet`]
+    pub fn new() -> Self {
+        RutBitmapSet::default()
+    }
+
+    /// Adds a [`Rut`], returning whether it was not in the set already
+    pub fn insert(&mut self, rut: &Rut) -> bool {
+        self.0.insert(rut.num())
+    }
+
+    /// Removes a [`Rut`], returning whether it was in the set
+    pub fn remove(&mut self, rut: &Rut) -> bool {
+        self.0.remove(rut.num())
+    }
+
+    /// Checks whether the provided [`Rut`] is in the set
+    #[inline]
+    pub fn contains(&self, rut: &Rut) -> bool {
+        self.0.contains(rut.num())
+    }
+
+    /// Amount of RUTs in the set
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.0.len()
+    }
+
+    /// Checks whether the set holds no RUTs
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the RUTs in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Rut> + '_ {
+        self.0
+            .iter()
+            .map(|num| Rut(num, VerificationDigit::compute(num)))
+    }
+
+    /// RUTs in either set
+    pub fn union(&self, other: &RutBitmapSet) -> RutBitmapSet {
+        RutBitmapSet(&self.0 | &other.0)
+    }
+
+    /// RUTs in both sets
+    pub fn intersection(&self, other: &RutBitmapSet) -> RutBitmapSet {
+        RutBitmapSet(&self.0 & &other.0)
+    }
+
+    /// RUTs in this set but not in `other`
+    pub fn difference(&self, other: &RutBitmapSet) -> RutBitmapSet {
+        RutBitmapSet(&self.0 - &other.0)
+    }
+
+    /// RUTs in exactly one of the sets
+    pub fn symmetric_difference(&self, other: &RutBitmapSet) -> RutBitmapSet {
+        RutBitmapSet(&self.0 ^ &other.0)
+    }
+
+    /// Amount of RUTs in both sets, without building the intersection
+    pub fn intersection_len(&self, other: &RutBitmapSet) -> u64 {
+        self.0.intersection_len(&other.0)
+    }
+
+    /// Retrieves the underlying [`RoaringBitmap`] of RUT numbers
+    #[inline]
+    pub fn as_bitmap(&self) -> &RoaringBitmap {
+        &self.0
+    }
+
+    /// Retrieves the underlying [`RoaringBitmap`] of RUT numbers
+    #[inline]
+    pub fn into_bitmap(self) -> RoaringBitmap {
+        self.0
+    }
+
+    /// Encodes the set in the portable Roaring serialization format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.0.serialized_size());
+
+        self.0
+            .serialize_into(&mut bytes)
+            .expect("Writing to a Vec never fails");
+        bytes
+    }
+
+    /// Decodes a set written by [`RutBitmapSet::to_bytes`] or by any other
+    /// Roaring implementation.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the bytes are not a Roaring
+    /// bitmap and with [`Error::OutOfRange`] if it holds numbers which are
+    /// not valid RUT numbers.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        RoaringBitmap::deserialize_from(bytes)
+            .map_err(|_| Error::InvalidFormat)
+            .and_then(RutBitmapSet::try_from)
+    }
+}
+
+/// Fails with [`Error::OutOfRange`] if the bitmap holds numbers which are
+/// not valid RUT numbers
+impl TryFrom<RoaringBitmap> for RutBitmapSet {
+    type Error = Error;
+
+    fn try_from(bitmap: RoaringBitmap) -> Result<Self, Self::Error> {
+        let in_range = match (bitmap.min(), bitmap.max()) {
+            (Some(min), Some(max)) => RANGE.contains(&min) && RANGE.contains(&max),
+            _ => true,
+        };
+
+        if !in_range {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(RutBitmapSet(bitmap))
+    }
+}
+
+impl FromIterator<Rut> for RutBitmapSet {
+    fn from_iter<T: IntoIterator<Item = Rut>>(iter: T) -> Self {
+        RutBitmapSet(iter.into_iter().map(|rut| rut.num()).collect())
+    }
+}
+
+impl Extend<Rut> for RutBitmapSet {
+    fn extend<T: IntoIterator<Item = Rut>>(&mut self, iter: T) {
+        self.0.extend(iter.into_iter().map(|rut| rut.num()));
+    }
+}
+
+/// Implements a set operator for references to [`RutBitmapSet`]s
+macro_rules! set_operator {
+    ($trait:ident, $fn:ident, $method:ident) => {
+        impl $trait<&RutBitmapSet> for &RutBitmapSet {
+            type Output = RutBitmapSet;
+
+            fn $fn(self, rhs: &RutBitmapSet) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+    };
+}
+
+set_operator!(BitOr, bitor, union);
+set_operator!(BitAnd, bitand, intersection);
+set_operator!(Sub, sub, difference);
+set_operator!(BitXor, bitxor, symmetric_difference);
@@ -0,0 +1,117 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Format, Rut};
+
+/// Context used to derive the BLAKE3 key from the shared secret, changing
+/// it would change every code
+const ROTATING_CODE_CONTEXT: &str = "rutcl 2026-10-16 RUT rotating code v1";
+
+/// Time each rotating code is valid for
+pub const ROTATING_CODE_STEP: Duration = Duration::from_secs(30);
+
+/// Amount of digits in a rotating code
+pub const ROTATING_CODE_DIGITS: usize = 6;
+
+impl Rut {
+    /// Produces the short code for this RUT in the [`ROTATING_CODE_STEP`]
+    /// holding the provided instant, TOTP-like.
+    ///
+    /// The code is made of [`ROTATING_CODE_DIGITS`] decimal digits derived
+    /// from a keyed BLAKE3 hash of the RUT and the step's counter, so an app
+    /// and a call center holding the same secret show and expect the same
+    /// code, while it can't be guessed for another RUT or step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let at = UNIX_EPOCH + Duration::from_secs(1_790_000_000);
+    /// let code = rut.rotating_code(b"shared secret", at);
+    ///
+    /// assert_eq!(code.len(), 6);
+    /// assert_eq!(code, rut.rotating_code(b"shared secret", at + Duration::from_secs(5)));
+    /// assert!(rut.verify_rotating_code(b"shared secret", &code, at, 1));
+    /// ```
+    pub fn rotating_code(&self, secret: &[u8], at: SystemTime) -> String {
+        code(
+            &blake3::derive_key(ROTATING_CODE_CONTEXT, secret),
+            self,
+            step(at),
+        )
+    }
+
+    /// Checks a code produced by [`Rut::rotating_code`] against the step
+    /// holding the provided instant and up to `window` steps before and after
+    /// it, to make up for clock drift and the time taken to read the code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let shown = UNIX_EPOCH + Duration::from_secs(1_790_000_000);
+    /// let code = rut.rotating_code(b"shared secret", shown);
+    /// let read = shown + Duration::from_secs(45);
+    ///
+    /// assert!(rut.verify_rotating_code(b"shared secret", &code, read, 2));
+    /// assert!(!rut.verify_rotating_code(b"shared secret", &code, read, 0));
+    /// assert!(!rut.verify_rotating_code(b"other secret", &code, read, 2));
+    /// ```
+    pub fn verify_rotating_code(
+        &self,
+        secret: &[u8],
+        code: &str,
+        at: SystemTime,
+        window: u64,
+    ) -> bool {
+        if code.len() != ROTATING_CODE_DIGITS {
+            return false;
+        }
+
+        let key = blake3::derive_key(ROTATING_CODE_CONTEXT, secret);
+        let now = step(at);
+
+        // Every step in the window is checked so the time taken doesn't
+        // reveal which step matched
+        (now.saturating_sub(window)..=now.saturating_add(window)).fold(false, |found, step| {
+            found | constant_time_eq(code.as_bytes(), self::code(&key, self, step).as_bytes())
+        })
+    }
+}
+
+/// Counter of the [`ROTATING_CODE_STEP`] holding the provided instant,
+/// instants before the Unix epoch fall in the first step
+fn step(at: SystemTime) -> u64 {
+    let elapsed = at.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    elapsed.as_secs() / ROTATING_CODE_STEP.as_secs()
+}
+
+/// Code for the provided RUT and step counter
+fn code(key: &[u8; 32], rut: &Rut, step: u64) -> String {
+    let mut hasher = blake3::Hasher::new_keyed(key);
+
+    hasher.update(rut.format(Format::Sans).as_bytes());
+    hasher.update(&step.to_be_bytes());
+
+    let hash = hasher.finalize();
+    let mut head = [0u8; 8];
+
+    head.copy_from_slice(&hash.as_bytes()[..8]);
+
+    let value = u64::from_be_bytes(head) % 10u64.pow(ROTATING_CODE_DIGITS as u32);
+
+    format!("{value:0width$}", width = ROTATING_CODE_DIGITS)
+}
+
+/// Compares two byte slices of the same length in constant time
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
@@ -0,0 +1,28 @@
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::db::Codec;
+use crate::{Format, Rut};
+
+/// Binds the [`Rut`] as `TEXT` written in [`Format::Dash`], which is stored
+/// as is in columns of any affinity.
+///
+/// To store the RUT's number in an `INTEGER` column instead, bind
+/// [`Codec::to_int`].
+impl ToSql for Rut {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_text(Format::Dash)))
+    }
+}
+
+/// Reads a [`Rut`] from `TEXT` values written in any [`Format`], or from
+/// `INTEGER` values holding the RUT's number.
+impl FromSql for Rut {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let rut = match value {
+            ValueRef::Integer(value) => Rut::from_int(value),
+            value => Rut::from_text_any(value.as_str()?),
+        };
+
+        rut.map_err(|err| FromSqlError::Other(Box::new(err)))
+    }
+}
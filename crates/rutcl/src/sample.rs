@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use rand::{thread_rng, Rng};
+
+use crate::{Error, Num, Rut, VerificationDigit, RANGE};
+
+impl Rut {
+    /// Draws `k` distinct [`Rut`]s from `range` (inclusive RUT numbers),
+    /// every subset of `k` RUTs being equally likely.
+    ///
+    /// RUTs are returned in ascending order. Fails with [`Error::OutOfRange`]
+    /// if `range` is not inside of the valid RUT numbers or if it holds less
+    /// than `k` RUTs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let audit = Rut::random_many_distinct_in_range(10_000_000..=10_000_099, 10).unwrap();
+    ///
+    /// assert_eq!(audit.len(), 10);
+    /// assert!(audit.windows(2).all(|pair| pair[0] < pair[1]));
+    /// assert!(Rut::random_many_distinct_in_range(10_000_000..=10_000_009, 11).is_err());
+    /// ```
+    pub fn random_many_distinct_in_range(
+        range: RangeInclusive<Num>,
+        k: usize,
+    ) -> Result<Vec<Self>, Error> {
+        Rut::random_many_distinct_in_range_with(&mut thread_rng(), range, k)
+    }
+
+    /// Same as [`Rut::random_many_distinct_in_range`] drawing from the
+    /// provided random number generator, so samples can be reproduced by
+    /// seeding it.
+    ///
+    /// Uses Robert Floyd's sampling algorithm, which draws exactly `k`
+    /// random numbers regardless of how many collide.
+    pub fn random_many_distinct_in_range_with<G: Rng + ?Sized>(
+        rng: &mut G,
+        range: RangeInclusive<Num>,
+        k: usize,
+    ) -> Result<Vec<Self>, Error> {
+        let (min, max) = range.into_inner();
+
+        if min > max || !RANGE.contains(&min) || !RANGE.contains(&max) {
+            return Err(Error::OutOfRange);
+        }
+
+        let len = (max - min) as usize + 1;
+
+        if k > len {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut picked = HashSet::with_capacity(k);
+
+        for j in (len - k)..len {
+            let offset = rng.gen_range(0..=j);
+
+            if !picked.insert(offset) {
+                picked.insert(j);
+            }
+        }
+
+        let mut ruts = picked
+            .into_iter()
+            .map(|offset| {
+                let num = min + offset as Num;
+
+                Rut(num, VerificationDigit::compute(num))
+            })
+            .collect::<Vec<Rut>>();
+
+        ruts.sort_unstable();
+
+        Ok(ruts)
+    }
+}
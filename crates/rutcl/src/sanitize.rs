@@ -0,0 +1,85 @@
+/// Controls which separators are stripped from an input before parsing it as
+/// a RUT.
+///
+/// ASCII dots (`.`) and dashes (`-`) are always stripped. The remaining
+/// options deal with characters that sneak in when RUTs are copy-pasted from
+/// PDFs, spreadsheets and word processors.
+///
+/// The [`Default`] options are the ones used by [`Rut::from_str`] and
+/// [`Rut::sans`]: they strip Unicode dashes, dots and non-breaking spaces
+/// and trim whitespace around the input, but keep whitespace in between
+/// digits, so `17 951 585-7` is rejected. Use [`SanitizeOptions::LENIENT`]
+/// to strip whitespace anywhere.
+///
+/// [`Rut::from_str`]: std::str::FromStr::from_str
+/// [`Rut::sans`]: crate::Rut::sans
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SanitizeOptions {
+    /// Strips Unicode dashes such as the en dash (`–`), em dash (`—`), minus
+    /// sign (`−`) and soft hyphen
+    pub unicode_dashes: bool,
+    /// Strips middle dots (`·`) and other Unicode dot separators
+    pub unicode_dots: bool,
+    /// Strips non-breaking spaces (`U+00A0`, `U+2007` and `U+202F`)
+    pub nbsp: bool,
+    /// Trims whitespace around the input
+    pub trim: bool,
+    /// Strips whitespace anywhere, including in between digits
+    pub whitespace: bool,
+}
+
+impl SanitizeOptions {
+    /// Only strips ASCII dots and dashes
+    pub const STRICT: Self = SanitizeOptions {
+        unicode_dashes: false,
+        unicode_dots: false,
+        nbsp: false,
+        trim: false,
+        whitespace: false,
+    };
+
+    /// Strips every supported separator
+    pub const LENIENT: Self = SanitizeOptions {
+        unicode_dashes: true,
+        unicode_dots: true,
+        nbsp: true,
+        trim: true,
+        whitespace: true,
+    };
+
+    /// Checks whether the provided char is a separator to strip
+    pub fn is_separator(&self, c: char) -> bool {
+        match c {
+            '.' | '-' => true,
+            '\u{00AD}' | '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}'
+            | '\u{2015}' | '\u{2212}' | '\u{FE63}' | '\u{FF0D}' => self.unicode_dashes,
+            '\u{00B7}' | '\u{2022}' | '\u{2024}' | '\u{2219}' | '\u{30FB}' | '\u{FF0E}' => {
+                self.unicode_dots
+            }
+            '\u{00A0}' | '\u{2007}' | '\u{202F}' => self.nbsp || self.whitespace,
+            c if c.is_whitespace() => self.whitespace,
+            _ => false,
+        }
+    }
+
+    /// Trims whitespace around the input if [`SanitizeOptions::trim`] is set
+    pub fn trim<'a>(&self, input: &'a str) -> &'a str {
+        if self.trim {
+            input.trim()
+        } else {
+            input
+        }
+    }
+}
+
+impl Default for SanitizeOptions {
+    fn default() -> Self {
+        SanitizeOptions {
+            unicode_dashes: true,
+            unicode_dots: true,
+            nbsp: true,
+            trim: true,
+            whitespace: false,
+        }
+    }
+}
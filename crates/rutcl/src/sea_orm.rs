@@ -0,0 +1,60 @@
+use ::sea_orm::sea_query::{ArrayType, ColumnType, Nullable, StringLen, ValueType, ValueTypeErr};
+use ::sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value};
+
+use crate::db::Codec;
+use crate::{Format, Rut};
+
+/// Stores the [`Rut`] as a string written in [`Format::Dash`]
+impl From<Rut> for Value {
+    fn from(rut: Rut) -> Self {
+        Value::String(Some(Box::new(rut.to_text(Format::Dash))))
+    }
+}
+
+impl Nullable for Rut {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+/// Reads a [`Rut`] from strings written in any [`Format`], or from integers
+/// holding the RUT's number.
+impl ValueType for Rut {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        let rut = match v {
+            Value::String(Some(value)) => Rut::from_text_any(&value),
+            Value::Int(Some(value)) => Rut::from_int(value.into()),
+            Value::BigInt(Some(value)) => Rut::from_int(value),
+            _ => return Err(ValueTypeErr),
+        };
+
+        rut.map_err(|_| ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        "Rut".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(StringLen::None)
+    }
+}
+
+/// Reads a [`Rut`] from a string column written in any [`Format`]
+impl TryGetable for Rut {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let value = String::try_get_by(res, index)?;
+
+        Rut::from_text_any(&value).map_err(|err| {
+            TryGetError::DbErr(DbErr::TryIntoErr {
+                from: "String",
+                into: "Rut",
+                source: Box::new(err),
+            })
+        })
+    }
+}
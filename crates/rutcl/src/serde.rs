@@ -0,0 +1,591 @@
+//! Helpers to use [`Rut`](crate::Rut) with `#[serde(with = "...")]` field
+//! attributes.
+
+/// (De)serializes an `Option<Rut>` treating an empty string as `None`.
+///
+/// Payloads generated by HTML forms or legacy backends often send an absent
+/// RUT as `""` instead of `null`, which would otherwise fail with
+/// [`Error::EmptyString`](crate::Error::EmptyString).
+///
+/// `None` is serialized as a unit value (e.g. `null` in JSON).
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Rut;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Form {
+///     #[serde(default, with = "rutcl::serde::option_empty_as_none")]
+///     rut: Option<Rut>,
+/// }
+///
+/// let form: Form = serde_json::from_str(r#"{ "rut": "" }"#).unwrap();
+///
+/// assert_eq!(form.rut, None);
+/// ```
+pub mod option_empty_as_none {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Rut;
+
+    pub fn serialize<S>(value: &Option<Rut>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(rut) => serializer.serialize_some(rut),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Rut>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionRutVisitor)
+    }
+
+    struct OptionRutVisitor;
+
+    impl<'de> Visitor<'de> for OptionRutVisitor {
+        type Value = Option<Rut>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a Rut String instance, an empty string or null")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if cfg!(feature = "serde-always-str") || deserializer.is_human_readable() {
+                return deserializer.deserialize_str(self);
+            }
+
+            Rut::deserialize(deserializer).map(Some)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            if v.trim().is_empty() {
+                return Ok(None);
+            }
+
+            Rut::from_str(v)
+                .map(Some)
+                .map_err(|err| E::custom(err.to_string()))
+        }
+    }
+}
+
+/// (De)serializes a map keyed by [`Rut`](crate::Rut), such as
+/// `HashMap<Rut, T>` or `BTreeMap<Rut, T>`, with string keys.
+///
+/// `Rut` already works as a JSON map key, this module also keeps keys as
+/// strings for compact formats (where a `Rut` is otherwise a number) and
+/// rejects maps where two keys are the same RUT written differently (e.g.
+/// `"17.951.585-7"` and `"179515857"`), which would otherwise silently
+/// overwrite each other.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rutcl::Rut;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Invoices {
+///     #[serde(with = "rutcl::serde::rut_key_map")]
+///     by_rut: HashMap<Rut, Vec<u32>>,
+/// }
+///
+/// let json = r#"{ "by_rut": { "92.635.843-K": [1, 2] } }"#;
+/// let invoices: Invoices = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(
+///     serde_json::to_string(&invoices).unwrap(),
+///     r#"{"by_rut":{"92635843K":[1,2]}}"#
+/// );
+/// assert!(serde_json::from_str::<Invoices>(
+///     r#"{ "by_rut": { "92.635.843-K": [1], "92635843k": [2] } }"#
+/// ).is_err());
+/// ```
+pub mod rut_key_map {
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use serde::de::{Error, MapAccess, Visitor};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Format, Rut};
+
+    pub fn serialize<'a, S, M, T>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        &'a M: IntoIterator<Item = (&'a Rut, &'a T)>,
+        T: Serialize + 'a,
+    {
+        let entries = map.into_iter();
+        let mut map = serializer.serialize_map(entries.size_hint().1)?;
+
+        for (rut, value) in entries {
+            map.serialize_entry(&rut.format(Format::Sans), value)?;
+        }
+
+        map.end()
+    }
+
+    pub fn deserialize<'de, D, M, T>(deserializer: D) -> Result<M, D::Error>
+    where
+        D: Deserializer<'de>,
+        M: Default + Extend<(Rut, T)>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_map(RutKeyMapVisitor(PhantomData))
+    }
+
+    struct RutKeyMapVisitor<M, T>(PhantomData<(M, T)>);
+
+    impl<'de, M, T> Visitor<'de> for RutKeyMapVisitor<M, T>
+    where
+        M: Default + Extend<(Rut, T)>,
+        T: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map keyed by Rut String instances")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = M::default();
+            let mut seen = HashSet::new();
+
+            while let Some((key, value)) = access.next_entry::<String, T>()? {
+                let rut = Rut::from_str(&key).map_err(|err| A::Error::custom(err.to_string()))?;
+
+                if !seen.insert(rut) {
+                    return Err(A::Error::custom(format!("duplicate RUT key {key:?}")));
+                }
+
+                map.extend([(rut, value)]);
+            }
+
+            Ok(map)
+        }
+    }
+}
+
+/// (De)serializes [`Rut`](crate::Rut), `Option<Rut>` and `Vec<Rut>` fields
+/// as strings written in a given [`Format`](crate::Format).
+///
+/// Use `formatted::dots`, `formatted::dash` or `formatted::sans` for `Rut`
+/// fields, and their `option` and `vec` submodules for `Option<Rut>` and
+/// `Vec<Rut>` fields. Deserialization accepts any format, serialization
+/// always writes the chosen one, even for compact formats.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Rut;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Invoice {
+///     #[serde(with = "rutcl::serde::formatted::dots")]
+///     issuer: Rut,
+///     #[serde(default, with = "rutcl::serde::formatted::dots::option")]
+///     receiver: Option<Rut>,
+///     #[serde(with = "rutcl::serde::formatted::dash::vec")]
+///     signers: Vec<Rut>,
+/// }
+///
+/// let json = r#"{ "issuer": "760864285", "receiver": null, "signers": ["17.951.585-7"] }"#;
+/// let invoice: Invoice = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(
+///     serde_json::to_string(&invoice).unwrap(),
+///     r#"{"issuer":"76.086.428-5","receiver":null,"signers":["17951585-7"]}"#
+/// );
+/// ```
+pub mod formatted {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Format, Rut};
+
+    /// Serializes the wrapped [`Rut`] as a string in the given [`Format`]
+    struct Written(Rut, Format);
+
+    impl Serialize for Written {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.0.format(self.1))
+        }
+    }
+
+    /// Deserializes a [`Rut`] from a string in any [`Format`]
+    struct Parsed(Rut);
+
+    impl<'de> Deserialize<'de> for Parsed {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(ParsedVisitor)
+        }
+    }
+
+    struct ParsedVisitor;
+
+    impl<'de> Visitor<'de> for ParsedVisitor {
+        type Value = Parsed;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a Rut String instance in any format")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Rut::from_str(v)
+                .map(Parsed)
+                .map_err(|err| E::custom(err.to_string()))
+        }
+    }
+
+    fn serialize<S>(rut: &Rut, fmt: Format, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Written(*rut, fmt).serialize(serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<Rut, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Parsed::deserialize(deserializer).map(|parsed| parsed.0)
+    }
+
+    fn serialize_option<S>(rut: &Option<Rut>, fmt: Format, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match rut {
+            Some(rut) => serializer.serialize_some(&Written(*rut, fmt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_option<'de, D>(deserializer: D) -> Result<Option<Rut>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Parsed>::deserialize(deserializer).map(|parsed| parsed.map(|parsed| parsed.0))
+    }
+
+    fn serialize_vec<S>(ruts: &[Rut], fmt: Format, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(ruts.iter().map(|rut| Written(*rut, fmt)))
+    }
+
+    fn deserialize_vec<'de, D>(deserializer: D) -> Result<Vec<Rut>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Parsed>::deserialize(deserializer)
+            .map(|parsed| parsed.into_iter().map(|parsed| parsed.0).collect())
+    }
+
+    /// Implements a module (de)serializing RUTs in `$fmt`
+    macro_rules! formatted_module {
+        ($(#[$meta:meta])* $name:ident, $fmt:expr) => {
+            $(#[$meta])*
+            pub mod $name {
+                use serde::{Deserializer, Serializer};
+
+                use crate::{Format, Rut};
+
+                pub fn serialize<S>(rut: &Rut, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    super::serialize(rut, $fmt, serializer)
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Rut, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    super::deserialize(deserializer)
+                }
+
+                /// Same as the parent module for `Option<Rut>` fields
+                pub mod option {
+                    use serde::{Deserializer, Serializer};
+
+                    use crate::{Format, Rut};
+
+                    pub fn serialize<S>(rut: &Option<Rut>, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        super::super::serialize_option(rut, $fmt, serializer)
+                    }
+
+                    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Rut>, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        super::super::deserialize_option(deserializer)
+                    }
+                }
+
+                /// Same as the parent module for `Vec<Rut>` fields
+                pub mod vec {
+                    use serde::{Deserializer, Serializer};
+
+                    use crate::{Format, Rut};
+
+                    pub fn serialize<S>(ruts: &[Rut], serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        super::super::serialize_vec(ruts, $fmt, serializer)
+                    }
+
+                    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Rut>, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        super::super::deserialize_vec(deserializer)
+                    }
+                }
+            }
+        };
+    }
+
+    formatted_module!(
+        /// (De)serializes RUTs written in [`Format::Dots`], e.g. `17.951.585-7`
+        dots,
+        Format::Dots
+    );
+
+    formatted_module!(
+        /// (De)serializes RUTs written in [`Format::Dash`], e.g. `17951585-7`
+        dash,
+        Format::Dash
+    );
+
+    formatted_module!(
+        /// (De)serializes RUTs written in [`Format::Sans`], e.g. `179515857`
+        sans,
+        Format::Sans
+    );
+}
+
+/// Implements a module (de)serializing RUTs which must belong to `$band`
+macro_rules! band_module {
+    ($(#[$meta:meta])* $name:ident, $band:expr) => {
+        $(#[$meta])*
+        pub mod $name {
+            use serde::de::Error;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::{Rut, RutBand};
+
+            /// Fails unless `rut` belongs to the band enforced by this module
+            fn check<E: Error>(rut: Rut) -> Result<Rut, E> {
+                if $band.contains(&rut) {
+                    return Ok(rut);
+                }
+
+                Err(E::custom(crate::Error::OutOfBand($band)))
+            }
+
+            pub fn serialize<S>(rut: &Rut, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                rut.serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Rut, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Rut::deserialize(deserializer).and_then(check)
+            }
+
+            /// Same as the parent module for `Option<Rut>` fields
+            pub mod option {
+                use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+                use crate::Rut;
+
+                pub fn serialize<S>(rut: &Option<Rut>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    rut.serialize(serializer)
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Rut>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Option::<Rut>::deserialize(deserializer)?
+                        .map(super::check)
+                        .transpose()
+                }
+            }
+        }
+    };
+}
+
+band_module!(
+    /// (De)serializes [`Rut`](crate::Rut) fields which must belong to
+    /// [`RutBand::Persons`](crate::RutBand::Persons), rejecting any other
+    /// RUT during deserialization.
+    ///
+    /// Use `person_only::option` for `Option<Rut>` fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Patient {
+    ///     #[serde(with = "rutcl::serde::person_only")]
+    ///     rut: Rut,
+    /// }
+    ///
+    /// assert!(serde_json::from_str::<Patient>(r#"{ "rut": "17.951.585-7" }"#).is_ok());
+    /// assert!(serde_json::from_str::<Patient>(r#"{ "rut": "76.086.428-5" }"#).is_err());
+    /// ```
+    person_only,
+    RutBand::Persons
+);
+
+band_module!(
+    /// (De)serializes [`Rut`](crate::Rut) fields which must belong to
+    /// [`RutBand::Companies`](crate::RutBand::Companies), rejecting any other
+    /// RUT during deserialization.
+    ///
+    /// Use `company_only::option` for `Option<Rut>` fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Invoice {
+    ///     #[serde(with = "rutcl::serde::company_only")]
+    ///     sender: Rut,
+    ///     #[serde(default, with = "rutcl::serde::company_only::option")]
+    ///     carrier: Option<Rut>,
+    /// }
+    ///
+    /// let json = r#"{ "sender": "76.086.428-5", "carrier": null }"#;
+    ///
+    /// assert!(serde_json::from_str::<Invoice>(json).is_ok());
+    /// assert!(serde_json::from_str::<Invoice>(r#"{ "sender": "17.951.585-7" }"#).is_err());
+    /// ```
+    company_only,
+    RutBand::Companies
+);
+
+/// (De)serializes a [`Rut`](crate::Rut) as a [`RutParts`](crate::RutParts)
+/// struct, verifying the number and digit during deserialization.
+///
+/// Combined with `#[serde(flatten)]` it maps a [`Rut`](crate::Rut) field to
+/// the separate `num` and `vd` fields used by many APIs and databases.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Rut;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Employee {
+///     name: String,
+///     #[serde(flatten, with = "rutcl::serde::parts")]
+///     rut: Rut,
+/// }
+///
+/// let json = r#"{ "name": "Juan", "num": 17951585, "vd": "7" }"#;
+/// let employee: Employee = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(employee.rut, "17.951.585-7".parse::<Rut>().unwrap());
+/// assert_eq!(
+///     serde_json::to_string(&employee).unwrap(),
+///     r#"{"name":"Juan","num":17951585,"vd":"7"}"#
+/// );
+/// assert!(serde_json::from_str::<Employee>(r#"{ "name": "Juan", "num": 17951585, "vd": 8 }"#).is_err());
+/// ```
+pub mod parts {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Rut, RutParts};
+
+    pub fn serialize<S>(rut: &Rut, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RutParts::from(*rut).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Rut, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parts = RutParts::deserialize(deserializer)?;
+
+        Rut::try_from(parts).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,151 @@
+use std::iter::FusedIterator;
+use std::ops::RangeInclusive;
+
+use crate::{Num, Rut, RutBand, VerificationDigit, RANGE};
+
+/// Feistel rounds applied to every index
+const ROUNDS: u64 = 6;
+
+/// Iterator over a pseudorandom permutation of a range of [`Rut`]s.
+///
+/// Each [`Rut`] in the range is yielded exactly once, in an order fully
+/// determined by the seed, without keeping track of the ones already
+/// yielded: the n-th item is computed by encrypting `n` with a small
+/// Feistel network over the range's size, so memory usage is constant
+/// regardless of the range.
+///
+/// > The permutation is meant to shuffle test sweeps, it is not
+/// > cryptographically secure.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::Rut;
+///
+/// let first = Rut::random_stream(42).take(3).collect::<Vec<Rut>>();
+/// let again = Rut::random_stream(42).take(3).collect::<Vec<Rut>>();
+///
+/// assert_eq!(first, again);
+/// assert_ne!(first, Rut::random_stream(7).take(3).collect::<Vec<Rut>>());
+/// assert_eq!(Rut::random_stream(42).len(), 99_000_000);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RutShuffle {
+    start: Num,
+    len: u64,
+    idx: u64,
+    half_bits: u32,
+    seed: u64,
+}
+
+impl RutShuffle {
+    /// Creates a [`RutShuffle`] from `start` to `end` (inclusive), shuffled
+    /// with the provided seed
+    pub fn new(start: Rut, end: Rut, seed: u64) -> Self {
+        RutShuffle::from_nums(start.num()..=end.num(), seed)
+    }
+
+    /// Builds a [`RutShuffle`] over numbers already known to be valid
+    pub(crate) fn from_nums(range: RangeInclusive<Num>, seed: u64) -> Self {
+        let (start, end) = range.into_inner();
+        let len = if start > end {
+            0
+        } else {
+            (end - start) as u64 + 1
+        };
+        // The Feistel domain is the smallest power of two holding every
+        // index, rounded up to an even amount of bits
+        let bits = (u64::BITS - len.saturating_sub(1).leading_zeros()).max(2);
+
+        RutShuffle {
+            start,
+            len,
+            idx: 0,
+            half_bits: bits.div_ceil(2),
+            seed,
+        }
+    }
+
+    /// Maps an index to its position in the permutation, walking the
+    /// Feistel cycle until landing back inside of the range
+    fn permute(&self, idx: u64) -> u64 {
+        let mut value = idx;
+
+        loop {
+            value = self.feistel(value);
+
+            if value < self.len {
+                return value;
+            }
+        }
+    }
+
+    /// Balanced Feistel network over `2 * half_bits` bits
+    fn feistel(&self, value: u64) -> u64 {
+        let mask = (1 << self.half_bits) - 1;
+        let mut left = value >> self.half_bits;
+        let mut right = value & mask;
+
+        for round in 0..ROUNDS {
+            let next = left ^ (mix(self.seed ^ (round << 32) ^ right) & mask);
+
+            left = right;
+            right = next;
+        }
+
+        (left << self.half_bits) | right
+    }
+}
+
+/// SplitMix64 finalizer
+fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+impl Iterator for RutShuffle {
+    type Item = Rut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+
+        let num = self.start + self.permute(self.idx) as Num;
+        self.idx += 1;
+
+        Some(Rut(num, VerificationDigit::compute(num)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.idx) as usize;
+
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.idx = self.idx.saturating_add(n as u64).min(self.len);
+        self.next()
+    }
+}
+
+impl ExactSizeIterator for RutShuffle {}
+
+impl FusedIterator for RutShuffle {}
+
+impl Rut {
+    /// Iterates over every valid [`Rut`] exactly once, in a pseudorandom
+    /// order determined by `seed`. See [`RutShuffle`].
+    pub fn random_stream(seed: u64) -> RutShuffle {
+        RutShuffle::from_nums(RANGE, seed)
+    }
+}
+
+impl RutBand {
+    /// Iterates over every [`Rut`] in this band exactly once, in a
+    /// pseudorandom order determined by `seed`. See [`RutShuffle`].
+    pub fn random_stream(&self, seed: u64) -> RutShuffle {
+        RutShuffle::from_nums(self.range(), seed)
+    }
+}
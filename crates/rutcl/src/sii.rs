@@ -0,0 +1,171 @@
+//! RUTs as required by the SII electronic invoicing (DTE) XML schema.
+//!
+//! The schema declares every RUT field (`RUTEmisor`, `RUTRecep`, `RutEnvia`
+//! and friends) with the `RUTType` simple type:
+//!
+//! ```xml
+//! <xs:simpleType name="RUTType">
+//!   <xs:restriction base="xs:string">
+//!     <xs:minLength value="3"/>
+//!     <xs:maxLength value="10"/>
+//!     <xs:pattern value="[0-9]+-([0-9]|K)"/>
+//!   </xs:restriction>
+//! </xs:simpleType>
+//! ```
+//!
+//! Values violating any of these facets fail with [`Error::SiiSchema`]
+//! naming the offending [`Rule`], the rest of the checks (range and
+//! verification digit) fail with the regular [`Error`] variants.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Error, Format, Rut};
+
+/// RUT of the document issuer, in `Encabezado/Emisor`
+pub const RUT_EMISOR: &str = "RUTEmisor";
+
+/// RUT of the document receiver, in `Encabezado/Receptor`
+pub const RUT_RECEP: &str = "RUTRecep";
+
+/// RUT of the person submitting the envelope, in `Caratula`
+pub const RUT_ENVIA: &str = "RutEnvia";
+
+/// RUT of the envelope's receiver, in `Caratula`
+pub const RUT_RECEPTOR: &str = "RutReceptor";
+
+/// Min length allowed by `RUTType`
+const MIN_LENGTH: usize = 3;
+
+/// Max length allowed by `RUTType`
+const MAX_LENGTH: usize = 10;
+
+/// Facets of the SII `RUTType` XML schema type
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Rule {
+    /// `RUTType.minLength`, at least 3 chars
+    MinLength,
+    /// `RUTType.maxLength`, at most 10 chars
+    MaxLength,
+    /// `RUTType.pattern`, digits followed by a dash and a digit or `K`
+    Pattern,
+}
+
+impl Rule {
+    /// Retrieves the rule name as referenced in SII validation reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::MinLength => "RUTType.minLength",
+            Rule::MaxLength => "RUTType.maxLength",
+            Rule::Pattern => "RUTType.pattern",
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Formats a [`Rut`] as required by `RUTType`: no dots, a dash and an
+/// uppercase `K`.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{sii, Rut};
+///
+/// let rut: Rut = "92.635.843-k".parse().unwrap();
+///
+/// assert_eq!(sii::format(&rut), "92635843-K");
+/// ```
+pub fn format(rut: &Rut) -> String {
+    rut.format(Format::Dash)
+}
+
+/// Parses a `RUTType` value, rejecting anything the SII schema would reject.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::sii::{self, Rule};
+/// use rutcl::Error;
+///
+/// assert_eq!(sii::parse("17951585-7").unwrap().num(), 17_951_585);
+/// assert!(matches!(
+///     sii::parse("92635843-k"),
+///     Err(Error::SiiSchema { rule: Rule::Pattern, .. })
+/// ));
+/// ```
+pub fn parse(value: &str) -> Result<Rut, Error> {
+    let violation = |rule| Error::SiiSchema {
+        rule,
+        value: value.to_string(),
+    };
+
+    if value.len() < MIN_LENGTH {
+        return Err(violation(Rule::MinLength));
+    }
+
+    if value.len() > MAX_LENGTH {
+        return Err(violation(Rule::MaxLength));
+    }
+
+    let matches_pattern = value.split_once('-').is_some_and(|(num, vd)| {
+        !num.is_empty()
+            && num.bytes().all(|b| b.is_ascii_digit())
+            && matches!(vd.as_bytes(), [b'0'..=b'9' | b'K'])
+    });
+
+    if !matches_pattern {
+        return Err(violation(Rule::Pattern));
+    }
+
+    Rut::from_str(value)
+}
+
+/// Parses the RUT held by the `name` element (`<name>...</name>`) or
+/// attribute (`name="..."`) of a DTE XML document.
+///
+/// Fails with [`Error::InvalidFormat`] if there is no such element nor
+/// attribute.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::sii::{self, RUT_EMISOR, RUT_RECEP};
+///
+/// let dte = r#"<Encabezado>
+///     <Emisor><RUTEmisor>76086428-5</RUTEmisor></Emisor>
+///     <Receptor><RUTRecep>17951585-7</RUTRecep></Receptor>
+/// </Encabezado>"#;
+///
+/// assert_eq!(sii::find(dte, RUT_EMISOR).unwrap().num(), 76_086_428);
+/// assert_eq!(sii::find(dte, RUT_RECEP).unwrap().num(), 17_951_585);
+/// ```
+pub fn find(xml: &str, name: &str) -> Result<Rut, Error> {
+    element(xml, name)
+        .or_else(|| attribute(xml, name))
+        .ok_or(Error::InvalidFormat)
+        .and_then(|value| parse(value.trim()))
+}
+
+/// Retrieves the text inside of the first `<name>...</name>` element
+pub(crate) fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let open = format!("<{name}>");
+    let close = format!("</{name}>");
+    let start = xml.find(&open)? + open.len();
+    let len = xml[start..].find(&close)?;
+
+    Some(&xml[start..start + len])
+}
+
+/// Retrieves the value of the first `name="..."` attribute
+fn attribute<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let open = format!(" {name}=\"");
+    let start = xml.find(&open)? + open.len();
+    let len = xml[start..].find('"')?;
+
+    Some(&xml[start..start + len])
+}
@@ -0,0 +1,111 @@
+//! Sorting, searching and formatting helpers for slices of [`Rut`]s.
+//!
+//! [`Rut`]'s order is the order of its number, so the inherent slice methods
+//! (`sort_unstable`, `binary_search`, ...) already sort and search RUTs
+//! correctly. [`RutSliceExt`] adds the shortcuts comparing numbers only and
+//! bulk formatting on top of them, [`RutVecExt`] the ones which need to
+//! shrink a `Vec<Rut>`.
+
+use crate::ascii::MAX_LEN;
+use crate::{default_format, Format, Num, Rut};
+
+/// Sorting, searching and bulk formatting for `[Rut]`, and so for
+/// `Vec<Rut>` as well.
+///
+/// Searching methods expect slices sorted in ascending order, as left by
+/// [`RutSliceExt::sort_by_num`].
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Format, Rut, RutSliceExt, RutVecExt};
+///
+/// let mut ruts: Vec<Rut> = ["92.635.843-K", "17.951.585-7", "92635843-K"]
+///     .into_iter()
+///     .map(|rut| rut.parse().unwrap())
+///     .collect();
+///
+/// ruts.sort_by_num();
+/// ruts.dedup_exact();
+///
+/// assert_eq!(ruts.binary_search_num(92_635_843), Ok(1));
+/// assert_eq!(ruts.format_all(Format::Dots), ["17.951.585-7", "92.635.843-K"]);
+/// ```
+pub trait RutSliceExt {
+    /// Sorts the RUTs in ascending order, without preserving the order of
+    /// equal RUTs.
+    ///
+    /// Sorts by the RUT's number, which is cheaper than comparing whole RUTs
+    /// and yields the same order.
+    fn sort_by_num(&mut self);
+
+    /// Binary searches the sorted RUTs for the one with the provided number
+    /// ([`Num`]), without computing its verification digit.
+    ///
+    /// Returns `Ok` with the index of the matching RUT or `Err` with the
+    /// index where it could be inserted keeping the RUTs sorted, as
+    /// `binary_search` does.
+    fn binary_search_num(&self, num: Num) -> Result<usize, usize>;
+
+    /// Formats every RUT in the provided [`Format`]
+    fn format_all(&self, fmt: Format) -> Vec<String>;
+
+    /// Writes the RUTs as the values of a CSV column, one per line in the
+    /// [`default_format`], without header.
+    ///
+    /// Every value is written into a single buffer.
+    fn to_csv_column(&self) -> String;
+}
+
+impl RutSliceExt for [Rut] {
+    fn sort_by_num(&mut self) {
+        self.sort_unstable_by_key(Rut::num);
+    }
+
+    fn binary_search_num(&self, num: Num) -> Result<usize, usize> {
+        self.binary_search_by_key(&num, Rut::num)
+    }
+
+    fn format_all(&self, fmt: Format) -> Vec<String> {
+        self.iter()
+            .map(|rut| {
+                let mut buf = String::with_capacity(MAX_LEN);
+
+                push_formatted(&mut buf, rut, fmt);
+                buf
+            })
+            .collect()
+    }
+
+    fn to_csv_column(&self) -> String {
+        let fmt = default_format();
+        let mut buf = String::with_capacity(self.len() * (MAX_LEN + 1));
+
+        for rut in self {
+            push_formatted(&mut buf, rut, fmt);
+            buf.push('\n');
+        }
+
+        buf
+    }
+}
+
+/// Deduplication for `Vec<Rut>`, see [`RutSliceExt`] for the helpers
+/// working on any slice
+pub trait RutVecExt {
+    /// Removes consecutive repeated RUTs, as `Vec::dedup` does. Sort the
+    /// RUTs first to remove every duplicate.
+    fn dedup_exact(&mut self);
+}
+
+impl RutVecExt for Vec<Rut> {
+    fn dedup_exact(&mut self) {
+        self.dedup();
+    }
+}
+
+/// Appends the RUT written in the provided [`Format`], as [`Rut::format`]
+/// does, without allocating
+fn push_formatted(buf: &mut String, rut: &Rut, fmt: Format) {
+    buf.push_str(rut.ascii(fmt, &mut [0; MAX_LEN]));
+}
@@ -0,0 +1,41 @@
+//! Allocation-free formatting through `smallstr`.
+
+use smallstr::SmallString;
+
+use crate::ascii::MAX_LEN;
+use crate::{BatchLine, Format, Rut};
+
+impl Rut {
+    /// Writes this [`Rut`] using the provided [`Format`] into an inline
+    /// string, without allocating.
+    ///
+    /// The longest representation, `99.999.999-9`, is 12 bytes long, so the
+    /// result always fits in the 16 bytes buffer. Prefer it over
+    /// [`Rut::format`] when formatting many RUTs in tight loops.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Format, Rut};
+    ///
+    /// let rut: Rut = "92635843K".parse().unwrap();
+    /// let dots = rut.format_smol(Format::Dots);
+    ///
+    /// assert_eq!(dots, "92.635.843-K");
+    /// assert!(!dots.spilled());
+    /// ```
+    pub fn format_smol(&self, fmt: Format) -> SmallString<[u8; 16]> {
+        SmallString::from_str(self.ascii(fmt, &mut [0; MAX_LEN]))
+    }
+}
+
+impl BatchLine<'_> {
+    /// Same as [`BatchLine::convert`] using [`Rut::format_smol`], only lines
+    /// which failed to parse and are longer than 16 bytes allocate
+    pub fn convert_smol(&self, fmt: Format) -> SmallString<[u8; 16]> {
+        match self.result() {
+            Ok(rut) => rut.format_smol(fmt),
+            Err(_) => SmallString::from_str(self.input()),
+        }
+    }
+}
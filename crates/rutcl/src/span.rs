@@ -0,0 +1,69 @@
+use std::ops::Range;
+
+use crate::{Error, SanitizeOptions};
+
+impl Error {
+    /// Retrieves the byte range of `input` this error points to, `input`
+    /// being the string which failed to parse.
+    ///
+    /// Verification digit errors point to the last char, number errors to
+    /// the first non digit char in the number or to the whole number,
+    /// [`Error::Syntax`] to the char at its offset and every other error to
+    /// the whole input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let input = "17.951.585-K";
+    /// let err = input.parse::<Rut>().unwrap_err();
+    ///
+    /// assert_eq!(err.span(input), 11..12);
+    /// assert_eq!(&input[err.span(input)], "K");
+    /// ```
+    pub fn span(&self, input: &str) -> Range<usize> {
+        let options = SanitizeOptions::default();
+        let start = input.len() - input.trim_start().len();
+        let chars = options
+            .trim(input)
+            .char_indices()
+            .map(|(idx, c)| (start + idx, c))
+            .filter(|(_, c)| !options.is_separator(*c))
+            .collect::<Vec<(usize, char)>>();
+        let whole = 0..input.len();
+
+        match (self, chars.split_last()) {
+            (
+                Error::InvalidVerificationDigit { .. } | Error::VerificationDigitOutOfBounds(_),
+                Some((last, _)),
+            ) => char_span(last),
+            (Error::NaN(_), Some((_, body))) => body
+                .iter()
+                .find(|(_, c)| !c.is_ascii_digit())
+                .map(char_span)
+                .or_else(|| body_span(body))
+                .unwrap_or(whole),
+            (Error::OutOfRange, Some((_, body))) => body_span(body).unwrap_or(whole),
+            (Error::Syntax { offset, .. }, _) => input
+                .get(*offset..)
+                .and_then(|rest| rest.chars().next())
+                .map(|c| char_span(&(*offset, c)))
+                .unwrap_or(whole),
+            _ => whole,
+        }
+    }
+}
+
+/// Span covering a single char
+fn char_span((idx, c): &(usize, char)) -> Range<usize> {
+    *idx..idx + c.len_utf8()
+}
+
+/// Span covering the RUT's number chars
+fn body_span(body: &[(usize, char)]) -> Option<Range<usize>> {
+    let (start, _) = body.first()?;
+    let (end, c) = body.last()?;
+
+    Some(*start..end + c.len_utf8())
+}
@@ -0,0 +1,108 @@
+use std::num::IntErrorKind;
+
+use crate::{Error, Expected, ForeignTaxId, Format, RutBand, RutPart, MAX, MIN};
+
+impl Error {
+    /// Retrieves a user facing message for this error in Spanish, suitable
+    /// to be shown verbatim in form UIs.
+    ///
+    /// Unlike the [`Display`](std::fmt::Display) output, which is meant for
+    /// logs, messages avoid internal details such as Rust error kinds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let err = "17.951.585-8".parse::<Rut>().unwrap_err();
+    ///
+    /// assert_eq!(err.to_spanish(), "Dígito verificador inválido: se esperaba 7");
+    /// ```
+    pub fn to_spanish(&self) -> String {
+        match self {
+            Error::InvalidVerificationDigit { want, .. } => {
+                format!("Dígito verificador inválido: se esperaba {want}")
+            }
+            Error::VerificationDigitOutOfBounds(vd) => {
+                format!("Dígito verificador inválido: {vd:?}, debe ser un número del 0 al 9 o K")
+            }
+            Error::InvalidFormat => "Formato de RUT inválido".to_string(),
+            Error::NaN(err) => match err.kind() {
+                IntErrorKind::Empty => "Falta el número del RUT".to_string(),
+                IntErrorKind::PosOverflow => "El número del RUT es demasiado largo".to_string(),
+                _ => "El RUT contiene caracteres no válidos".to_string(),
+            },
+            Error::OutOfRange => format!(
+                "El RUT debe estar entre {} y {}",
+                MIN.format(Format::Dots),
+                MAX.format(Format::Dots)
+            ),
+            Error::EmptyString => "Debe ingresar un RUT".to_string(),
+            Error::OutOfBand(band) => format!("Se esperaba un RUT {}", band_name(band)),
+            Error::UnsupportedWireVersion(version) => {
+                format!("Versión de codificación no soportada: {version}")
+            }
+            Error::EnvVar { var, .. } => format!("La variable de entorno {var} no está definida"),
+            Error::InvalidEnvVar { var, value, source } => format!(
+                "RUT {value:?} inválido en la variable de entorno {var}: {}",
+                source.to_spanish()
+            ),
+            Error::InvalidValue { value, source } => {
+                format!("RUT {value:?} inválido: {}", source.to_spanish())
+            }
+            Error::SiiSchema { rule, value } => {
+                format!("El RUT {value:?} no cumple la regla {rule} del esquema del SII")
+            }
+            Error::RejectedBand(band) => format!("No se aceptan RUTs {}", band_name(band)),
+            Error::LooksLikeForeignTaxId { guess } => {
+                let guess = match guess {
+                    ForeignTaxId::ArgentineCuit => "un CUIT argentino",
+                    ForeignTaxId::PeruvianRuc => "un RUC peruano",
+                };
+
+                format!("No es un RUT, parece ser {guess}")
+            }
+            Error::InvalidPart { part, source } => {
+                let part = match part {
+                    RutPart::Num => "Número del RUT inválido",
+                    RutPart::VerificationDigit => "Dígito verificador inválido",
+                };
+
+                match source.as_ref() {
+                    Error::InvalidVerificationDigit { .. } => source.to_spanish(),
+                    source => format!("{part}: {}", source.to_spanish()),
+                }
+            }
+            Error::Syntax { offset, expected } => {
+                let expected = match expected {
+                    Expected::Number => "un número sin ceros a la izquierda",
+                    Expected::Group => "un grupo de 3 dígitos",
+                    Expected::Dot => "`.`",
+                    Expected::Dash => "`-`",
+                    Expected::VerificationDigit => "el dígito verificador",
+                    Expected::EndOfInput => "el fin del RUT",
+                };
+
+                format!(
+                    "RUT mal escrito en la posición {}: se esperaba {expected}",
+                    offset + 1
+                )
+            }
+            Error::Placeholder => "No se aceptan RUTs genéricos".to_string(),
+        }
+    }
+}
+
+/// Describes the RUTs in a band, e.g. `de persona natural`
+fn band_name(band: &RutBand) -> String {
+    match band {
+        RutBand::Persons => "de persona natural".to_string(),
+        RutBand::Companies => "de empresa".to_string(),
+        RutBand::ForeignInvestors => "de inversionista extranjero".to_string(),
+        RutBand::Custom(_) => format!(
+            "entre {} y {}",
+            band.min().format(Format::Dots),
+            band.max().format(Format::Dots)
+        ),
+    }
+}
@@ -0,0 +1,44 @@
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+use crate::db::Codec;
+use crate::Rut;
+
+/// Stored as an `INTEGER` holding the RUT's number, see [`Codec::to_int`]
+impl<DB: Database> Type<DB> for Rut
+where
+    i32: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i32 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i32 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for Rut
+where
+    i32: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_int().encode_by_ref(buf)
+    }
+}
+
+/// Fails for numbers which are not valid RUT numbers
+impl<'r, DB: Database> Decode<'r, DB> for Rut
+where
+    i32: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let num = <i32 as Decode<'r, DB>>::decode(value)?;
+
+        Ok(Rut::from_int(i64::from(num))?)
+    }
+}
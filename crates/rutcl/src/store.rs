@@ -0,0 +1,339 @@
+//! Compact storage for large sets of [`Rut`]s.
+
+use std::iter::FusedIterator;
+
+use crate::{Error, Num, Rut, VerificationDigit, RANGE};
+
+/// Amount of RUTs between two entries of the skip index
+const STRIDE: usize = 64;
+
+/// A sorted run of distinct [`Rut`]s, stored as varint encoded deltas
+/// between consecutive RUT numbers.
+///
+/// Dense sets such as allowlists take around a byte per RUT plus a small
+/// skip index, instead of the 8 bytes taken by each [`Rut`] in a `Vec`.
+/// Membership checks binary search the skip index and decode at most a few
+/// dozen deltas, so they take `O(log n)` time.
+///
+/// # Binary Encoding
+///
+/// [`SortedRutBlock::to_bytes`] writes [`SortedRutBlock::VERSION`], the
+/// amount of RUTs and then each delta, starting from `0`, all of them but
+/// the version as LEB128 varints. The verification digits are not stored.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::store::SortedRutBlock;
+/// use rutcl::Rut;
+///
+/// let block = ["76.086.428-5", "17.951.585-7", "17951585-7"]
+///     .into_iter()
+///     .map(|rut| rut.parse::<Rut>().unwrap())
+///     .collect::<SortedRutBlock>();
+///
+/// assert_eq!(block.len(), 2);
+/// assert!(block.contains(&"17.951.585-7".parse().unwrap()));
+/// assert!(!block.contains(&"92.635.843-K".parse().unwrap()));
+/// assert_eq!(SortedRutBlock::from_bytes(&block.to_bytes()).unwrap(), block);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SortedRutBlock {
+    /// Varint encoded deltas
+    deltas: Vec<u8>,
+    /// Number of every [`STRIDE`]-th RUT and the offset of the delta after it
+    index: Vec<(Num, u32)>,
+    /// Number of the last RUT, `0` when empty
+    last: Num,
+    len: usize,
+}
+
+impl SortedRutBlock {
+    /// Version tag written as the first byte of the binary encoding
+    pub const VERSION: u8 = 1;
+
+    /// Creates an empty [`SortedRutBlock`]
+    pub fn new() -> Self {
+        SortedRutBlock::default()
+    }
+
+    /// Builds a [`SortedRutBlock`] from RUTs already sorted in strictly
+    /// ascending order, without buffering them.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if a RUT is not greater than the
+    /// previous one. Collect into a [`SortedRutBlock`] to sort and
+    /// deduplicate the RUTs instead.
+    pub fn from_sorted<I>(ruts: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Rut>,
+    {
+        let mut block = SortedRutBlock::new();
+
+        for rut in ruts {
+            block.push(rut.num())?;
+        }
+
+        Ok(block)
+    }
+
+    /// Amount of RUTs in this block
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether this block holds no RUTs
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bytes taken by the encoded RUTs and the skip index, excluding unused
+    /// capacity
+    pub fn size_in_bytes(&self) -> usize {
+        self.deltas.len() + self.index.len() * std::mem::size_of::<(Num, u32)>()
+    }
+
+    /// Checks whether the provided [`Rut`] is in this block
+    pub fn contains(&self, rut: &Rut) -> bool {
+        let num = rut.num();
+        let idx = match self.index.binary_search_by_key(&num, |(start, _)| *start) {
+            Ok(_) => return true,
+            Err(0) => return false,
+            Err(idx) => idx - 1,
+        };
+        let (start, offset) = self.index[idx];
+        let remaining = (self.len - idx * STRIDE - 1).min(STRIDE - 1);
+
+        Iter::new(&self.deltas[offset as usize..], start, remaining)
+            .map(|rut| rut.num())
+            .take_while(|item| *item <= num)
+            .any(|item| item == num)
+    }
+
+    /// Iterates over the RUTs in ascending order
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.deltas, 0, self.len)
+    }
+
+    /// Encodes this block, see [`SortedRutBlock`] for the layout
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.deltas.len() + 6);
+
+        bytes.push(Self::VERSION);
+        write_varint(&mut bytes, self.len as u64);
+        bytes.extend_from_slice(&self.deltas);
+        bytes
+    }
+
+    /// Decodes a block written by [`SortedRutBlock::to_bytes`].
+    ///
+    /// Fails with [`Error::UnsupportedWireVersion`] for other versions, with
+    /// [`Error::InvalidFormat`] for truncated or trailing bytes and with
+    /// [`Error::OutOfRange`] for numbers which are not valid RUT numbers.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (&version, mut rest) = bytes.split_first().ok_or(Error::InvalidFormat)?;
+
+        if version != Self::VERSION {
+            return Err(Error::UnsupportedWireVersion(version));
+        }
+
+        let len = read_varint(&mut rest).ok_or(Error::InvalidFormat)?;
+        let mut block = SortedRutBlock::new();
+        let mut num: u64 = 0;
+
+        for _ in 0..len {
+            let delta = read_varint(&mut rest).ok_or(Error::InvalidFormat)?;
+
+            num = num.checked_add(delta).ok_or(Error::OutOfRange)?;
+            block.push(Num::try_from(num).map_err(|_| Error::OutOfRange)?)?;
+        }
+
+        if !rest.is_empty() {
+            return Err(Error::InvalidFormat);
+        }
+
+        Ok(block)
+    }
+
+    /// Appends a RUT number greater than the last one
+    fn push(&mut self, num: Num) -> Result<(), Error> {
+        if !RANGE.contains(&num) {
+            return Err(Error::OutOfRange);
+        }
+
+        if num <= self.last {
+            return Err(Error::InvalidFormat);
+        }
+
+        write_varint(&mut self.deltas, (num - self.last) as u64);
+
+        if self.len.is_multiple_of(STRIDE) {
+            self.index.push((num, self.deltas.len() as u32));
+        }
+
+        self.last = num;
+        self.len += 1;
+
+        Ok(())
+    }
+}
+
+impl FromIterator<Rut> for SortedRutBlock {
+    /// Sorts and deduplicates the RUTs before encoding them
+    fn from_iter<T: IntoIterator<Item = Rut>>(iter: T) -> Self {
+        let mut nums = iter.into_iter().map(|rut| rut.num()).collect::<Vec<Num>>();
+
+        nums.sort_unstable();
+        nums.dedup();
+
+        let mut block = SortedRutBlock::new();
+
+        for num in nums {
+            block
+                .push(num)
+                .expect("Sorted and deduplicated RUT numbers are valid");
+        }
+
+        block
+    }
+}
+
+impl<'a> IntoIterator for &'a SortedRutBlock {
+    type Item = Rut;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the RUTs of a [`SortedRutBlock`], created with
+/// [`SortedRutBlock::iter`]
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    deltas: &'a [u8],
+    num: Num,
+    remaining: usize,
+}
+
+impl<'a> Iter<'a> {
+    fn new(deltas: &'a [u8], num: Num, remaining: usize) -> Self {
+        Iter {
+            deltas,
+            num,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Rut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let delta = read_varint(&mut self.deltas).expect("Deltas are validated when pushed");
+
+        self.num += delta as Num;
+        self.remaining -= 1;
+
+        Some(Rut(self.num, VerificationDigit::compute(self.num)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// Appends `value` as an LEB128 varint
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.push(value as u8);
+}
+
+/// Reads an LEB128 varint, advancing `bytes` past it
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+
+    for (idx, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * idx);
+
+        if byte & 0x80 == 0 {
+            *bytes = &bytes[idx + 1..];
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SortedRutBlock;
+
+    /// Serializes the binary encoding as bytes
+    impl Serialize for SortedRutBlock {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SortedRutBlock {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_bytes(SortedRutBlockVisitor)
+        }
+    }
+
+    struct SortedRutBlockVisitor;
+
+    impl<'de> Visitor<'de> for SortedRutBlockVisitor {
+        type Value = SortedRutBlock;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("the binary encoding of a SortedRutBlock")
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            SortedRutBlock::from_bytes(v).map_err(|err| E::custom(err.to_string()))
+        }
+
+        /// Human readable formats such as JSON write bytes as sequences
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+
+            self.visit_bytes(&bytes)
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Validation combinators for `futures::Stream`s of RUT strings.
+
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::{Error, Format, Rut};
+
+/// Extends streams of RUT strings (e.g. message payloads consumed from a
+/// queue) with validation combinators.
+///
+/// # Example
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use rutcl::stream::RutStreamExt;
+/// use rutcl::Format;
+///
+/// let messages = stream::iter(vec!["179515857", "not a RUT", "92635843k"]);
+/// let normalized = block_on(
+///     messages
+///         .normalize_ruts(Format::Dots)
+///         .filter_map(|rut| async move { rut.ok() })
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// assert_eq!(normalized, vec!["17.951.585-7", "92.635.843-K"]);
+/// ```
+pub trait RutStreamExt: Stream + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Parses every item, yielding the parsing result
+    fn validate_ruts(self) -> ValidateRuts<Self> {
+        ValidateRuts { stream: self }
+    }
+
+    /// Parses every item, yielding valid RUTs and skipping the rest
+    fn filter_valid_ruts(self) -> FilterValidRuts<Self> {
+        FilterValidRuts { stream: self }
+    }
+
+    /// Parses every item, yielding valid RUTs written in the provided
+    /// [`Format`]
+    fn normalize_ruts(self, fmt: Format) -> NormalizeRuts<Self> {
+        NormalizeRuts { stream: self, fmt }
+    }
+}
+
+impl<S> RutStreamExt for S
+where
+    S: Stream,
+    S::Item: AsRef<str>,
+{
+}
+
+pin_project! {
+    /// Stream returned by [`RutStreamExt::validate_ruts`]
+    #[derive(Clone, Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ValidateRuts<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> Stream for ValidateRuts<S>
+where
+    S: Stream,
+    S::Item: AsRef<str>,
+{
+    type Item = Result<Rut, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project()
+            .stream
+            .poll_next(cx)
+            .map(|item| item.map(|item| Rut::from_str(item.as_ref())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    /// Stream returned by [`RutStreamExt::filter_valid_ruts`]
+    #[derive(Clone, Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FilterValidRuts<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> Stream for FilterValidRuts<S>
+where
+    S: Stream,
+    S::Item: AsRef<str>,
+{
+    type Item = Rut;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut stream = self.project().stream;
+
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if let Ok(rut) = Rut::from_str(item.as_ref()) {
+                        return Poll::Ready(Some(rut));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+pin_project! {
+    /// Stream returned by [`RutStreamExt::normalize_ruts`]
+    #[derive(Clone, Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct NormalizeRuts<S> {
+        #[pin]
+        stream: S,
+        fmt: Format,
+    }
+}
+
+impl<S> Stream for NormalizeRuts<S>
+where
+    S: Stream,
+    S::Item: AsRef<str>,
+{
+    type Item = Result<String, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let fmt = *this.fmt;
+
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|item| Rut::from_str(item.as_ref()).map(|rut| rut.format(fmt))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
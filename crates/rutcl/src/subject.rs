@@ -0,0 +1,84 @@
+use crate::{Error, Format, Rut};
+
+/// Placeholder replaced by the subject token in subject templates
+const PLACEHOLDER: &str = "{rut}";
+
+impl Rut {
+    /// Retrieves a token for this [`Rut`] which can be used as a NATS subject
+    /// or MQTT topic segment.
+    ///
+    /// The token is the [`Format::Dash`] representation, it has no dots,
+    /// which separate NATS subject tokens, and no wildcard or separator
+    /// chars (`*`, `>`, `+`, `#`, `/`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "92.635.843-k".parse().unwrap();
+    ///
+    /// assert_eq!(rut.to_subject_token(), "92635843-K");
+    /// assert_eq!(Rut::from_subject_token("92635843-K").unwrap(), rut);
+    /// ```
+    pub fn to_subject_token(&self) -> String {
+        self.format(Format::Dash)
+    }
+
+    /// Parses a token written by [`Rut::to_subject_token`].
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the token is not written in
+    /// [`Format::Dash`].
+    pub fn from_subject_token(token: &str) -> Result<Self, Error> {
+        Rut::from_formatted(token, Format::Dash)
+    }
+
+    /// Builds a subject from a template, replacing the `{rut}` token with
+    /// [`Rut::to_subject_token`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    ///
+    /// let rut: Rut = "76.086.428-5".parse().unwrap();
+    /// let subject = rut.subject("invoices.{rut}.created");
+    ///
+    /// assert_eq!(subject, "invoices.76086428-5.created");
+    /// assert_eq!(Rut::from_subject(&subject, "invoices.{rut}.created").unwrap(), rut);
+    /// assert!(Rut::from_subject(&subject, "invoices.{rut}.paid").is_err());
+    /// ```
+    pub fn subject(&self, template: &str) -> String {
+        template
+            .split('.')
+            .map(|token| match token {
+                PLACEHOLDER => self.to_subject_token(),
+                token => token.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Retrieves the [`Rut`] from a subject built with [`Rut::subject`] and
+    /// the same template.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the subject doesn't match the
+    /// template or the template has no `{rut}` token.
+    pub fn from_subject(subject: &str, template: &str) -> Result<Self, Error> {
+        let mut rut = None;
+        let mut tokens = subject.split('.');
+
+        for expected in template.split('.') {
+            match (expected, tokens.next()) {
+                (PLACEHOLDER, Some(token)) => rut = Some(Rut::from_subject_token(token)?),
+                (expected, Some(token)) if expected == token => {}
+                _ => return Err(Error::InvalidFormat),
+            }
+        }
+
+        match (rut, tokens.next()) {
+            (Some(rut), None) => Ok(rut),
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Parse outcome telemetry.
+//!
+//! With the `tracing` feature every parse emits an event named
+//! `rutcl.parse.ok` (`TRACE` level) or `rutcl.parse.err` (`DEBUG` level,
+//! with the [`Error::kind`] as the `kind` field) under the `rutcl` target.
+//!
+//! With the `metrics` feature every parse increments the
+//! [`PARSE_COUNTER`] counter, labeled by `outcome` (`ok` or `err`) and, for
+//! failures, by `kind`.
+//!
+//! The parsed RUT is never recorded.
+
+use crate::{Error, Rut};
+
+/// Name of the `metrics` counter incremented on every parse
+#[cfg(feature = "metrics")]
+pub const PARSE_COUNTER: &str = "rutcl_parse_total";
+
+/// Records the outcome of parsing a RUT
+pub(crate) fn record(result: &Result<Rut, Error>) {
+    match result {
+        Ok(_) => {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(name: "rutcl.parse.ok", target: "rutcl", "parsed RUT");
+
+            #[cfg(feature = "metrics")]
+            metrics::counter!(PARSE_COUNTER, "outcome" => "ok").increment(1);
+        }
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(name: "rutcl.parse.err", target: "rutcl", kind = err.kind(), "failed to parse RUT");
+
+            #[cfg(feature = "metrics")]
+            metrics::counter!(PARSE_COUNTER, "outcome" => "err", "kind" => err.kind()).increment(1);
+        }
+    }
+}
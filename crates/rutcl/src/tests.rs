@@ -1,11 +1,11 @@
-use csv::ReaderBuilder;
+use ::csv::ReaderBuilder;
 
 #[cfg(feature = "serde")]
-use serde::de::value::{Error as ValueError, StrDeserializer, StringDeserializer};
+use ::serde::de::value::{Error as ValueError, StrDeserializer, StringDeserializer};
 #[cfg(feature = "serde")]
-use serde::de::IntoDeserializer;
+use ::serde::de::IntoDeserializer;
 #[cfg(feature = "serde")]
-use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Readable, Token};
 
 use super::*;
 
@@ -160,7 +160,7 @@ fn format_dots_rut_max() {
 fn serialize_rut_instance() {
     let rut = Rut::from_str("92.635.843-K").unwrap();
 
-    assert_tokens(&rut, &[Token::Str("92635843K")]);
+    assert_tokens(&rut.readable(), &[Token::Str("92635843K")]);
 }
 
 #[test]
@@ -184,7 +184,7 @@ fn deserialize_rut_as_string() {
 #[test]
 #[cfg(feature = "serde")]
 fn deserialize_rut_as_err_invalid_str() {
-    assert_de_tokens_error::<Rut>(
+    assert_de_tokens_error::<Readable<Rut>>(
         &[Token::Str("ThisIsNotARut")],
         "Provided string is not a number. invalid digit found in string",
     )
@@ -193,13 +193,13 @@ fn deserialize_rut_as_err_invalid_str() {
 #[test]
 #[cfg(feature = "serde")]
 fn deserialize_rut_as_err_empty() {
-    assert_de_tokens_error::<Rut>(&[Token::Str("")], "The provided string is empty")
+    assert_de_tokens_error::<Readable<Rut>>(&[Token::Str("")], "The provided string is empty")
 }
 
 #[test]
 #[cfg(feature = "serde")]
 fn deserialize_rut_as_err() {
-    assert_de_tokens_error::<Rut>(
+    assert_de_tokens_error::<Readable<Rut>>(
         &[Token::Str("1.111.111-1")],
         "Invalid verification digit: have 1, want 4",
     )
@@ -279,3 +279,3768 @@ fn generates_random_in_range() {
         );
     }
 }
+
+#[test]
+fn validates_samples() {
+    let samples = samples();
+
+    samples.iter().for_each(|Sample { rut, .. }| {
+        assert!(Rut::is_valid(rut), "{rut} should be valid");
+        assert!(Rut::validate(rut).is_ok(), "{rut} should be valid");
+    });
+}
+
+#[test]
+fn validate_reports_errors() {
+    let cases = vec![
+        ("", "The provided string is empty"),
+        ("-", "The provided string is empty"),
+        (
+            "7",
+            "Provided string is not a number. cannot parse integer from empty string",
+        ),
+        (
+            "ThisIsNotARut",
+            "Provided string is not a number. invalid digit found in string",
+        ),
+        ("1.111.111-1", "Invalid verification digit: have 1, want 4"),
+        ("999.999-9", "Out of range"),
+        (
+            "99999999999-9",
+            "Provided string is not a number. number too large to fit in target type",
+        ),
+        ("17951585-X", "Verification digit out of bounds found: X"),
+    ];
+
+    for (input, want) in cases {
+        assert!(!Rut::is_valid(input), "{input} should be invalid");
+        assert_eq!(Rut::validate(input).unwrap_err().to_string(), want);
+    }
+}
+
+#[test]
+fn band_contains_ruts() {
+    let person = Rut::from_str("17.951.585-7").unwrap();
+    let company = Rut::from_str("76.086.428-5").unwrap();
+
+    assert!(RutBand::Persons.contains(&person));
+    assert!(!RutBand::Persons.contains(&company));
+    assert!(RutBand::Companies.contains(&company));
+    assert!(!RutBand::Companies.contains(&person));
+    assert!(!RutBand::ForeignInvestors.contains(&person));
+    assert_eq!(RutBand::Persons.min(), MIN);
+    assert_eq!(RutBand::Companies.max(), MAX);
+}
+
+#[test]
+fn band_custom_bounds() {
+    let sme = RutBand::custom(76_000_000, 77_999_999).unwrap();
+
+    assert_eq!(sme.range(), 76_000_000..=77_999_999);
+    assert_eq!(sme.min().num(), 76_000_000);
+    assert!(matches!(sme, RutBand::Custom(_)));
+    assert!(matches!(
+        RutBand::custom(77_999_999, 76_000_000),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        RutBand::custom(999_999, 2_000_000),
+        Err(Error::OutOfRange)
+    ));
+}
+
+#[test]
+fn band_iterates_ruts() {
+    let band = RutBand::custom(49_999_998, 50_000_001).unwrap();
+    let ruts = band.iter().map(|rut| rut.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        ruts,
+        vec!["499999984", "499999992", "500000007", "500000015"]
+    );
+    assert_eq!(band.iter().next_back(), Some(band.max()));
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn band_generates_random() {
+    for band in [
+        RutBand::Persons,
+        RutBand::Companies,
+        RutBand::ForeignInvestors,
+    ] {
+        for _ in 0..100 {
+            assert!(band.contains(&band.random().unwrap()));
+        }
+    }
+}
+
+#[test]
+fn display_honors_formatter_flags() {
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+
+    assert_eq!(format!("{rut:>15}"), "      179515857");
+    assert_eq!(format!("{rut:<12}|"), "179515857   |");
+    assert_eq!(format!("{rut:0>12}"), "000179515857");
+    assert_eq!(format!("{rut:*^13}"), "**179515857**");
+    assert_eq!(format!("{:>3}", VerificationDigit::K), "  K");
+}
+
+#[test]
+fn debug_includes_dash_format() {
+    let rut = Rut::from_str("92.635.843-K").unwrap();
+
+    assert_eq!(format!("{rut:?}"), "Rut(92635843, K) 92635843-K");
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct OptionalRut {
+    #[serde(default, with = "crate::serde::option_empty_as_none")]
+    rut: Option<Rut>,
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn deserialize_option_empty_as_none() {
+    let cases = vec![
+        (r#"{ "rut": "" }"#, None),
+        (r#"{ "rut": "  " }"#, None),
+        (r#"{ "rut": null }"#, None),
+        (r#"{}"#, None),
+        (
+            r#"{ "rut": "17.951.585-7" }"#,
+            Some(Rut(17951585, VerificationDigit::Seven)),
+        ),
+    ];
+
+    for (json, rut) in cases {
+        let have: OptionalRut = serde_json::from_str(json).unwrap();
+        assert_eq!(have, OptionalRut { rut }, "Failed to deserialize {json}");
+    }
+
+    assert!(serde_json::from_str::<OptionalRut>(r#"{ "rut": "17.951.585-K" }"#).is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize_option_empty_as_none() {
+    let some = OptionalRut {
+        rut: Some(Rut(92635843, VerificationDigit::K)),
+    };
+    let none = OptionalRut { rut: None };
+
+    assert_eq!(
+        serde_json::to_string(&some).unwrap(),
+        r#"{"rut":"92635843K"}"#
+    );
+    assert_eq!(serde_json::to_string(&none).unwrap(), r#"{"rut":null}"#);
+}
+
+#[test]
+fn person_and_company_newtypes() {
+    let person = Rut::from_str("17.951.585-7").unwrap();
+    let company = Rut::from_str("76.086.428-5").unwrap();
+
+    assert_eq!(*PersonRut::try_from(person).unwrap(), person);
+    assert_eq!(*CompanyRut::try_from(company).unwrap(), company);
+    assert!(matches!(
+        PersonRut::try_from(company),
+        Err(Error::OutOfBand(RutBand::Persons))
+    ));
+    assert!(matches!(
+        CompanyRut::from_str("17.951.585-7"),
+        Err(Error::OutOfBand(RutBand::Companies))
+    ));
+    assert_eq!(
+        CompanyRut::from_str("76.086.428-5").unwrap().to_string(),
+        "760864285"
+    );
+}
+
+#[test]
+fn from_formatted_rejects_other_formats() {
+    let cases = vec![
+        ("179515857", Format::Dash),
+        ("179515857", Format::Dots),
+        ("17951585-7", Format::Sans),
+        ("17951585-7", Format::Dots),
+        ("17.951.585-7", Format::Sans),
+        ("17.951.585-7", Format::Dash),
+        ("1-7.9.5-15857", Format::Dots),
+        ("1795.1585-7", Format::Dots),
+        ("17.951.5857", Format::Dots),
+        ("017.951.585-7", Format::Dots),
+        ("017951585-7", Format::Dash),
+        ("17951585-", Format::Dash),
+        ("", Format::Sans),
+    ];
+
+    for (input, fmt) in cases {
+        assert!(
+            matches!(Rut::from_formatted(input, fmt), Err(Error::InvalidFormat)),
+            "{input} should not be accepted as {fmt:?}"
+        );
+    }
+}
+
+#[test]
+fn from_formatted_round_trips_boundaries() {
+    let boundaries = vec![
+        MIN_NUM,
+        MIN_NUM + 1,
+        9_999_999,
+        10_000_000,
+        MAX_NUM - 1,
+        MAX_NUM,
+    ];
+
+    for num in boundaries {
+        let rut = Rut::try_from(num).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            assert_eq!(Rut::from_formatted(&rut.format(fmt), fmt).unwrap(), rut);
+        }
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn from_formatted_round_trips(num in MIN_NUM..=MAX_NUM, fmt in 0..3_u8) {
+        let fmt = match fmt {
+            0 => Format::Sans,
+            1 => Format::Dash,
+            _ => Format::Dots,
+        };
+        let rut = Rut::try_from(num).unwrap();
+
+        proptest::prop_assert_eq!(Rut::from_formatted(&rut.format(fmt), fmt).unwrap(), rut);
+    }
+
+    #[test]
+    fn from_formatted_accepts_lowercase_k(num in MIN_NUM..=MAX_NUM) {
+        let rut = Rut::try_from(num).unwrap();
+        let dash = rut.format(Format::Dash).to_lowercase();
+
+        proptest::prop_assert_eq!(Rut::from_formatted(&dash, Format::Dash).unwrap(), rut);
+    }
+}
+
+#[test]
+fn sample_fixture_is_deterministic() {
+    let ruts = Rut::sample_fixture(500, 7).collect::<Vec<Rut>>();
+    let prefix = Rut::sample_fixture(50, 7).collect::<Vec<Rut>>();
+
+    assert_eq!(ruts.len(), 500);
+    assert_eq!(ruts, Rut::sample_fixture(500, 7).collect::<Vec<Rut>>());
+    assert_eq!(&ruts[..50], prefix.as_slice());
+    assert_ne!(ruts, Rut::sample_fixture(500, 8).collect::<Vec<Rut>>());
+
+    for (idx, rut) in ruts.iter().enumerate() {
+        assert!(RANGE.contains(&rut.num()));
+        assert!(Rut::is_valid(&rut.to_string()));
+        assert!(!ruts[..idx].contains(rut), "{rut} is repeated");
+    }
+}
+
+#[test]
+fn sample_fixture_is_capped_at_keyspace() {
+    let fixture = Rut::sample_fixture(usize::MAX, 7);
+
+    assert_eq!(fixture.len(), (MAX_NUM - MIN_NUM + 1) as usize);
+    assert_eq!(fixture.take(3).count(), 3);
+}
+
+#[test]
+fn sample_fixture_snapshot() {
+    let ruts = Rut::sample_fixture(3, fixture::SAMPLES_SEED)
+        .map(|rut| rut.format(Format::Dash))
+        .collect::<Vec<String>>();
+
+    assert_eq!(ruts, vec!["63607535-3", "62355700-6", "11545679-2"]);
+}
+
+#[test]
+#[cfg(feature = "fixtures")]
+fn sample_fixture_static_tables() {
+    let ruts = Rut::sample_fixture(1000, fixture::SAMPLES_SEED).collect::<Vec<Rut>>();
+
+    assert_eq!(&ruts[..10], fixture::SAMPLES_10.as_slice());
+    assert_eq!(&ruts[..100], fixture::SAMPLES_100.as_slice());
+    assert_eq!(ruts, fixture::SAMPLES_1000.as_slice());
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, PartialEq, RutNewtype)]
+#[cfg_attr(feature = "serde", rut(serde))]
+struct EmployerRut(Rut);
+
+#[test]
+#[cfg(feature = "derive")]
+fn derives_rut_newtype() {
+    let employer = EmployerRut::from_str("76.086.428-5").unwrap();
+    let rut = Rut::from(employer);
+
+    assert_eq!(EmployerRut::from(rut).to_string(), "760864285");
+    assert_eq!(EmployerRut::from(rut).as_ref(), &rut);
+    assert!(EmployerRut::from_str("76.086.428-K").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "serde"))]
+fn derives_rut_newtype_serde() {
+    let employer = EmployerRut::from_str("92.635.843-K").unwrap();
+
+    assert_tokens(&employer.readable(), &[Token::Str("92635843K")]);
+}
+
+#[cfg(all(feature = "derive", feature = "serde"))]
+#[derive(Debug, PartialEq, RutNewtype)]
+#[rut(serde)]
+struct BranchRut<const BRANCH: u8>(Rut);
+
+#[test]
+#[cfg(all(feature = "derive", feature = "serde"))]
+fn derives_generic_rut_newtype_serde() {
+    let branch = BranchRut::<3>::from_str("92.635.843-K").unwrap();
+
+    assert_tokens(&branch.readable(), &[Token::Str("92635843K")]);
+}
+
+#[cfg(all(feature = "derive", feature = "sqlx"))]
+#[derive(Debug, PartialEq, RutNewtype)]
+#[rut(sqlx)]
+struct TaxpayerRut(Rut);
+
+/// No driver is enabled, so this only checks that the derived impls
+/// delegate to [`Rut`]'s for any database storing `i32` values
+#[cfg(all(feature = "derive", feature = "sqlx"))]
+#[allow(dead_code)]
+fn derives_rut_newtype_sqlx<DB>()
+where
+    DB: ::sqlx::Database,
+    i32: ::sqlx::Type<DB> + for<'q> ::sqlx::Encode<'q, DB> + for<'r> ::sqlx::Decode<'r, DB>,
+{
+    fn assert_column<DB, T>()
+    where
+        DB: ::sqlx::Database,
+        T: ::sqlx::Type<DB> + for<'q> ::sqlx::Encode<'q, DB> + for<'r> ::sqlx::Decode<'r, DB>,
+    {
+    }
+
+    assert_column::<DB, Rut>();
+    assert_column::<DB, TaxpayerRut>();
+}
+
+#[test]
+fn parses_copy_pasted_separators() {
+    let cases = vec![
+        "17.951.585\u{2013}7",
+        "17.951.585\u{2014}7",
+        "17\u{00B7}951\u{00B7}585-7",
+        "17.951.585\u{00A0}-\u{00A0}7",
+        "17.951.585\u{00AD}-7",
+        " 17.951.585-7\n",
+        "\u{00A0}17.951.585-7",
+    ];
+
+    for input in cases {
+        let rut = Rut::from_str(input).unwrap();
+
+        assert_eq!(rut, Rut(17951585, VerificationDigit::Seven), "{input:?}");
+        assert_eq!(Rut::sans(input), "179515857");
+        assert!(Rut::parse_sanitized(input, SanitizeOptions::STRICT).is_err());
+    }
+}
+
+#[test]
+fn strips_interior_whitespace_only_when_lenient() {
+    for input in ["17 951 585 7", "17.951.585 - 7", "1795\t1585-7"] {
+        assert!(Rut::from_str(input).is_err(), "{input:?}");
+        assert_eq!(
+            Rut::parse_sanitized(input, SanitizeOptions::LENIENT).unwrap(),
+            Rut(17951585, VerificationDigit::Seven)
+        );
+    }
+
+    assert_eq!(Rut::sans(" 17 951 585-7 "), "17 951 5857");
+}
+
+#[test]
+fn sanitize_options_are_selective() {
+    let options = SanitizeOptions {
+        unicode_dashes: true,
+        unicode_dots: false,
+        nbsp: false,
+        trim: false,
+        whitespace: false,
+    };
+
+    assert!(Rut::parse_sanitized("17.951.585\u{2013}7", options).is_ok());
+    assert!(Rut::parse_sanitized("17\u{00B7}951\u{00B7}585-7", options).is_err());
+    assert!(Rut::parse_sanitized("17.951.585 -7", options).is_err());
+    assert_eq!(
+        Rut::sans_with("17.951.585 \u{2013}7", options),
+        "17951585 7"
+    );
+}
+
+#[test]
+fn verification_digit_accepts_lowercase_k() {
+    assert_eq!(
+        VerificationDigit::from_str("k").unwrap(),
+        VerificationDigit::K
+    );
+    assert_eq!(
+        VerificationDigit::try_from('k').unwrap(),
+        VerificationDigit::K
+    );
+}
+
+#[test]
+fn abs_diff_between_ruts() {
+    assert_eq!(MIN.abs_diff(&MAX), MAX_NUM - MIN_NUM);
+    assert_eq!(MAX.abs_diff(&MIN), MAX_NUM - MIN_NUM);
+    assert_eq!(MIN.abs_diff(&MIN), 0);
+}
+
+#[test]
+fn similarity_scores_typos() {
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+    let exact = similarity("179515857", &rut);
+    let typo = similarity("17.951.586-7", &rut);
+    let transposition = similarity("17.951.558-7", &rut);
+    let unknown_vd = similarity("17951558-?", &rut);
+    let unrelated = similarity("98.127.523-3", &rut);
+
+    assert_eq!(exact, 1.0);
+    assert!((typo - 8.0 / 9.0).abs() < f32::EPSILON);
+    assert!((transposition - 8.0 / 9.0).abs() < f32::EPSILON);
+    assert!((unknown_vd - 8.0 / 9.0).abs() < f32::EPSILON);
+    assert!(unrelated < 0.5, "{unrelated}");
+    assert_eq!(similarity("", &rut), 0.0);
+}
+
+#[test]
+fn similarity_is_case_insensitive() {
+    let rut = Rut::from_str("92.635.843-K").unwrap();
+
+    assert_eq!(similarity("92.635.843-k", &rut), 1.0);
+}
+
+#[test]
+#[cfg(feature = "wire")]
+fn wire_v1_string_encoding() {
+    use crate::wire::WireV1;
+
+    let rut = Rut::from_str("92.635.843-k").unwrap();
+
+    assert_eq!(WireV1::encode_str(&rut), "92635843-K");
+    assert_eq!(WireV1::encode_str(&MIN), "1000000-9");
+    assert_eq!(WireV1::decode_str("92635843-K").unwrap(), rut);
+
+    for input in [
+        "92635843-k",
+        "92.635.843-K",
+        "92635843K",
+        "092635843-K",
+        "-K",
+    ] {
+        assert!(
+            matches!(WireV1::decode_str(input), Err(Error::InvalidFormat)),
+            "{input} should not be accepted"
+        );
+    }
+
+    assert!(matches!(
+        WireV1::decode_str("92635843-1"),
+        Err(Error::InvalidVerificationDigit { .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "wire")]
+fn wire_v1_binary_encoding() {
+    use crate::wire::WireV1;
+
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+    let bytes = WireV1::encode_bytes(&rut);
+
+    assert_eq!(bytes, [1, 0x01, 0x11, 0xEB, 0x61, b'7']);
+    assert_eq!(WireV1::decode_bytes(&bytes).unwrap(), rut);
+    assert!(matches!(
+        WireV1::decode_bytes(&[2, 0x01, 0x11, 0xEB, 0x61, b'7']),
+        Err(Error::UnsupportedWireVersion(2))
+    ));
+    assert!(matches!(
+        WireV1::decode_bytes(&[2, 0x01, 0x11, 0xEB]),
+        Err(Error::UnsupportedWireVersion(2))
+    ));
+    assert!(matches!(
+        WireV1::decode_bytes(&bytes[..5]),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        WireV1::decode_bytes(&[]),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        WireV1::decode_bytes(&[1, 0, 0, 0, 1, b'9']),
+        Err(Error::OutOfRange)
+    ));
+
+    let mut lowercase = WireV1::encode_bytes(&Rut::from_str("92.635.843-K").unwrap());
+
+    assert_eq!(lowercase[5], b'K');
+    lowercase[5] = b'k';
+    assert!(matches!(
+        WireV1::decode_bytes(&lowercase),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+#[cfg(feature = "redis")]
+fn redis_round_trip() {
+    use ::redis::{FromRedisValue, ToRedisArgs, Value};
+
+    let rut = Rut::from_str("92.635.843-K").unwrap();
+    let args = rut.to_redis_args();
+
+    assert_eq!(args, vec![b"92635843K".to_vec()]);
+
+    let value = Value::BulkString(args[0].clone());
+
+    assert_eq!(Rut::from_redis_value(&value).unwrap(), rut);
+    assert_eq!(
+        Rut::from_redis_value(&Value::SimpleString("92.635.843-K".into())).unwrap(),
+        rut
+    );
+    assert!(Rut::from_redis_value(&Value::BulkString(b"92635843-1".to_vec())).is_err());
+    assert!(Rut::from_redis_value(&Value::Nil).is_err());
+}
+
+#[test]
+#[cfg(feature = "bson")]
+fn bson_conversions() {
+    use ::bson::Bson;
+
+    let rut = Rut::from_str("92.635.843-K").unwrap();
+
+    assert_eq!(Bson::from(rut), Bson::String("92635843K".into()));
+    assert_eq!(Rut::try_from(Bson::from(rut)).unwrap(), rut);
+    assert_eq!(Rut::try_from(Bson::Int32(92_635_843)).unwrap(), rut);
+    assert_eq!(Rut::try_from(Bson::Int64(92_635_843)).unwrap(), rut);
+    assert!(matches!(
+        Rut::try_from(Bson::Int32(-1)),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        Rut::try_from(Bson::Boolean(true)),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+#[cfg(feature = "bson")]
+fn bson_documents_round_trip() {
+    use ::bson::{doc, from_document, to_document};
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Customer {
+        rut: Rut,
+    }
+
+    let customer = Customer {
+        rut: Rut::from_str("17.951.585-7").unwrap(),
+    };
+    let document = to_document(&customer).unwrap();
+
+    assert_eq!(document, doc! { "rut": "179515857" });
+    assert_eq!(from_document::<Customer>(document).unwrap(), customer);
+    assert_eq!(
+        from_document::<Customer>(doc! { "rut": 17_951_585_i32 }).unwrap(),
+        customer
+    );
+    assert!(from_document::<Customer>(doc! { "rut": 17_951_584_i64 * 100 }).is_err());
+}
+
+#[test]
+#[cfg(feature = "clap")]
+fn clap_value_parser() {
+    use ::clap::error::ErrorKind;
+    use ::clap::{value_parser, Arg, Command};
+
+    let cmd =
+        Command::new("rutcl").arg(Arg::new("rut").long("rut").value_parser(value_parser!(Rut)));
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["rutcl", "--rut", "92.635.843-k"])
+        .unwrap();
+
+    assert_eq!(
+        matches.get_one::<Rut>("rut"),
+        Some(&Rut(92635843, VerificationDigit::K))
+    );
+
+    let err = cmd
+        .try_get_matches_from(["rutcl", "--rut", "1.111.111-1"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains(
+        "invalid value '1.111.111-1' for '--rut <rut>': Invalid verification digit: have 1, want 4"
+    ));
+}
+
+#[test]
+fn from_env_reports_variable() {
+    use std::error::Error as _;
+
+    std::env::set_var("RUTCL_TEST_FROM_ENV_VALID", "17.951.585-7");
+    std::env::set_var("RUTCL_TEST_FROM_ENV_INVALID", "17.951.585-K");
+
+    assert_eq!(
+        Rut::from_env("RUTCL_TEST_FROM_ENV_VALID").unwrap(),
+        Rut(17951585, VerificationDigit::Seven)
+    );
+
+    let err = Rut::from_env("RUTCL_TEST_FROM_ENV_INVALID").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Invalid RUT \"17.951.585-K\" in environment variable RUTCL_TEST_FROM_ENV_INVALID"
+    );
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Invalid verification digit: have K, want 7"
+    );
+
+    let err = Rut::from_env("RUTCL_TEST_FROM_ENV_MISSING").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Environment variable RUTCL_TEST_FROM_ENV_MISSING is not available"
+    );
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn try_from_string_keeps_value() {
+    use std::error::Error as _;
+
+    assert_eq!(
+        Rut::try_from(String::from("17.951.585-7")).unwrap(),
+        Rut(17951585, VerificationDigit::Seven)
+    );
+
+    let err = Rut::try_from(String::from("1.111.111-1")).unwrap_err();
+
+    assert_eq!(err.to_string(), "Invalid RUT \"1.111.111-1\"");
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Invalid verification digit: have 1, want 4"
+    );
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn miette_labels_offending_input() {
+    use ::miette::Diagnostic;
+
+    let cases = vec![
+        (
+            "17.951.585-K",
+            (11, 1),
+            "expected 7",
+            "rutcl::invalid_verification_digit",
+        ),
+        (
+            "17.951.585-X",
+            (11, 1),
+            "not a verification digit",
+            "rutcl::verification_digit_out_of_bounds",
+        ),
+        ("17.9a1.585-7", (4, 1), "not a number", "rutcl::nan"),
+        ("999.999-9", (0, 7), "out of range", "rutcl::out_of_range"),
+        ("", (0, 0), "empty", "rutcl::empty_string"),
+    ];
+
+    for (input, (offset, len), label, code) in cases {
+        let err = Rut::from_str(input).unwrap_err().with_input(input);
+        let have = err.labels().unwrap().next().unwrap();
+
+        assert_eq!((have.offset(), have.len()), (offset, len), "{input}");
+        assert_eq!(have.label(), Some(label), "{input}");
+        assert_eq!(err.code().unwrap().to_string(), code, "{input}");
+        assert!(err.source_code().is_some());
+    }
+}
+
+#[test]
+fn detects_format() {
+    let cases = vec![
+        ("179515857", Some(Format::Sans)),
+        ("92635843k", Some(Format::Sans)),
+        ("17951585-7", Some(Format::Dash)),
+        ("92635843-K", Some(Format::Dash)),
+        ("17.951.585-7", Some(Format::Dots)),
+        ("1.000.000-9", Some(Format::Dots)),
+        ("17.951585-7", None),
+        ("17951.585-7", None),
+        ("17.951.5857", None),
+        ("17.951.585-X", None),
+        ("17-951-585-7", None),
+        ("ThisIsNotARut", None),
+        ("", None),
+    ];
+
+    for (input, want) in cases {
+        assert_eq!(Format::detect(input), want, "{input}");
+    }
+}
+
+#[test]
+#[cfg(feature = "subtle")]
+fn compares_ruts_in_constant_time() {
+    let a = Rut::from_str("17.951.585-7").unwrap();
+    let b = Rut::from_str("92.635.843-K").unwrap();
+
+    assert!(a.ct_eq(&a));
+    assert!(!a.ct_eq(&b));
+}
+
+#[test]
+#[cfg(feature = "blake3")]
+fn hash_pseudonym_is_keyed() {
+    let a = Rut::from_str("17.951.585-7").unwrap();
+    let b = Rut::from_str("92.635.843-K").unwrap();
+
+    assert_eq!(a.hash_pseudonym(b"salt"), a.hash_pseudonym(b"salt"));
+    assert_ne!(a.hash_pseudonym(b"salt"), b.hash_pseudonym(b"salt"));
+    assert_ne!(a.hash_pseudonym(b"salt"), a.hash_pseudonym(b"pepper"));
+    assert!(a
+        .hash_pseudonym(b"salt")
+        .to_string()
+        .chars()
+        .all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+#[cfg(feature = "anonymize")]
+fn vault_permutes_band() {
+    use crate::anonymize::Vault;
+
+    let band = RutBand::custom(10_000_000, 10_009_999).unwrap();
+    let vault = Vault::new(b"secret", band);
+    let mut tokens = band
+        .iter()
+        .map(|rut| vault.tokenize(&rut).unwrap())
+        .collect::<Vec<Rut>>();
+
+    for (rut, token) in band.iter().zip(tokens.iter()) {
+        assert!(band.contains(token));
+        assert!(Rut::is_valid(&token.to_string()));
+        assert_eq!(vault.detokenize(token).unwrap(), rut);
+    }
+
+    tokens.sort();
+    tokens.dedup();
+
+    assert_eq!(tokens.len(), 10_000);
+}
+
+#[test]
+#[cfg(feature = "anonymize")]
+fn vault_is_keyed() {
+    use crate::anonymize::Vault;
+
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+    let a = Vault::new(b"secret", RutBand::Persons);
+    let b = Vault::new(b"another secret", RutBand::Persons);
+
+    assert_eq!(a.tokenize(&rut).unwrap(), a.tokenize(&rut).unwrap());
+    assert_ne!(a.tokenize(&rut).unwrap(), b.tokenize(&rut).unwrap());
+    assert!(matches!(
+        a.tokenize(&Rut::from_str("76.086.428-5").unwrap()),
+        Err(Error::OutOfBand(RutBand::Persons))
+    ));
+}
+
+#[test]
+#[cfg(feature = "anonymize")]
+fn vault_rejects_tokens_out_of_band() {
+    use crate::anonymize::Vault;
+
+    let narrow = Vault::new(b"secret", RutBand::custom(10_000_000, 10_000_099).unwrap());
+
+    assert!(matches!(
+        narrow.detokenize(&Rut::from_str("17.951.585-7").unwrap()),
+        Err(Error::OutOfBand(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "loadgen")]
+fn loadgen_uniform_is_unique() {
+    use crate::loadgen::{Pattern, RutStream};
+
+    let band = RutBand::custom(10_000_000, 10_000_099).unwrap();
+    let mut ruts = RutStream::new(Pattern::Uniform)
+        .unwrap()
+        .with_band(band)
+        .collect::<Vec<Rut>>();
+
+    assert_eq!(ruts.len(), 100);
+    assert!(ruts.iter().all(|rut| band.contains(rut)));
+
+    ruts.sort();
+    ruts.dedup();
+
+    assert_eq!(ruts.len(), 100);
+}
+
+#[test]
+#[cfg(feature = "loadgen")]
+fn loadgen_zipf_favors_hot_ruts() {
+    use std::collections::HashMap;
+
+    use crate::loadgen::{Pattern, RutStream};
+
+    let stream = RutStream::new(Pattern::Zipf {
+        subset: 10,
+        exponent: 1.5,
+    })
+    .unwrap()
+    .with_seed(7);
+    let mut counts: HashMap<Rut, usize> = HashMap::new();
+
+    for rut in stream.take(10_000) {
+        *counts.entry(rut).or_default() += 1;
+    }
+
+    let mut counts = counts.into_values().collect::<Vec<usize>>();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    assert!(counts.len() <= 10);
+    assert!(counts[0] > 3 * counts[counts.len() - 1]);
+}
+
+#[test]
+#[cfg(feature = "loadgen")]
+fn loadgen_rejects_invalid_zipf() {
+    use crate::loadgen::{Pattern, RutStream};
+
+    for (subset, exponent) in [(0, 1.0), (10, -1.0), (10, f64::NAN), (10, f64::INFINITY)] {
+        assert!(matches!(
+            RutStream::new(Pattern::Zipf { subset, exponent }),
+            Err(Error::OutOfRange)
+        ));
+    }
+
+    assert!(RutStream::new(Pattern::Zipf {
+        subset: 1,
+        exponent: 0.0
+    })
+    .is_ok());
+}
+
+#[test]
+#[cfg(feature = "loadgen")]
+fn loadgen_seed_is_deterministic() {
+    use crate::loadgen::{Pattern, RutStream};
+
+    let a = RutStream::new(Pattern::Uniform)
+        .unwrap()
+        .with_seed(1)
+        .take(10);
+    let b = RutStream::new(Pattern::Uniform)
+        .unwrap()
+        .with_seed(1)
+        .take(10);
+
+    assert!(a.eq(b));
+}
+
+#[test]
+#[cfg(feature = "loadgen")]
+fn loadgen_honors_rate() {
+    use std::num::NonZeroU32;
+    use std::time::{Duration, Instant};
+
+    use crate::loadgen::{Pattern, RutStream};
+
+    let start = Instant::now();
+    let ruts = RutStream::new(Pattern::Uniform)
+        .unwrap()
+        .with_rate(NonZeroU32::new(100).unwrap())
+        .take(11)
+        .count();
+
+    assert_eq!(ruts, 11);
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn loadgen_stream_honors_rate() {
+    use std::num::NonZeroU32;
+    use std::time::{Duration, Instant};
+
+    use futures::StreamExt;
+
+    use crate::loadgen::{Pattern, RutStream};
+
+    let start = Instant::now();
+    let stream = RutStream::new(Pattern::Uniform)
+        .unwrap()
+        .with_rate(NonZeroU32::new(100).unwrap());
+    let ruts = futures::executor::block_on(StreamExt::take(stream, 11).collect::<Vec<Rut>>());
+
+    assert_eq!(ruts.len(), 11);
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[test]
+fn cedula_barcode_payload_round_trip() {
+    let cases = vec![
+        ("7.066.524-7", b"70665247 "),
+        ("17.951.585-7", b"179515857"),
+        ("92.635.843-K", b"92635843K"),
+    ];
+
+    for (rut, payload) in cases {
+        let rut = Rut::from_str(rut).unwrap();
+
+        assert_eq!(&rut.to_barcode_payload(), payload);
+        assert_eq!(Rut::from_barcode_payload(payload).unwrap(), rut);
+    }
+}
+
+#[test]
+fn reads_cedula_barcode_payloads() {
+    let rut = Rut(17951585, VerificationDigit::Seven);
+    let qr =
+        b"https://portal.sidiv.registrocivil.cl/docstatus?type=CEDULA&RUN=17951585-7&serial=A1";
+
+    assert_eq!(Rut::from_barcode_payload(b"179515857SOTO").unwrap(), rut);
+    assert_eq!(Rut::from_barcode_payload(qr).unwrap(), rut);
+    assert!(matches!(
+        Rut::from_barcode_payload(b"1795"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        Rut::from_barcode_payload(b"17.951.58"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        Rut::from_barcode_payload(b"https://portal.sidiv.registrocivil.cl/docstatus?type=CEDULA"),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+fn reads_ted_ruts() {
+    use crate::barcode::{ted_ruts, TedRuts};
+
+    let ted = b"<TED version=\"1.0\"><DD><RE>92635843-K</RE><TD>33</TD><F>12</F><RR>17951585-7</RR></DD></TED>";
+
+    assert_eq!(
+        ted_ruts(ted).unwrap(),
+        TedRuts {
+            emisor: Rut(92635843, VerificationDigit::K),
+            receptor: Rut(17951585, VerificationDigit::Seven),
+        }
+    );
+    assert!(matches!(
+        ted_ruts(b"<TED><DD><RE>92635843-K</RE></DD></TED>"),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+fn formats_sii_rut_type() {
+    assert_eq!(
+        sii::format(&Rut(92635843, VerificationDigit::K)),
+        "92635843-K"
+    );
+    assert_eq!(
+        sii::format(&Rut(1111111, VerificationDigit::Four)),
+        "1111111-4"
+    );
+}
+
+#[test]
+fn parses_sii_rut_type() {
+    use crate::sii::Rule;
+
+    let cases = vec![
+        ("1-", Some(Rule::MinLength)),
+        ("92.635.843-K", Some(Rule::MaxLength)),
+        ("92635843-k", Some(Rule::Pattern)),
+        ("92635843K", Some(Rule::Pattern)),
+        ("-92635843K", Some(Rule::Pattern)),
+        ("1795158-57", Some(Rule::Pattern)),
+        ("17951585-8", None),
+    ];
+
+    for (value, want) in cases {
+        match (sii::parse(value), want) {
+            (Err(Error::SiiSchema { rule, value: have }), Some(want)) => {
+                assert_eq!(rule, want, "{value}");
+                assert_eq!(have, value);
+            }
+            (Err(Error::InvalidVerificationDigit { .. }), None) => {}
+            (other, _) => panic!("unexpected result for {value}: {other:?}"),
+        }
+    }
+
+    assert_eq!(
+        sii::parse("92635843-K").unwrap(),
+        Rut(92635843, VerificationDigit::K)
+    );
+}
+
+#[test]
+fn finds_sii_ruts_in_dte_xml() {
+    let dte = r#"<EnvioDTE><SetDTE ID="SetDoc"><Caratula version="1.0">
+        <RutEmisor>76086428-5</RutEmisor><RutEnvia>17951585-7</RutEnvia>
+        </Caratula></SetDTE><Referencia RUTOtr="92635843-K"/>
+        <RUTRecep>92.635.843-K</RUTRecep></EnvioDTE>"#;
+
+    assert_eq!(
+        sii::find(dte, sii::RUT_ENVIA).unwrap(),
+        Rut(17951585, VerificationDigit::Seven)
+    );
+    assert_eq!(
+        sii::find(dte, "RUTOtr").unwrap(),
+        Rut(92635843, VerificationDigit::K)
+    );
+    assert!(matches!(
+        sii::find(dte, sii::RUT_RECEP),
+        Err(Error::SiiSchema {
+            rule: sii::Rule::MaxLength,
+            ..
+        })
+    ));
+    assert!(matches!(
+        sii::find(dte, sii::RUT_EMISOR),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+fn shards_are_contiguous_and_cover_the_keyspace() {
+    for n_shards in [1, 2, 3, 7, 16, 1000] {
+        let partitioner = RutPartitioner::new(n_shards);
+        let ranges = partitioner.iter().collect::<Vec<_>>();
+
+        assert_eq!(ranges.len(), n_shards as usize);
+        assert_eq!(*ranges[0].start(), MIN_NUM);
+        assert_eq!(*ranges.last().unwrap().end(), MAX_NUM);
+
+        for (shard, pair) in ranges.windows(2).enumerate() {
+            assert_eq!(*pair[0].end() + 1, *pair[1].start());
+
+            let last = Rut(*pair[0].end(), VerificationDigit::compute(*pair[0].end()));
+            let first = Rut(
+                *pair[1].start(),
+                VerificationDigit::compute(*pair[1].start()),
+            );
+
+            assert_eq!(last.shard(n_shards), shard as u32);
+            assert_eq!(first.shard(n_shards), shard as u32 + 1);
+        }
+    }
+
+    assert_eq!(RutPartitioner::new(4).range(4), None);
+}
+
+#[test]
+fn shard_is_stable() {
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+
+    assert_eq!(rut.shard(1), 0);
+    assert_eq!(rut.shard(10), 1);
+    assert_eq!(rut.shard(64), 10);
+    assert_eq!(MAX.shard(64), 63);
+}
+
+#[test]
+#[should_panic]
+fn shard_panics_on_zero_shards() {
+    MIN.shard(0);
+}
+
+#[test]
+fn rut_range_iterates_inclusive_and_exclusive_ranges() {
+    let end = Rut(1000002, VerificationDigit::Five);
+    let want = vec![
+        Rut(1000000, VerificationDigit::Nine),
+        Rut(1000001, VerificationDigit::Seven),
+        end,
+    ];
+
+    assert_eq!((MIN..=end).ruts().collect::<Vec<_>>(), want);
+    assert_eq!((MIN..end).ruts().collect::<Vec<_>>(), want[..2]);
+    assert_eq!((MIN..=end).ruts().rev().collect::<Vec<_>>(), {
+        let mut want = want.clone();
+        want.reverse();
+        want
+    });
+    assert_eq!((end..MIN).ruts().count(), 0);
+    assert_eq!((end..=MIN).ruts().next(), None);
+    assert_eq!((MAX..=MAX).ruts().collect::<Vec<_>>(), vec![MAX]);
+}
+
+#[test]
+fn rut_range_fast_paths() {
+    let mut range = (MIN..=MAX).ruts();
+
+    assert_eq!(range.size_hint(), (99_000_000, Some(99_000_000)));
+    assert_eq!(range.clone().count(), 99_000_000);
+    assert_eq!(range.clone().last(), Some(MAX));
+    assert_eq!(range.nth(16_951_585).unwrap().to_string(), "179515857");
+    assert_eq!(range.len(), 82_048_414);
+    assert_eq!(range.nth_back(0), Some(MAX));
+    assert_eq!(range.nth(82_048_413), None);
+    assert!(range.is_empty());
+    assert_eq!(range.next_back(), None);
+}
+
+proptest::proptest! {
+    #[test]
+    fn rut_range_nth_matches_step_by_step(start in MIN_NUM..=MIN_NUM + 1000, len in 0u32..1000, n in 0usize..1100) {
+        let start = Rut(start, VerificationDigit::compute(start));
+        let end = Rut(start.num() + len, VerificationDigit::compute(start.num() + len));
+        let range = (start..=end).ruts();
+
+        let nums = (start.num()..=end.num()).collect::<Vec<_>>();
+        let want = |num: Option<&u32>| num.map(|num| Rut(*num, VerificationDigit::compute(*num)));
+
+        proptest::prop_assert_eq!(range.clone().nth(n), want(nums.get(n)));
+        proptest::prop_assert_eq!(range.clone().nth_back(n), want(nums.iter().rev().nth(n)));
+        proptest::prop_assert_eq!(range.len(), len as usize + 1);
+    }
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn random_many_distinct_in_range_draws_distinct_ruts() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let range = 20_000_000..=20_000_049;
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for k in [0, 1, 25, 50] {
+        let ruts = Rut::random_many_distinct_in_range_with(&mut rng, range.clone(), k).unwrap();
+
+        assert_eq!(ruts.len(), k);
+        assert!(ruts.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ruts
+            .iter()
+            .all(|rut| range.contains(&rut.num())
+                && rut.vd() == VerificationDigit::compute(rut.num())));
+    }
+
+    let seeded = |seed| {
+        Rut::random_many_distinct_in_range_with(&mut StdRng::seed_from_u64(seed), range.clone(), 5)
+            .unwrap()
+    };
+
+    assert_eq!(seeded(1), seeded(1));
+    assert!(matches!(
+        Rut::random_many_distinct_in_range(range.clone(), 51),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        Rut::random_many_distinct_in_range(0..=10, 1),
+        Err(Error::OutOfRange)
+    ));
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn random_many_distinct_in_range_is_uniform() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut hits = [0usize; 10];
+
+    for _ in 0..10_000 {
+        for rut in
+            Rut::random_many_distinct_in_range_with(&mut rng, 30_000_000..=30_000_009, 3).unwrap()
+        {
+            hits[(rut.num() - 30_000_000) as usize] += 1;
+        }
+    }
+
+    // Each RUT is expected 3.000 times
+    assert!(
+        hits.iter().all(|hit| (2_800..=3_200).contains(hit)),
+        "{hits:?}"
+    );
+}
+
+#[test]
+fn error_kind_is_stable() {
+    assert_eq!(
+        Rut::from_str("17.951.585-8").unwrap_err().kind(),
+        "invalid_verification_digit"
+    );
+    assert_eq!(Rut::from_str("").unwrap_err().kind(), "empty_string");
+    assert_eq!(Rut::from_str("1-9").unwrap_err().kind(), "out_of_range");
+    assert_eq!(Error::InvalidFormat.kind(), "invalid_format");
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn emits_parse_events() {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    type Events = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    struct Recorder(Events);
+
+    struct Kind(Option<String>);
+
+    impl Visit for Kind {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "kind" {
+                self.0 = Some(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut kind = Kind(None);
+
+            event.record(&mut kind);
+            self.0
+                .lock()
+                .unwrap()
+                .push((event.metadata().name().to_string(), kind.0));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Recorder(Arc::clone(&events));
+
+    tracing::subscriber::with_default(recorder, || {
+        let _ = Rut::from_str("17.951.585-7");
+        let _ = Rut::validate("17.951.585-8");
+        let _ = Rut::from_formatted("17951585-7", Format::Dots);
+    });
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            (String::from("rutcl.parse.ok"), None),
+            (
+                String::from("rutcl.parse.err"),
+                Some(String::from("invalid_verification_digit"))
+            ),
+            (
+                String::from("rutcl.parse.err"),
+                Some(String::from("invalid_format"))
+            ),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn random_with_skips_reserved_ranges() {
+    let options = RandomOptions::SAFE.skip_range(1_000_000..=45_999_999);
+
+    for _ in 0..1_000 {
+        let rut = RutBand::Persons.random_with(&options).unwrap();
+
+        assert!((48_000_000..=49_999_999).contains(&rut.num()), "{rut:?}");
+    }
+
+    let options = RandomOptions::UNRESTRICTED
+        .skip_range(1_000_000..=22_222_221)
+        .skip_range(22_222_223..=99_999_999);
+
+    assert_eq!(
+        Rut::random_with(&options).unwrap(),
+        Rut(22222222, VerificationDigit::Two)
+    );
+
+    let options = RandomOptions {
+        skip_well_known: true,
+        ..options
+    };
+
+    assert!(matches!(Rut::random_with(&options), Err(Error::OutOfRange)));
+    assert!(matches!(
+        RutBand::ForeignInvestors.random_with(&RandomOptions::default()),
+        Err(Error::OutOfRange)
+    ));
+}
+
+#[test]
+fn parse_many_from_text_finds_ruts() {
+    let text = "RUT: 17.951.585-7, empresa 92635843-k; sans 760864285.\n\
+        Tel 179515857123, ID179515857, mal 17.951.585-8, x 49.999.99-2 y 1111111-4";
+    let matches = Rut::parse_many_from_text(text)
+        .into_iter()
+        .map(|m| (m.range(), m.as_str(), m.rut()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        matches,
+        vec![
+            (
+                5..17,
+                "17.951.585-7",
+                Rut(17951585, VerificationDigit::Seven)
+            ),
+            (27..37, "92635843-k", Rut(92635843, VerificationDigit::K)),
+            (44..53, "760864285", Rut(76086428, VerificationDigit::Five)),
+            (120..129, "1111111-4", Rut(1111111, VerificationDigit::Four)),
+        ]
+    );
+
+    for (range, raw, _) in matches {
+        assert_eq!(&text[range], raw);
+    }
+}
+
+#[test]
+fn parse_many_from_text_handles_unicode_and_edges() {
+    let text = "«17951585-7»—92635843K";
+    let matches = Rut::parse_many_from_text(text);
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].as_str(), "17951585-7");
+    assert_eq!(&text[matches[1].range()], "92635843K");
+    assert!(Rut::parse_many_from_text("").is_empty());
+    assert!(Rut::parse_many_from_text("...-7 -- 1.").is_empty());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn extractor_applies_context_rules() {
+    let text = "RUT: 17.951.585-7\nTel.: 17951585-7\nRUN 9 263 584 3 k\nR.U.T. N° 76086428 5\nfolio 1111111-4";
+    let found = |extractor: Extractor| {
+        extractor
+            .extract(text)
+            .into_iter()
+            .map(|m| m.as_str().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        found(Extractor::builder().build()),
+        vec!["17.951.585-7", "17951585-7", "76086428 5", "1111111-4"]
+    );
+    assert_eq!(
+        found(Extractor::builder().exclude_phone_numbers().build()),
+        vec!["17.951.585-7", "76086428 5", "1111111-4"]
+    );
+    assert_eq!(
+        found(Extractor::builder().require_label().build()),
+        vec!["17.951.585-7", "76086428 5"]
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn extractor_resolves_overlaps() {
+    // "1 234561 5" and "5 000 002 8" share the "5"
+    let text = "ids: 1 234561 5 000 002 8";
+    let first = Extractor::builder().build().extract(text);
+    let longest = Extractor::builder()
+        .overlap(Overlap::Longest)
+        .build()
+        .extract(text);
+
+    assert_eq!(
+        first.iter().map(|m| m.as_str()).collect::<Vec<_>>(),
+        vec!["1 234561 5"]
+    );
+    assert_eq!(
+        longest.iter().map(|m| m.as_str()).collect::<Vec<_>>(),
+        vec!["5 000 002 8"]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "serde", not(feature = "serde-always-str")))]
+fn serde_uses_number_for_compact_formats() {
+    use serde_test::assert_de_tokens;
+
+    let rut = Rut(92635843, VerificationDigit::K);
+
+    assert_tokens(&rut.compact(), &[Token::U32(92635843)]);
+    assert_tokens(&rut.readable(), &[Token::Str("92635843K")]);
+    assert_de_tokens(&rut.compact(), &[Token::I64(92635843)]);
+    assert_de_tokens_error::<serde_test::Compact<Rut>>(&[Token::U32(42)], "Out of range");
+    assert_de_tokens_error::<serde_test::Compact<Rut>>(&[Token::U64(1 << 40)], "Out of range");
+}
+
+#[test]
+#[cfg(feature = "serde-always-str")]
+fn serde_always_str_ignores_compact_formats() {
+    let rut = Rut(92635843, VerificationDigit::K);
+
+    assert_tokens(&rut.compact(), &[Token::Str("92635843K")]);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn rut_map_keys_round_trip_through_json() {
+    use std::collections::HashMap;
+
+    let map = HashMap::from([
+        (Rut(92635843, VerificationDigit::K), 1),
+        (Rut(17951585, VerificationDigit::Seven), 2),
+    ]);
+    let json = serde_json::to_value(&map).unwrap();
+
+    assert_eq!(json["92635843K"], 1);
+    assert_eq!(json["179515857"], 2);
+    assert_eq!(
+        serde_json::from_value::<HashMap<Rut, i32>>(json).unwrap(),
+        map
+    );
+    assert_eq!(
+        serde_json::from_str::<HashMap<Rut, i32>>(r#"{ "92.635.843-k": 1 }"#).unwrap(),
+        HashMap::from([(Rut(92635843, VerificationDigit::K), 1)])
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn rut_key_map_rejects_duplicated_ruts() {
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Invoices {
+        #[serde(with = "crate::serde::rut_key_map")]
+        by_rut: BTreeMap<Rut, u32>,
+    }
+
+    let invoices: Invoices =
+        serde_json::from_str(r#"{ "by_rut": { "17.951.585-7": 1, "92635843-K": 2 } }"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&invoices).unwrap(),
+        r#"{"by_rut":{"179515857":1,"92635843K":2}}"#
+    );
+
+    let err =
+        serde_json::from_str::<Invoices>(r#"{ "by_rut": { "17.951.585-7": 1, "179515857": 2 } }"#)
+            .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("duplicate RUT key \"179515857\""));
+    assert!(serde_json::from_str::<Invoices>(r#"{ "by_rut": { "17.951.585-8": 1 } }"#).is_err());
+}
+
+#[test]
+fn fuzz_check_round_trips_samples() {
+    for sample in samples() {
+        fuzz_check(sample.rut.as_bytes());
+    }
+
+    fuzz_check(b"");
+    fuzz_check(b"\xf0\x28\x8c\x28");
+    fuzz_check("17.951.585\u{2013}7".as_bytes());
+    fuzz_check(b"99999999999999999999-9");
+}
+
+proptest::proptest! {
+    #[test]
+    fn fuzz_check_holds_for_any_input(input in "\\PC{0,16}") {
+        fuzz_check(input.as_bytes());
+    }
+}
+
+#[test]
+fn from_str_with_applies_validation_policy() {
+    let strict = ValidationPolicy::STRICT;
+
+    assert_eq!(
+        Rut::from_str_with("92.635.843-K", strict).unwrap(),
+        Rut(92635843, VerificationDigit::K)
+    );
+    assert!(matches!(
+        Rut::from_str_with("92.635.843-k", strict),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        Rut::from_str_with("017.951.585-7", strict),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        Rut::from_str_with("17.951.585\u{2013}7", strict),
+        Err(Error::NaN(_))
+    ));
+    assert!(Rut::from_str_with("017.951.585-7", ValidationPolicy::default()).is_ok());
+    assert!(Rut::from_str_with("92.635.843-k", ValidationPolicy::default()).is_ok());
+}
+
+#[test]
+fn from_str_with_applies_bands() {
+    let hospital = ValidationPolicy {
+        reject_companies: true,
+        ..ValidationPolicy::default()
+    };
+    let investors = ValidationPolicy {
+        band: RutBand::ForeignInvestors,
+        ..ValidationPolicy::default()
+    };
+
+    assert!(matches!(
+        Rut::from_str_with("76.086.428-5", hospital),
+        Err(Error::RejectedBand(RutBand::Companies))
+    ));
+    assert!(matches!(
+        Rut::from_str_with("17.951.585-7", investors),
+        Err(Error::OutOfBand(RutBand::ForeignInvestors))
+    ));
+    assert!(Rut::from_str_with("46.000.000-9", investors).is_ok());
+    assert_eq!(investors.min(), Rut(46000000, VerificationDigit::Nine));
+    assert_eq!(ValidationPolicy::default().max(), MAX);
+}
+
+#[test]
+fn rut_parser_resolves_configuration() {
+    let strict = RutParser::builder().lenient(false).build();
+    let lenient = RutParser::builder()
+        .policy(ValidationPolicy::STRICT)
+        .lenient(true)
+        .build();
+    let input = "17.951.585\u{2013}7";
+
+    assert!(strict.parse(input).is_err());
+    assert_eq!(
+        lenient.parse(input).unwrap(),
+        Rut(17951585, VerificationDigit::Seven)
+    );
+    assert!(lenient.parse("92.635.843-k").is_err());
+    assert_eq!(lenient.policy().sanitize, SanitizeOptions::LENIENT);
+    assert_eq!(
+        RutParser::builder().build().policy(),
+        &ValidationPolicy::default()
+    );
+
+    for sample in samples() {
+        assert_eq!(
+            RutParser::default().parse(&sample.rut).ok(),
+            Rut::from_str(&sample.rut).ok()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn uwrite_matches_format() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            let mut out = String::new();
+
+            rut.uwrite(&mut out, fmt).unwrap();
+
+            assert_eq!(out, rut.format(fmt));
+        }
+    }
+
+    let mut out = String::new();
+
+    MAX.uwrite(&mut out, Format::Dots).unwrap();
+    MIN.uwrite(&mut out, Format::Dots).unwrap();
+
+    assert_eq!(out, "99.999.999-91.000.000-9");
+}
+
+#[test]
+fn rut_macro_builds_constants() {
+    const EMPLOYER: Rut = rut!("92.635.843-k");
+    const SANS: Rut = rut!("179515857");
+
+    assert_eq!(EMPLOYER, Rut(92635843, VerificationDigit::K));
+    assert_eq!(SANS, Rut(17951585, VerificationDigit::Seven));
+    assert_eq!(rut!("1000000-9"), MIN);
+}
+
+#[test]
+fn from_literal_agrees_with_from_str() {
+    let cases = vec![
+        "17.951.585-7",
+        "17.951.585-8",
+        "",
+        "-",
+        "K",
+        "1-9",
+        "12.345.678-5",
+        "999999999999-9",
+        "17a951585-7",
+        "92635843k",
+    ];
+
+    for case in cases {
+        assert_eq!(
+            Rut::__from_literal(case).ok(),
+            Rut::parse_sanitized(case, SanitizeOptions::STRICT).ok(),
+            "{case}"
+        );
+    }
+}
+
+#[test]
+fn db_codec_round_trips() {
+    use crate::db::Codec;
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        assert_eq!(Rut::from_int(rut.to_int().into()).unwrap(), rut);
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            assert_eq!(Rut::from_text_any(&rut.to_text(fmt)).unwrap(), rut);
+        }
+    }
+
+    assert!(matches!(Rut::from_int(-17951585), Err(Error::OutOfRange)));
+    assert!(matches!(Rut::from_int(1 << 40), Err(Error::OutOfRange)));
+    assert_eq!(
+        Rut::from_text_any(" 92.635.843\u{2013}k\t").unwrap(),
+        Rut(92635843, VerificationDigit::K)
+    );
+}
+
+#[test]
+fn db_verify_reports_rows_which_do_not_round_trip() {
+    use crate::db::{verify, Problem, Stored};
+
+    let stored: Vec<Stored> = vec![
+        "92635843-K".into(),
+        "92635843-k".into(),
+        "92.635.843-K".into(),
+        "".into(),
+        92635843i32.into(),
+        0i64.into(),
+    ];
+    let issues = verify(stored, Format::Dash)
+        .into_iter()
+        .map(|issue| match issue.problem {
+            Problem::Invalid(err) => (issue.row, err.kind().to_string()),
+            Problem::NotCanonical { canonical } => (issue.row, canonical),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        issues,
+        vec![
+            (1, String::from("92635843-K")),
+            (2, String::from("92635843-K")),
+            (3, String::from("empty_string")),
+            (5, String::from("out_of_range")),
+        ]
+    );
+}
+
+proptest::proptest! {
+    #[test]
+    fn ord_is_consistent_with_eq(a in MIN_NUM..=MAX_NUM, b in MIN_NUM..=MAX_NUM) {
+        let a = Rut(a, VerificationDigit::compute(a));
+        let b = Rut(b, VerificationDigit::compute(b));
+
+        proptest::prop_assert_eq!(a.cmp(&b), a.num().cmp(&b.num()));
+        proptest::prop_assert_eq!(a.cmp(&b) == std::cmp::Ordering::Equal, a == b);
+        proptest::prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+    }
+}
+
+#[test]
+fn slice_helpers_sort_and_search() {
+    let mut ruts = samples()
+        .into_iter()
+        .map(|sample| Rut::from_str(&sample.rut).unwrap())
+        .collect::<Vec<_>>();
+    let mut want = ruts.clone();
+
+    want.sort();
+    ruts.as_mut_slice().sort_by_num();
+
+    assert_eq!(ruts, want);
+
+    ruts.dedup();
+
+    for (idx, rut) in ruts.iter().enumerate() {
+        assert_eq!(ruts.binary_search(rut), Ok(idx));
+        assert_eq!(ruts.as_slice().binary_search_num(rut.num()), Ok(idx));
+    }
+
+    assert_eq!(ruts.binary_search_num(0), Err(0));
+    assert_eq!(ruts.binary_search_num(MAX_NUM), Err(ruts.len()));
+    assert!(ruts.binary_search(&MAX).is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_formatted_adapters() {
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Record {
+        #[serde(with = "crate::serde::formatted::sans")]
+        id: Rut,
+        #[serde(default, with = "crate::serde::formatted::dots::option")]
+        owner: Option<Rut>,
+        #[serde(default, with = "crate::serde::formatted::dash::vec")]
+        related: Vec<Rut>,
+    }
+
+    let record: Record = serde_json::from_str(
+        r#"{ "id": "92.635.843-k", "owner": "17951585-7", "related": ["760864285", "1.111.111-4"] }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        record,
+        Record {
+            id: Rut(92635843, VerificationDigit::K),
+            owner: Some(Rut(17951585, VerificationDigit::Seven)),
+            related: vec![
+                Rut(76086428, VerificationDigit::Five),
+                Rut(1111111, VerificationDigit::Four),
+            ],
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&record).unwrap(),
+        r#"{"id":"92635843K","owner":"17.951.585-7","related":["76086428-5","1111111-4"]}"#
+    );
+
+    let empty: Record = serde_json::from_str(r#"{ "id": "92635843K", "owner": null }"#).unwrap();
+
+    assert_eq!(empty.owner, None);
+    assert!(empty.related.is_empty());
+    assert!(serde_json::from_str::<Record>(r#"{ "id": "92635843K", "related": ["1"] }"#).is_err());
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn rut_stream_ext_combinators() {
+    use futures::executor::block_on;
+    use futures::stream::{self, Stream, StreamExt};
+
+    use crate::stream::RutStreamExt;
+
+    let messages = || {
+        stream::iter(vec![
+            String::from("17.951.585-7"),
+            String::from("17.951.585-8"),
+            String::from("76086428-5"),
+        ])
+    };
+
+    let validated = block_on(messages().validate_ruts().collect::<Vec<_>>());
+
+    assert!(validated[0].is_ok());
+    assert!(matches!(
+        validated[1],
+        Err(Error::InvalidVerificationDigit { .. })
+    ));
+    assert_eq!(
+        block_on(messages().filter_valid_ruts().collect::<Vec<_>>()),
+        vec![
+            Rut(17951585, VerificationDigit::Seven),
+            Rut(76086428, VerificationDigit::Five)
+        ]
+    );
+    assert_eq!(
+        block_on(
+            messages()
+                .normalize_ruts(Format::Sans)
+                .map(Result::ok)
+                .collect::<Vec<_>>()
+        ),
+        vec![
+            Some(String::from("179515857")),
+            None,
+            Some(String::from("760864285"))
+        ]
+    );
+    assert_eq!(messages().filter_valid_ruts().size_hint(), (0, Some(3)));
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn validates_files_asynchronously() {
+    use futures::StreamExt;
+
+    let path = std::env::temp_dir().join(format!("rutcl-aio-{}.txt", std::process::id()));
+
+    std::fs::write(&path, "17.951.585-7\r\n17.951.585-8\n\n76086428-5").unwrap();
+
+    let lines = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(async {
+            crate::aio::validate_file(&path)
+                .await
+                .unwrap()
+                .map(|line| {
+                    let (line, result) = line.unwrap();
+
+                    (line, result.map_err(|err| err.kind()))
+                })
+                .collect::<Vec<_>>()
+                .await
+        });
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        lines,
+        vec![
+            (1, Ok(Rut(17951585, VerificationDigit::Seven))),
+            (2, Err("invalid_verification_digit")),
+            (3, Err("empty_string")),
+            (4, Ok(Rut(76086428, VerificationDigit::Five))),
+        ]
+    );
+}
+
+#[test]
+fn rut_interner_assigns_dense_ids() {
+    let ruts = samples()
+        .into_iter()
+        .map(|sample| Rut::from_str(&sample.rut).unwrap())
+        .collect::<Vec<_>>();
+    let mut interner = RutInterner::with_capacity(ruts.len());
+    let ids = ruts
+        .iter()
+        .map(|rut| interner.intern(*rut))
+        .collect::<Vec<_>>();
+
+    for (rut, id) in ruts.iter().zip(&ids) {
+        assert_eq!(interner.resolve(*id), Some(*rut));
+        assert_eq!(interner.get(rut), Some(*id));
+    }
+
+    assert!(ids.iter().all(|id| (*id as usize) < interner.len()));
+    assert_eq!(
+        interner
+            .as_slice()
+            .iter()
+            .copied()
+            .collect::<RutInterner>()
+            .len(),
+        interner.len()
+    );
+    assert_eq!(interner.get(&MAX), None);
+    assert_eq!(interner.resolve(interner.len() as u32), None);
+    assert!(RutInterner::new().is_empty());
+}
+
+#[test]
+#[cfg(feature = "arrow")]
+fn rut_array_round_trips_through_arrow() {
+    use ::arrow_array::UInt32Array;
+    use ::arrow_schema::{DataType, Field};
+
+    use crate::arrow::{field, is_rut_field, RutArray};
+
+    let mut builder = RutArray::builder(3);
+
+    builder.append(Rut(92635843, VerificationDigit::K));
+    builder.append_null();
+    builder.append_option(Some(MIN));
+
+    let array = builder.finish();
+    let read = RutArray::try_from_array(array.as_inner()).unwrap();
+
+    assert_eq!(
+        read.iter().collect::<Vec<_>>(),
+        vec![Some(Rut(92635843, VerificationDigit::K)), None, Some(MIN)]
+    );
+    assert!(is_rut_field(&field("rut", true)));
+    assert!(!is_rut_field(&Field::new("rut", DataType::UInt32, true)));
+    assert!(matches!(
+        RutArray::try_from_array(&UInt32Array::from(vec![42])),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        RutArray::try_from_array(&::arrow_array::Int64Array::from(vec![17951585])),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn rut_array_round_trips_through_parquet() {
+    use crate::arrow::{parquet, RutArray};
+
+    let ruts = samples()
+        .into_iter()
+        .map(|sample| Rut::from_str(&sample.rut).ok())
+        .chain([None])
+        .collect::<Vec<_>>();
+    let path = std::env::temp_dir().join(format!("rutcl-{}.parquet", std::process::id()));
+
+    parquet::write(
+        std::fs::File::create(&path).unwrap(),
+        "rut",
+        ruts.iter().copied().collect::<RutArray>(),
+    )
+    .unwrap();
+
+    let read = parquet::read(std::fs::File::open(&path).unwrap(), "rut");
+    let missing = parquet::read(std::fs::File::open(&path).unwrap(), "id");
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.unwrap(), ruts);
+    assert!(missing.is_err());
+}
+
+#[test]
+#[cfg(feature = "polars")]
+fn polars_namespace_validates_and_normalizes() {
+    use ::polars::prelude::*;
+
+    use crate::polars::RutExprExt;
+
+    let samples = samples();
+    let df = df!(
+        "rut" => samples.iter().map(|sample| Some(sample.rut.as_str())).chain([Some("12.345.678-0"), None]).collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let out = df
+        .lazy()
+        .select([
+            col("rut").rut().is_valid().alias("valid"),
+            col("rut").rut().normalize(Format::Dash).alias("dash"),
+            col("rut").rut().num(),
+        ])
+        .collect()
+        .unwrap();
+
+    let valid = out.column("valid").unwrap().bool().unwrap();
+    let dash = out.column("dash").unwrap().str().unwrap();
+    let num = out.column("rut").unwrap().u32().unwrap();
+
+    for (idx, sample) in samples.iter().enumerate() {
+        assert_eq!(valid.get(idx), Some(true));
+        assert_eq!(
+            dash.get(idx),
+            Some(format!("{}-{}", sample.num, sample.vd).as_str())
+        );
+        assert_eq!(num.get(idx), sample.num.parse().ok());
+    }
+
+    let invalid = samples.len();
+
+    assert_eq!(valid.get(invalid), Some(false));
+    assert_eq!(dash.get(invalid), None);
+    assert_eq!(num.get(invalid), None);
+    assert_eq!(valid.get(invalid + 1), None);
+}
+
+#[test]
+fn format_names_round_trip() {
+    for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+        assert_eq!(fmt.to_string().parse::<Format>().unwrap(), fmt);
+        assert_eq!(fmt.name().to_uppercase().parse::<Format>().unwrap(), fmt);
+    }
+
+    assert!(matches!("".parse::<Format>(), Err(Error::InvalidFormat)));
+}
+
+#[test]
+#[cfg(feature = "heuristics")]
+fn age_band_estimate_buckets_person_ruts() {
+    use crate::RegistrationEra;
+
+    let era = |num| Rut(num, VerificationDigit::compute(num)).age_band_estimate();
+
+    assert_eq!(era(MIN_NUM), Some(RegistrationEra::Before1990));
+    assert_eq!(era(16_999_999), Some(RegistrationEra::Before1990));
+    assert_eq!(era(17_000_000), Some(RegistrationEra::Nineties));
+    assert_eq!(era(20_500_000), Some(RegistrationEra::TwoThousands));
+    assert_eq!(era(23_500_000), Some(RegistrationEra::Recent));
+    assert_eq!(era(45_999_999), Some(RegistrationEra::Recent));
+    assert_eq!(era(46_000_000), None);
+    assert_eq!(era(48_000_000), Some(RegistrationEra::Recent));
+    assert_eq!(era(50_000_000), None);
+}
+
+#[test]
+#[cfg(feature = "dataset")]
+fn dataset_injects_requested_dirtiness() {
+    use crate::dataset::{Defect, RecordKind, RutDataset};
+
+    let build = || {
+        RutDataset::builder()
+            .persons(900)
+            .companies(100)
+            .collision_rate(0.1)
+            .invalid_rate(0.2)
+            .format(Format::Dash)
+            .seed(7)
+            .build()
+            .unwrap()
+    };
+    let dataset = build();
+    let records = dataset.records();
+    let count = |defect| records.iter().filter(|r| r.defect == Some(defect)).count();
+
+    assert_eq!(dataset, build());
+    assert_eq!(records.len(), 1000);
+    assert_eq!(
+        records
+            .iter()
+            .filter(|r| r.kind == RecordKind::Company)
+            .count(),
+        100
+    );
+    assert!((50..150).contains(&count(Defect::Collision)));
+    assert!(
+        (100..260).contains(&(count(Defect::WrongVerificationDigit) + count(Defect::Malformed)))
+    );
+
+    for record in records {
+        assert_eq!(
+            Rut::from_str(&record.value).ok(),
+            record.is_valid().then_some(record.rut),
+            "{record:?}"
+        );
+
+        let band = match record.kind {
+            RecordKind::Person => RutBand::Persons,
+            RecordKind::Company => RutBand::Companies,
+        };
+
+        assert!(band.contains(&record.rut));
+    }
+
+    let mut csv = Vec::new();
+    let mut json = Vec::new();
+
+    dataset.write_csv(&mut csv).unwrap();
+    dataset.write_json(&mut json).unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+
+    assert_eq!(String::from_utf8(csv).unwrap().lines().count(), 1001);
+    assert_eq!(json.as_array().unwrap().len(), 1000);
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn csv_clean_flags_invalid_rows() {
+    use crate::csv::{clean, CleanOptions, CleanReport, OnInvalid};
+
+    let input = "\
+payer,payee,amount
+17.951.585-7,76086428-5,100
+179515850,76.086.428-5,200
+,760864285,300
+";
+    let mut output = Vec::new();
+    let options = CleanOptions::new(["payer", "payee"])
+        .format(Format::Dots)
+        .on_invalid(OnInvalid::Flag("valid".into()));
+    let report = clean(input.as_bytes(), &mut output, &options).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\
+payer,payee,amount,valid
+17.951.585-7,76.086.428-5,100,true
+179515850,76.086.428-5,200,false
+,76.086.428-5,300,false
+"
+    );
+    assert_eq!(
+        report,
+        CleanReport {
+            rows_in: 3,
+            rows_out: 3,
+            fixed: 2,
+            invalid: 2,
+            invalid_rows: 2,
+        }
+    );
+    assert!(clean(input.as_bytes(), Vec::new(), &CleanOptions::new(["rut"])).is_err());
+}
+
+#[test]
+#[cfg(feature = "calamine")]
+fn calamine_recovers_mangled_columns() {
+    use ::calamine::{Data, Range};
+
+    use crate::calamine::{from_range, Recovery, SheetError};
+
+    let rows = [
+        ["nombre", "rut", "dv"],
+        ["Ana", "17.951.585-7", ""],
+        ["Beto", "17951585", "7"],
+        ["Carla", "", "k"],
+        ["Dino", "", ""],
+    ];
+    let mut range = Range::new((4, 1), (8, 3));
+
+    for (row, values) in rows.iter().enumerate() {
+        for (col, value) in values.iter().enumerate() {
+            let data = match (row, col, *value) {
+                (3, 1, _) => Data::Float(92635843.0),
+                (4, 1, _) => Data::Int(179515857),
+                (_, _, "") => Data::Empty,
+                (_, _, value) => Data::String(value.to_string()),
+            };
+
+            range.set_value((4 + row as u32, 1 + col as u32), data);
+        }
+    }
+
+    let recovered = from_range(&range, "rut", Some("dv"))
+        .unwrap()
+        .into_iter()
+        .map(|(row, result)| {
+            (
+                row,
+                result
+                    .map(|r| (r.rut.format(Format::Dots), r.recovery))
+                    .ok(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        recovered,
+        vec![
+            (6, Some(("17.951.585-7".into(), Recovery::Parsed))),
+            (7, Some(("17.951.585-7".into(), Recovery::SeparateDigit))),
+            (8, Some(("92.635.843-K".into(), Recovery::SeparateDigit))),
+            (9, Some(("17.951.585-7".into(), Recovery::Sans))),
+        ]
+    );
+    assert!(matches!(
+        from_range(&range, "run", None),
+        Err(SheetError::MissingColumn(column)) if column == "run"
+    ));
+}
+
+#[test]
+fn checksum_trace_matches_verification_digit() {
+    use crate::explain::ChecksumTrace;
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let trace = rut.explain();
+
+        assert_eq!(trace.vd, rut.vd());
+        assert_eq!(trace.vd.to_string(), sample.vd.to_uppercase());
+        assert_eq!(trace.sum % 11, trace.remainder);
+        assert_eq!(trace.steps.len(), sample.num.len());
+    }
+
+    assert_eq!(
+        ChecksumTrace::new(92635843).to_string(),
+        "3×2 + 4×3 + 8×4 + 5×5 + 3×6 + 6×7 + 2×2 + 9×3 = 166; 166 mod 11 = 1; 11 - 1 = 10 → K"
+    );
+}
+
+#[test]
+fn parse_batch_points_errors_into_the_batch() {
+    let batch = "17.951.585-7\r\n  17951585-K \n\n7.951.585-x\n1a.951.585-7";
+    let lines = Rut::parse_batch(batch);
+    let spans = lines
+        .iter()
+        .map(|line| (line.line(), line.error_span().map(|span| &batch[span])))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        spans,
+        vec![(1, None), (2, Some("K")), (4, Some("x")), (5, Some("a"))]
+    );
+    assert_eq!(&batch[lines[1].range()], "17951585-K");
+    assert_eq!(lines[0].convert(Format::Sans), "179515857");
+    assert_eq!(lines[1].convert(Format::Sans), "17951585-K");
+    assert!(Rut::parse_batch(" \n\n").is_empty());
+}
+
+#[test]
+fn error_span_points_to_offending_chars() {
+    fn span(input: &str) -> &str {
+        let err = Rut::from_str(input).unwrap_err();
+
+        &input[err.span(input)]
+    }
+
+    assert_eq!(span("17.951.585-0"), "0");
+    assert_eq!(span("999.999-9"), "999.999");
+    assert_eq!(span(""), "");
+}
+
+#[test]
+fn verification_digit_integer_conversions() {
+    for (value, vd) in VerificationDigit::ALL.into_iter().enumerate() {
+        assert_eq!(VerificationDigit::try_from(value as u8).unwrap(), vd);
+        assert_eq!(VerificationDigit::try_from(value as u64).unwrap(), vd);
+        assert_eq!(u8::from(vd) as usize, value);
+        assert_eq!(vd.is_k(), value == 10);
+    }
+
+    assert!(VerificationDigit::ALL
+        .windows(2)
+        .all(|pair| pair[0] < pair[1]));
+    assert!(matches!(
+        VerificationDigit::try_from(11u8),
+        Err(Error::VerificationDigitOutOfBounds(value)) if value == "11"
+    ));
+    assert!(matches!(
+        VerificationDigit::try_from(u64::MAX),
+        Err(Error::VerificationDigitOutOfBounds(value)) if value == u64::MAX.to_string()
+    ));
+}
+
+#[test]
+#[cfg(feature = "strum")]
+fn strum_derives_match_hand_written_tables() {
+    use ::strum::{EnumCount, IntoEnumIterator};
+
+    assert_eq!(
+        VerificationDigit::iter().collect::<Vec<_>>(),
+        VerificationDigit::ALL
+    );
+    assert_eq!(VerificationDigit::COUNT, VerificationDigit::ALL.len());
+
+    for vd in VerificationDigit::iter() {
+        let name: &'static str = vd.into();
+
+        assert_eq!(name, vd.to_string());
+        assert_eq!(name.parse::<VerificationDigit>().unwrap(), vd);
+    }
+
+    for fmt in Format::iter() {
+        let name: &'static str = fmt.into();
+
+        assert_eq!(name, fmt.name());
+        assert_eq!(name.parse::<Format>().unwrap(), fmt);
+    }
+
+    assert_eq!(Format::COUNT, 3);
+}
+
+#[test]
+fn sans_cow_borrows_clean_inputs() {
+    use std::borrow::Cow;
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let sans = rut.format(Format::Sans);
+        let dots = rut.format(Format::Dots);
+
+        assert!(matches!(Rut::sans_cow(&sans), Cow::Borrowed(s) if s == sans));
+        assert!(matches!(Rut::sans_cow(&dots), Cow::Owned(s) if s == sans));
+        assert_eq!(Rut::sans(&dots), sans);
+    }
+}
+
+#[test]
+#[cfg(feature = "smallstr")]
+fn format_smol_matches_format() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            let smol = rut.format_smol(fmt);
+
+            assert_eq!(smol, rut.format(fmt));
+            assert!(!smol.spilled());
+        }
+    }
+
+    for line in Rut::parse_batch("17.951.585-7\nnot a rut, and quite a long one") {
+        assert_eq!(line.convert_smol(Format::Dash), line.convert(Format::Dash));
+    }
+}
+
+#[test]
+fn from_bytes_matches_from_str() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            let written = rut.format(fmt);
+
+            assert_eq!(Rut::from_bytes(written.as_bytes()).unwrap(), rut);
+        }
+    }
+
+    for input in [
+        "",
+        "  ",
+        "-",
+        "17.951.585-8",
+        "17951585X",
+        "4294967296-0",
+        " 17 951 585 7 ",
+    ] {
+        assert_eq!(
+            Rut::from_bytes(input.as_bytes()).map_err(|err| err.to_string()),
+            Rut::from_str(input).map_err(|err| err.to_string()),
+            "{input:?}"
+        );
+    }
+
+    assert!(Rut::from_bytes("17.951.585–7".as_bytes()).is_err());
+    assert!(matches!(
+        Rut::from_bytes(b"17951585\xc3"),
+        Err(Error::VerificationDigitOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn parse_many_from_bytes_matches_text() {
+    let text = "Cliente 17.951.585-7 (empresa 76086428-5), ref. 17.951.585-8";
+
+    assert_eq!(
+        Rut::parse_many_from_bytes(text.as_bytes()),
+        Rut::parse_many_from_text(text)
+    );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn to_uuid_v5_is_stable_across_formats() {
+    use ::uuid::Uuid;
+
+    let customers = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com/customers");
+    let vendors = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com/vendors");
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let id = rut.to_uuid_v5(&customers);
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            let written = Rut::from_str(&rut.format(fmt)).unwrap();
+
+            assert_eq!(written.to_uuid_v5(&customers), id);
+        }
+
+        assert_ne!(rut.to_uuid_v5(&vendors), id);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_band_adapters() {
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Transfer {
+        #[serde(with = "crate::serde::company_only")]
+        sender: Rut,
+        #[serde(default, with = "crate::serde::person_only::option")]
+        receiver: Option<Rut>,
+    }
+
+    let transfer: Transfer =
+        serde_json::from_str(r#"{ "sender": "76.086.428-5", "receiver": "17951585-7" }"#).unwrap();
+
+    assert_eq!(
+        transfer,
+        Transfer {
+            sender: Rut(76086428, VerificationDigit::Five),
+            receiver: Some(Rut(17951585, VerificationDigit::Seven)),
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&transfer).unwrap(),
+        r#"{"sender":"760864285","receiver":"179515857"}"#
+    );
+    assert!(
+        serde_json::from_str::<Transfer>(r#"{ "sender": "760864285" }"#)
+            .unwrap()
+            .receiver
+            .is_none()
+    );
+
+    let err = serde_json::from_str::<Transfer>(r#"{ "sender": "17951585-7" }"#).unwrap_err();
+
+    assert!(err.to_string().contains("Out of band Companies"));
+    assert!(serde_json::from_str::<Transfer>(
+        r#"{ "sender": "760864285", "receiver": "760864285" }"#
+    )
+    .is_err());
+}
+
+#[test]
+fn foreign_tax_ids_get_targeted_errors() {
+    for (input, want) in [
+        ("20-12345678-6", ForeignTaxId::ArgentineCuit),
+        ("30-71234567-1", ForeignTaxId::ArgentineCuit),
+        ("20123456786", ForeignTaxId::PeruvianRuc),
+        ("20100070970", ForeignTaxId::PeruvianRuc),
+        ("10456789019", ForeignTaxId::PeruvianRuc),
+    ] {
+        assert_eq!(ForeignTaxId::guess(input), Some(want), "{input:?}");
+
+        for err in [
+            Rut::from_str(input).unwrap_err(),
+            Rut::from_bytes(input.as_bytes()).unwrap_err(),
+        ] {
+            assert!(
+                matches!(err, Error::LooksLikeForeignTaxId { guess } if guess == want),
+                "{input:?}: {err:?}"
+            );
+            assert_eq!(err.kind(), "looks_like_foreign_tax_id");
+        }
+    }
+
+    assert_eq!(
+        Rut::from_str("20-12345678-6").unwrap_err().to_string(),
+        "Not a RUT, looks like a foreign tax ID: Argentine CUIT"
+    );
+
+    for input in ["20-12345678-5", "99123456789", "123456789012", "1-9"] {
+        assert_eq!(ForeignTaxId::guess(input), None, "{input:?}");
+        assert!(!matches!(
+            Rut::from_str(input),
+            Err(Error::LooksLikeForeignTaxId { .. })
+        ));
+    }
+}
+
+#[test]
+fn national_id_matches_rut_checksum() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let id = NationalId::<RutChecksum>::from_str(&sample.rut).unwrap();
+
+        assert_eq!(RutChecksum::check_digit(rut.num()), rut.vd());
+        assert_eq!(NationalId::<RutChecksum>::from(rut), id);
+        assert_eq!(Rut::from(id), rut);
+        assert_eq!(id.to_string(), rut.format(Format::Dash));
+    }
+
+    assert!(matches!(
+        NationalId::<RutChecksum>::from_str("17.951.585-8"),
+        Err(Error::InvalidVerificationDigit {
+            have: '8',
+            want: '7'
+        })
+    ));
+    assert!(matches!(
+        NationalId::<RutChecksum>::new(MAX_NUM + 1),
+        Err(Error::OutOfRange)
+    ));
+}
+
+#[test]
+#[cfg(feature = "rusqlite")]
+fn rusqlite_round_trip() {
+    use ::rusqlite::{params, Connection};
+
+    use crate::db::Codec;
+
+    let conn = Connection::open_in_memory().unwrap();
+
+    conn.execute_batch("CREATE TABLE customers (as_text TEXT, as_int INTEGER, as_any)")
+        .unwrap();
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        conn.execute(
+            "INSERT INTO customers VALUES (?1, ?2, ?1)",
+            params![rut, rut.to_int()],
+        )
+        .unwrap();
+
+        let row: (Rut, Rut, Rut, String) = conn
+            .query_row(
+                "SELECT as_text, as_int, as_any, as_text FROM customers WHERE as_text = ?1",
+                [rut],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+
+        assert_eq!(row, (rut, rut, rut, rut.format(Format::Dash)));
+        conn.execute("DELETE FROM customers", []).unwrap();
+    }
+
+    let invalid = conn
+        .query_row("SELECT '17.951.585-8'", [], |row| row.get::<_, Rut>(0))
+        .unwrap_err();
+
+    assert!(invalid.to_string().contains("Invalid verification digit"));
+    assert!(conn
+        .query_row("SELECT 1.5", [], |row| row.get::<_, Rut>(0))
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "sea-orm")]
+fn sea_orm_round_trip() {
+    use std::collections::BTreeMap;
+
+    use ::sea_orm::sea_query::{Nullable, ValueType};
+    use ::sea_orm::{ProxyRow, QueryResult, TryGetable, Value};
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let value = Value::from(rut);
+
+        assert_eq!(value, Value::from(rut.format(Format::Dash)));
+        assert_eq!(<Rut as ValueType>::try_from(value.clone()).unwrap(), rut);
+        assert_eq!(
+            <Rut as ValueType>::try_from(Value::from(rut.num() as i64)).unwrap(),
+            rut
+        );
+
+        let res = QueryResult::from(ProxyRow::new(BTreeMap::from([
+            ("rut".to_string(), value),
+            ("owner".to_string(), Rut::null()),
+        ])));
+
+        assert_eq!(Rut::try_get(&res, "", "rut").unwrap(), rut);
+        assert_eq!(Option::<Rut>::try_get(&res, "", "owner").unwrap(), None);
+    }
+
+    let res = QueryResult::from(ProxyRow::new(BTreeMap::from([(
+        "rut".to_string(),
+        Value::from("17.951.585-8"),
+    )])));
+
+    assert!(Rut::try_get(&res, "", "rut").is_err());
+    assert!(<Rut as ValueType>::try_from(Value::from(1.5)).is_err());
+}
+
+#[test]
+#[cfg(feature = "kafka")]
+fn kafka_murmur2_matches_java_client() {
+    // Vectors from the Java client's `UtilsTest.testMurmur2`
+    for (key, want) in [
+        ("21", -973932308),
+        ("foobar", -790332482),
+        ("a-little-bit-long-string", -985981536),
+        ("a-little-bit-longer-string", -1486304829),
+        (
+            "lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8",
+            -58897971,
+        ),
+        ("abc", 479470107),
+    ] {
+        assert_eq!(kafka::murmur2(key.as_bytes()), want, "{key:?}");
+    }
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let partition = kafka::partition_for(&rut, 7);
+
+        assert!(partition < 7);
+        assert_eq!(
+            partition,
+            kafka::partition_for_key(&(rut.num() as i32).to_be_bytes(), 7)
+        );
+    }
+}
+
+#[test]
+fn subject_tokens_round_trip() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let token = rut.to_subject_token();
+
+        assert!(!token.contains(['.', '*', '>', '+', '#', '/']));
+        assert_eq!(Rut::from_subject_token(&token).unwrap(), rut);
+        assert_eq!(
+            Rut::from_subject(&rut.subject("{rut}.events.>"), "{rut}.events.>").unwrap(),
+            rut
+        );
+    }
+
+    for (subject, template) in [
+        ("invoices.76086428-5", "invoices.{rut}.created"),
+        ("invoices.76086428-5.created.v2", "invoices.{rut}.created"),
+        ("invoices.76086428-5.created", "invoices.created"),
+        ("invoices.76.086.428-5.created", "invoices.{rut}.created"),
+        ("invoices.76086428-6.created", "invoices.{rut}.created"),
+    ] {
+        assert!(Rut::from_subject(subject, template).is_err(), "{subject:?}");
+    }
+}
+
+#[test]
+fn label_budget_caps_cardinality() {
+    use std::collections::HashSet;
+
+    let budget = LabelBudget::new(3, 8);
+    let ruts = Rut::sample_fixture(100, 0).collect::<Vec<Rut>>();
+    let labels = ruts
+        .iter()
+        .map(|rut| budget.label(rut))
+        .collect::<HashSet<String>>();
+
+    assert_eq!(budget.tracked(), 3);
+    assert!(labels.len() <= 3 + 8);
+
+    for rut in &ruts[..3] {
+        assert_eq!(budget.label(rut), rut.to_metric_label());
+    }
+
+    for rut in &ruts[3..] {
+        assert_eq!(budget.label(rut), format!("bucket-{}", budget.bucket(rut)));
+        assert!(budget.bucket(rut) < 8);
+    }
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn codegen_regex_matches_formats() {
+    use regex::Regex;
+
+    let formats = [Format::Sans, Format::Dash, Format::Dots];
+    let patterns = formats.map(|fmt| Regex::new(&codegen::regex(fmt)).unwrap());
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for (fmt, pattern) in formats.iter().zip(&patterns) {
+            for (other, written) in formats.map(|other| (other, rut.format(other))) {
+                assert_eq!(pattern.is_match(&written), *fmt == other, "{written:?}");
+            }
+
+            assert!(pattern.is_match(&rut.format(*fmt).to_lowercase()));
+        }
+    }
+
+    for pattern in &patterns {
+        assert!(!pattern.is_match("1-9"));
+        assert!(!pattern.is_match("100.000.000-7"));
+    }
+
+    assert!(codegen::javascript().starts_with("function isValidRut(input) {"));
+    assert!(codegen::plpgsql().contains("ARRAY[2, 3, 4, 5, 6, 7]"));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn correcting_rut_flags_wrong_digits() {
+    #[derive(::serde::Deserialize)]
+    struct Record {
+        rut: CorrectingRut,
+    }
+
+    let records: Vec<Record> = serde_json::from_str(
+        r#"[{ "rut": "17.951.585-7" }, { "rut": "17.951.585-K" }, { "rut": "76086428?" }]"#,
+    )
+    .unwrap();
+    let flagged = records
+        .iter()
+        .map(|record| (record.rut.to_string(), record.rut.original_digit()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        flagged,
+        [
+            ("179515857".to_string(), None),
+            ("179515857".to_string(), Some('K')),
+            ("760864285".to_string(), Some('?')),
+        ]
+    );
+    assert!(serde_json::from_str::<Record>(r#"{ "rut": "1-9" }"#).is_err());
+    assert!(serde_json::from_str::<Record>(r#"{ "rut": "" }"#).is_err());
+    assert_eq!(
+        serde_json::to_string(&records[1].rut).unwrap(),
+        r#""179515857""#
+    );
+}
+
+#[test]
+fn group_by_prefix_matches_counts() {
+    let ruts = Rut::sample_fixture(500, 7).collect::<Vec<Rut>>();
+    let buckets = Rut::group_by_prefix(ruts.iter().copied());
+    let counts = Rut::count_by_prefix(ruts.iter().copied());
+
+    assert_eq!(buckets.values().map(Vec::len).sum::<usize>(), ruts.len());
+    assert!(buckets.keys().eq(counts.keys()));
+
+    for (prefix, bucket) in &buckets {
+        assert_eq!(bucket.len(), counts[prefix]);
+        assert!((1..=99).contains(prefix));
+        assert!(bucket
+            .iter()
+            .all(|rut| rut.format(Format::Dots).starts_with(&format!("{prefix}."))));
+    }
+
+    assert_eq!(MIN.millions_prefix(), 1);
+    assert_eq!(MAX.millions_prefix(), 99);
+}
+
+#[test]
+fn fixedwidth_round_trip() {
+    use crate::fixedwidth::{self, Field, Width};
+
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for width in [Width::Nine, Width::Ten, Width::Twelve] {
+            let field = fixedwidth::write(&rut, width);
+
+            assert_eq!(field.len(), width.bytes());
+            assert_eq!(fixedwidth::read(field.as_bytes(), width).unwrap(), rut);
+        }
+    }
+
+    for field in [
+        "17951585k",
+        "17951585-7",
+        "1795158 7",
+        "+79515857",
+        "0179515857",
+    ] {
+        assert!(matches!(
+            fixedwidth::read(field.as_bytes(), Width::Nine),
+            Err(Error::InvalidFormat)
+        ));
+    }
+
+    assert!(matches!(
+        fixedwidth::read(b"179515858", Width::Nine),
+        Err(Error::InvalidVerificationDigit { .. })
+    ));
+    assert!(Field::new(4, Width::Ten).read(b"0001017951585").is_err());
+    assert!(Field::new(4, Width::Ten)
+        .write(&MIN, &mut [b' '; 13])
+        .is_err());
+}
+
+#[test]
+fn fixedwidth_conventions() {
+    use crate::fixedwidth::{Convention, Width};
+
+    let rut = Rut::from_str("76.086.428-5").unwrap();
+
+    for (convention, width, field) in [
+        (Convention::Previred, Width::Twelve, "000760864285"),
+        (Convention::BancoEstadoNomina, Width::Ten, "0760864285"),
+        (Convention::Legacy, Width::Nine, "760864285"),
+    ] {
+        let mut record = format!("{:<20}", "").into_bytes();
+
+        assert_eq!(convention.width(), width);
+        assert_eq!(convention.write(&rut), field);
+        assert_eq!(convention.read(field.as_bytes()).unwrap(), rut);
+
+        convention.field(3).write(&rut, &mut record).unwrap();
+
+        assert_eq!(&record[3..3 + field.len()], field.as_bytes());
+        assert_eq!(convention.field(3).read(&record).unwrap(), rut);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn unverified_rut_defers_verification() {
+    #[derive(::serde::Deserialize, ::serde::Serialize)]
+    struct Record {
+        rut: UnverifiedRut,
+    }
+
+    let records: Vec<Record> = serde_json::from_str(
+        r#"[{ "rut": "17.951.585-7" }, { "rut": "not a rut" }, { "rut": "76086428-5" }]"#,
+    )
+    .unwrap();
+    let inputs = records
+        .into_iter()
+        .map(|record| record.rut)
+        .collect::<Vec<_>>();
+    let verified = UnverifiedRut::verify_all(&inputs)
+        .map(|result| result.map(|rut| rut.num()).map_err(|err| err.kind()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(verified, [Ok(17951585), Err("nan"), Ok(76086428)]);
+    assert_eq!(
+        serde_json::to_string(&Record {
+            rut: inputs[1].clone()
+        })
+        .unwrap(),
+        r#"{"rut":"not a rut"}"#
+    );
+    assert_eq!(Rut::try_from(UnverifiedRut::from(MIN)).unwrap(), MIN);
+}
+
+#[test]
+fn rut_ref_borrows_input() {
+    let buf = samples()
+        .into_iter()
+        .map(|sample| sample.rut)
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    for (line, sample) in buf.lines().zip(samples()) {
+        let borrowed = RutRef::parse(line).unwrap();
+        let from_bytes = RutRef::parse_bytes(line.as_bytes()).unwrap();
+
+        assert!(std::ptr::eq(borrowed.as_str(), line));
+        assert_eq!(borrowed, from_bytes);
+        assert_eq!(borrowed, Rut::from_str(&sample.rut).unwrap());
+        assert_eq!(borrowed.num().to_string(), sample.num);
+    }
+
+    assert!(RutRef::parse("17.951.585-8").is_err());
+    assert!(RutRef::parse_bytes(b"17.951.585-\xff").is_err());
+}
+
+#[cfg(feature = "winnow")]
+#[test]
+fn parse_strict_follows_grammar() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            let input = rut.format(fmt);
+
+            assert_eq!(Format::parse_grammar(&input).unwrap(), fmt);
+            assert_eq!(Rut::parse_strict(&input).unwrap(), rut);
+        }
+    }
+
+    let cases = vec![
+        ("1-7.9.5-15857", 1, Expected::Dot),
+        ("17.95.1585-7", 3, Expected::Group),
+        ("1795.585-7", 3, Expected::Dot),
+        ("17.951.585-77", 12, Expected::EndOfInput),
+        ("017951585-7", 0, Expected::Number),
+        ("17951585-", 9, Expected::VerificationDigit),
+        ("17951585-x", 9, Expected::VerificationDigit),
+        (" 179515857", 0, Expected::Number),
+        ("17951x585", 5, Expected::EndOfInput),
+        ("1", 1, Expected::VerificationDigit),
+    ];
+
+    for (input, want_offset, want_expected) in cases {
+        let err = Rut::parse_strict(input).unwrap_err();
+
+        assert!(
+            matches!(err, Error::Syntax { offset, expected } if offset == want_offset && expected == want_expected),
+            "{input}: {err:?}"
+        );
+        assert_eq!(err.kind(), "syntax");
+    }
+
+    assert!(Rut::from_str("1-7.9.5-15857").is_ok());
+    assert!(matches!(
+        Rut::parse_strict("17.951.585-8"),
+        Err(Error::InvalidVerificationDigit { .. })
+    ));
+}
+
+#[test]
+fn random_stream_is_a_permutation() {
+    let band = RutBand::custom(17_950_000, 17_960_000).unwrap();
+    let mut nums = band
+        .random_stream(42)
+        .map(|rut| rut.num())
+        .collect::<Vec<Num>>();
+
+    assert_eq!(nums.len(), 10_001);
+    assert_ne!(nums, band.iter().map(|rut| rut.num()).collect::<Vec<Num>>());
+
+    nums.sort_unstable();
+
+    assert_eq!(nums, band.range().collect::<Vec<Num>>());
+
+    let mut stream = Rut::random_stream(7);
+    let third = stream.clone().nth(2).unwrap();
+
+    assert_eq!(stream.len(), 99_000_000);
+    assert_eq!(stream.nth(2), Some(third));
+    assert_eq!(stream.len(), 98_999_997);
+    assert!(stream.take(1_000).all(|rut| RANGE.contains(&rut.num())));
+
+    let single = RutShuffle::new(MIN, MIN, 1).collect::<Vec<Rut>>();
+
+    assert_eq!(single, vec![MIN]);
+    assert_eq!(RutShuffle::new(MAX, MIN, 1).count(), 0);
+}
+
+#[test]
+fn band_constants_match_bands() {
+    assert_eq!(RANGE, MIN.num()..=MAX.num());
+    assert_eq!(RutBand::Persons.range(), MIN_NUM..=PERSON_MAX_NUM);
+    assert_eq!(RutBand::Companies.range(), COMPANY_MIN_NUM..=MAX_NUM);
+    assert_eq!(
+        RutBand::ForeignInvestors.range(),
+        FOREIGN_INVESTOR_MIN_NUM..=FOREIGN_INVESTOR_MAX_NUM
+    );
+
+    let person = Rut::from_str("17.951.585-7").unwrap();
+    let company = Rut::from_str("76.086.428-5").unwrap();
+    let investor = RutBand::ForeignInvestors.min();
+
+    assert!(person.in_person_band() && !person.in_company_band());
+    assert!(company.in_company_band() && !company.in_person_band());
+    assert!(investor.in_foreign_investor_band() && investor.in_person_band());
+    assert!(!person.in_foreign_investor_band());
+}
+
+#[test]
+fn rut_parts_round_trip() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let parts = RutParts::from(rut);
+
+        assert_eq!(parts.num.to_string(), sample.num);
+        assert_eq!(Rut::try_from(parts).unwrap(), rut);
+    }
+
+    let lower = RutParts {
+        num: 92_635_843,
+        vd: 'k',
+    };
+
+    assert_eq!(Rut::try_from(lower).unwrap().to_string(), "92635843K");
+    assert!(matches!(
+        Rut::try_from(RutParts { num: 999, vd: '0' }),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        Rut::try_from(RutParts {
+            num: 17_951_585,
+            vd: 'x'
+        }),
+        Err(Error::VerificationDigitOutOfBounds(_))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rut_parts_serde() {
+    let parts = RutParts {
+        num: 76_086_428,
+        vd: '5',
+    };
+
+    assert_tokens(
+        &parts.readable(),
+        &[
+            Token::Struct {
+                name: "RutParts",
+                len: 2,
+            },
+            Token::Str("num"),
+            Token::U32(76_086_428),
+            Token::Str("vd"),
+            Token::Char('5'),
+            Token::StructEnd,
+        ],
+    );
+
+    for json in [
+        r#"{"num":76086428,"vd":"5"}"#,
+        r#"{"num":76086428,"vd":5}"#,
+        r#"[76086428,"5"]"#,
+    ] {
+        assert_eq!(serde_json::from_str::<RutParts>(json).unwrap(), parts);
+    }
+
+    let k: RutParts = serde_json::from_str(r#"{"vd":10,"num":92635843}"#).unwrap();
+
+    assert_eq!(k.vd, 'K');
+    assert!(serde_json::from_str::<RutParts>(r#"{"num":76086428,"vd":"55"}"#).is_err());
+    assert!(serde_json::from_str::<RutParts>(r#"{"num":76086428,"vd":11}"#).is_err());
+    assert!(serde_json::from_str::<RutParts>(r#"{"num":76086428}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rut_parts_bincode() {
+    for parts in [
+        RutParts {
+            num: 76_086_428,
+            vd: '5',
+        },
+        RutParts {
+            num: 92_635_843,
+            vd: 'K',
+        },
+    ] {
+        let bytes = bincode::serialize(&parts).unwrap();
+
+        assert_eq!(bincode::deserialize::<RutParts>(&bytes).unwrap(), parts);
+    }
+}
+
+#[test]
+fn errors_in_spanish() {
+    let cases = vec![
+        ("17.951.585-8", "Dígito verificador inválido: se esperaba 7"),
+        ("", "Debe ingresar un RUT"),
+        (
+            "999.999-9",
+            "El RUT debe estar entre 1.000.000-9 y 99.999.999-9",
+        ),
+        ("17.9x1.585-7", "El RUT contiene caracteres no válidos"),
+        ("1795158587777", "El número del RUT es demasiado largo"),
+        (
+            "20-12345678-6",
+            "No es un RUT, parece ser un CUIT argentino",
+        ),
+    ];
+
+    for (input, want) in cases {
+        assert_eq!(
+            Rut::from_str(input).unwrap_err().to_spanish(),
+            want,
+            "{input}"
+        );
+    }
+
+    assert_eq!(
+        CompanyRut::from_str("17.951.585-7")
+            .unwrap_err()
+            .to_spanish(),
+        "Se esperaba un RUT de empresa"
+    );
+    assert_eq!(
+        Rut::try_from("1x5".to_string()).unwrap_err().to_spanish(),
+        "RUT \"1x5\" inválido: El RUT contiene caracteres no válidos"
+    );
+}
+
+#[test]
+fn http_error_responses() {
+    let cases = vec![
+        ("", 400, "empty_string"),
+        ("17.951.585-x", 400, "verification_digit_out_of_bounds"),
+        ("17.951.585-8", 422, "invalid_verification_digit"),
+        ("999.999-9", 422, "out_of_range"),
+    ];
+
+    for (input, status, kind) in cases {
+        let response = http::ErrorResponse::from(Rut::from_str(input).unwrap_err());
+
+        assert_eq!(response.status_code(), status, "{input}");
+        assert_eq!(response.kind(), kind);
+    }
+
+    let err = Rut::try_from("\"17\"\n".to_string()).unwrap_err();
+    let response = http::ErrorResponse::new(&err).with_type("https://example.com/problems/rut");
+
+    assert_eq!(response.status_code(), 400);
+    assert_eq!(response.title(), "Bad Request");
+    assert_eq!(
+        response.to_json(),
+        r#"{"type":"https://example.com/problems/rut","title":"Bad Request","status":400,"detail":"Invalid RUT \"\\\"17\\\"\\n\"","kind":"invalid_value"}"#
+    );
+    assert!(serde_json::from_str::<serde_json::Value>(&response.to_json()).is_ok());
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn http_axum_response() {
+    use ::axum_core::response::IntoResponse;
+
+    let response = Rut::from_str("17.951.585-8").unwrap_err().into_response();
+
+    assert_eq!(response.status(), 422);
+    assert_eq!(
+        response.headers()[::http::header::CONTENT_TYPE],
+        http::PROBLEM_JSON
+    );
+}
+
+#[cfg(feature = "actix")]
+#[test]
+fn http_actix_response() {
+    use ::actix_web::ResponseError;
+
+    let response = Rut::from_str("").unwrap_err().error_response();
+
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response
+            .headers()
+            .get(::actix_web::http::header::CONTENT_TYPE)
+            .unwrap(),
+        http::PROBLEM_JSON
+    );
+}
+
+#[test]
+fn try_from_parts_blames_the_failing_part() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let vd = sample.rut.chars().last().unwrap().to_string();
+
+        assert_eq!(Rut::try_from_parts(&sample.num, &vd).unwrap(), rut);
+    }
+
+    assert_eq!(
+        Rut::try_from_parts(" 92.635.843 ", "k")
+            .unwrap()
+            .to_string(),
+        "92635843K"
+    );
+
+    let cases = vec![
+        ("", "7", RutPart::Num, "nan"),
+        ("17951x585", "7", RutPart::Num, "nan"),
+        ("999999", "9", RutPart::Num, "out_of_range"),
+        (
+            "17951585",
+            "",
+            RutPart::VerificationDigit,
+            "verification_digit_out_of_bounds",
+        ),
+        (
+            "17951585",
+            "77",
+            RutPart::VerificationDigit,
+            "verification_digit_out_of_bounds",
+        ),
+        (
+            "17951585",
+            "8",
+            RutPart::VerificationDigit,
+            "invalid_verification_digit",
+        ),
+    ];
+
+    for (num, vd, want_part, want_kind) in cases {
+        let err = Rut::try_from_parts(num, vd).unwrap_err();
+
+        assert_eq!(err.kind(), "invalid_part");
+        assert!(
+            matches!(&err, Error::InvalidPart { part, source } if *part == want_part && source.kind() == want_kind),
+            "{num} {vd}: {err:?}"
+        );
+    }
+
+    let err = Rut::try_from_parts("17951585", "8").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Invalid RUT verification digit: Invalid verification digit: have 8, want 7"
+    );
+    assert_eq!(
+        err.to_spanish(),
+        "Dígito verificador inválido: se esperaba 7"
+    );
+}
+
+#[test]
+fn format_custom_matches_formats() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            assert_eq!(
+                rut.format_custom(&FormatOptions::from(fmt)),
+                rut.format(fmt)
+            );
+        }
+    }
+
+    let rut = Rut::from_str("1.000.000-9").unwrap();
+    let padded = FormatOptions {
+        pad_width: 10,
+        ..FormatOptions::DOTS
+    };
+
+    assert_eq!(rut.format_custom(&padded), "0.001.000.000-9");
+    assert_eq!(
+        rut.format_custom(&FormatOptions {
+            dash: Some(' '),
+            pad_width: 3,
+            ..FormatOptions::SANS
+        }),
+        "1000000 9"
+    );
+    assert_eq!(FormatOptions::default(), FormatOptions::DOTS);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn json_value_round_trip() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+
+        for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+            assert_eq!(Rut::from_json_value(&rut.to_json_value(fmt)).unwrap(), rut);
+        }
+
+        let num = serde_json::Value::from(rut.num());
+
+        assert_eq!(Rut::from_json_value(&num).unwrap(), rut);
+    }
+
+    let cases = vec![
+        (serde_json::json!(-1), "out_of_range"),
+        (serde_json::json!(1.5), "out_of_range"),
+        (serde_json::json!(-17951585), "out_of_range"),
+        (serde_json::json!(17951585.0), "out_of_range"),
+        (serde_json::json!(99_999_999_999u64), "out_of_range"),
+        (serde_json::json!(null), "invalid_format"),
+        (serde_json::json!(["17.951.585-7"]), "invalid_format"),
+        (
+            serde_json::json!("17.951.585-8"),
+            "invalid_verification_digit",
+        ),
+    ];
+
+    for (value, kind) in cases {
+        assert_eq!(
+            Rut::from_json_value(&value).unwrap_err().kind(),
+            kind,
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn credential_payloads_round_trip() {
+    for sample in samples() {
+        let rut = Rut::from_str(&sample.rut).unwrap();
+        let vcard = format!(
+            "BEGIN:VCARD\r\nVERSION:4.0\r\n{}\r\nEND:VCARD\r\n",
+            rut.to_vcard_line()
+        );
+
+        assert_eq!(Rut::from_vcard(&vcard).unwrap(), rut);
+        assert_eq!(Rut::from_url(&rut.to_url("app://scan")).unwrap(), rut);
+        assert_eq!(
+            Rut::from_barcode_payload(rut.to_url("https://example.com/docstatus").as_bytes())
+                .unwrap(),
+            rut
+        );
+    }
+
+    let folded = "BEGIN:VCARD\nX-RUT:1\n 7951585\n\t-7\nEND:VCARD";
+
+    assert_eq!(Rut::from_vcard(folded).unwrap().num(), 17_951_585);
+    assert!(Rut::from_vcard("NOTE:X-RUT:17951585-7").is_err());
+    assert!(Rut::from_vcard("X-RUT:17951585-8").is_err());
+    assert!(Rut::from_url("app://scan?RUN=17951585%2").is_err());
+    assert!(Rut::from_url("app://scan#RUN=17951585-7").is_err());
+    assert_eq!(
+        Rut::from_url("app://scan?Run=+17951585-7").unwrap().num(),
+        17_951_585
+    );
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn join_keys_match_across_keyers() {
+    let ruts = samples()
+        .into_iter()
+        .map(|sample| Rut::from_str(&sample.rut).unwrap())
+        .collect::<Vec<Rut>>();
+    let ours = JoinKeyer::new("k1", b"shared").unwrap();
+    let theirs = JoinKeyer::new("k1", b"shared").unwrap();
+    let other = JoinKeyer::new("k1", b"not shared").unwrap();
+
+    let keys = ours.join_keys(ruts.clone()).collect::<Vec<String>>();
+
+    assert_eq!(
+        keys,
+        theirs.join_keys(ruts.clone()).collect::<Vec<String>>()
+    );
+    assert!(keys.iter().all(|key| key.len() == "k1:".len() + 64));
+
+    for (rut, key) in ruts.iter().zip(&keys) {
+        assert!(theirs.verify(rut, key));
+        assert!(!other.verify(rut, key));
+        assert_ne!(other.join_key(rut), *key);
+    }
+
+    let mut rotated = ours.clone();
+
+    assert!(rotated.rotate("k1", b"again").is_err());
+    assert!(rotated.rotate("bad:kid", b"again").is_err());
+    assert!(JoinKeyer::new("", b"secret").is_err());
+
+    rotated.rotate("k2", b"fresh").unwrap();
+
+    assert_eq!(rotated.kids().collect::<Vec<&str>>(), vec!["k1", "k2"]);
+    assert!(rotated.verify(&ruts[0], &keys[0]));
+    assert!(!rotated.remove("k2"));
+    assert!(rotated.remove("k1"));
+    assert!(!rotated.verify(&ruts[0], &keys[0]));
+    assert!(!rotated.verify(&ruts[0], "k2:not hex"));
+    assert!(!format!("{rotated:?}").contains("fresh"));
+}
+
+#[test]
+fn sorted_rut_block_membership() {
+    let ruts = RutBand::custom(17_000_000, 17_100_000)
+        .unwrap()
+        .iter()
+        .step_by(7)
+        .collect::<Vec<Rut>>();
+    let block = store::SortedRutBlock::from_sorted(ruts.iter().copied()).unwrap();
+
+    assert_eq!(block.len(), ruts.len());
+    assert_eq!(block.iter().collect::<Vec<Rut>>(), ruts);
+    assert!(block.size_in_bytes() < ruts.len() * 2);
+
+    for rut in RutBand::custom(16_999_990, 17_100_010).unwrap().iter() {
+        assert_eq!(
+            block.contains(&rut),
+            ruts.binary_search(&rut).is_ok(),
+            "{rut}"
+        );
+    }
+
+    let shuffled = ruts.iter().rev().chain(&ruts).copied();
+
+    assert_eq!(shuffled.collect::<store::SortedRutBlock>(), block);
+    assert_eq!(
+        store::SortedRutBlock::from_bytes(&block.to_bytes()).unwrap(),
+        block
+    );
+    assert!(store::SortedRutBlock::from_sorted(ruts.iter().rev().copied()).is_err());
+    assert!(store::SortedRutBlock::new().iter().next().is_none());
+    assert!(!store::SortedRutBlock::new().contains(&MIN));
+
+    let bytes = block.to_bytes();
+
+    assert!(matches!(
+        store::SortedRutBlock::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        store::SortedRutBlock::from_bytes(&[2, 0]),
+        Err(Error::UnsupportedWireVersion(2))
+    ));
+    assert!(matches!(
+        store::SortedRutBlock::from_bytes(&[1, 2, 0x80, 0x89, 0x7a, 0]),
+        Err(Error::InvalidFormat)
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn sorted_rut_block_serde() {
+    let block = samples()
+        .into_iter()
+        .map(|sample| Rut::from_str(&sample.rut).unwrap())
+        .collect::<store::SortedRutBlock>();
+    let json = serde_json::to_string(&block).unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<store::SortedRutBlock>(&json).unwrap(),
+        block
+    );
+    assert!(serde_json::from_str::<store::SortedRutBlock>("[2,0]").is_err());
+}
+
+#[cfg(feature = "roaring")]
+#[test]
+fn rut_bitmap_set_algebra() {
+    let persons = RutBand::custom(17_000_000, 17_000_999)
+        .unwrap()
+        .iter()
+        .collect::<RutBitmapSet>();
+    let mut evens = RutBand::custom(17_000_500, 17_001_499)
+        .unwrap()
+        .iter()
+        .filter(|rut| rut.num() % 2 == 0)
+        .collect::<RutBitmapSet>();
+
+    assert_eq!(persons.len(), 1_000);
+    assert_eq!((&persons | &evens).len(), 1_000 + 250);
+    assert_eq!((&persons & &evens).len(), 250);
+    assert_eq!(persons.intersection_len(&evens), 250);
+    assert_eq!((&persons - &evens).len(), 750);
+    assert_eq!((&persons ^ &evens).len(), 750 + 250);
+    assert_eq!(
+        persons.iter().collect::<Vec<Rut>>(),
+        RutBand::custom(17_000_000, 17_000_999)
+            .unwrap()
+            .iter()
+            .collect::<Vec<Rut>>()
+    );
+
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+
+    assert!(evens.insert(&rut));
+    assert!(!evens.insert(&rut));
+    assert!(evens.contains(&rut));
+    assert!(evens.remove(&rut));
+    assert!(!evens.contains(&rut));
+
+    evens.extend([rut]);
+
+    assert_eq!(RutBitmapSet::from_bytes(&evens.to_bytes()).unwrap(), evens);
+    assert!(matches!(
+        RutBitmapSet::try_from(::roaring::RoaringBitmap::from_iter([1, 2])),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        RutBitmapSet::from_bytes(b"not a bitmap"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(RutBitmapSet::new().is_empty());
+}
+
+#[test]
+fn rut_slice_ext_formats_as_format() {
+    let mut ruts = samples()
+        .iter()
+        .map(|Sample { rut, .. }| Rut::from_str(rut).unwrap())
+        .collect::<Vec<Rut>>();
+
+    for fmt in [Format::Sans, Format::Dash, Format::Dots] {
+        let want = ruts
+            .iter()
+            .map(|rut| rut.format(fmt))
+            .collect::<Vec<String>>();
+
+        assert_eq!(ruts.format_all(fmt), want);
+    }
+
+    let column = ruts.to_csv_column();
+
+    assert_eq!(column.lines().count(), ruts.len());
+    assert!(column.ends_with('\n'));
+
+    let len = ruts.len();
+
+    ruts.extend(ruts.clone());
+    ruts.sort_by_num();
+
+    assert!(ruts.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    ruts.dedup_exact();
+
+    assert!(ruts.len() <= len);
+    assert!(ruts.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn error_sources_wrapped_errors() {
+    use std::error::Error as _;
+
+    let err = Rut::try_from_parts("17951585", "8").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Invalid RUT verification digit: Invalid verification digit: have 8, want 7"
+    );
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Invalid verification digit: have 8, want 7"
+    );
+    assert!(Error::InvalidFormat.source().is_none());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_is_in_range() {
+    for _ in 0..100 {
+        assert!(RANGE.contains(&Rut::random().unwrap().num()));
+    }
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn rotating_codes_change_every_step() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let rut = Rut::from_str("17.951.585-7").unwrap();
+    let other = Rut::from_str("76.086.428-5").unwrap();
+    let at = UNIX_EPOCH + Duration::from_secs(1_790_000_010);
+    let code = rut.rotating_code(b"secret", at);
+
+    assert!(code.chars().all(|c| c.is_ascii_digit()));
+    assert_eq!(code.len(), ROTATING_CODE_DIGITS);
+    assert_ne!(code, rut.rotating_code(b"secret", at + ROTATING_CODE_STEP));
+    assert_ne!(code, other.rotating_code(b"secret", at));
+    assert!(rut.verify_rotating_code(b"secret", &code, at, 0));
+    assert!(rut.verify_rotating_code(b"secret", &code, at - ROTATING_CODE_STEP, 1));
+    assert!(!rut.verify_rotating_code(b"secret", &code, at + ROTATING_CODE_STEP * 2, 1));
+    assert!(!other.verify_rotating_code(b"secret", &code, at, 1));
+    assert!(!rut.verify_rotating_code(b"secret", &code[1..], at, 1));
+}
+
+#[test]
+fn rut_id_round_trips() {
+    for Sample { rut, .. } in samples() {
+        let rut = Rut::from_str(&rut).unwrap();
+        let id = RutId::from(rut);
+        let encoded = id.to_string();
+
+        assert_eq!(encoded.len(), 7);
+        assert_eq!(RutId::from_str(&encoded).unwrap(), id);
+        assert_eq!(RutId::from_str(&encoded.to_uppercase()).unwrap().rut(), rut);
+    }
+
+    assert!(matches!(RutId::from_str("aaaaaaa"), Err(Error::OutOfRange)));
+    assert!(matches!(RutId::from_str("zzzzzzz"), Err(Error::OutOfRange)));
+    assert!(matches!(
+        RutId::from_str("aard23!"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(RutId::from_str(""), Err(Error::InvalidFormat)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rut_id_serializes_as_string() {
+    let id = RutId::from(Rut::from_str("17.951.585-7").unwrap());
+
+    assert_tokens(&id, &[Token::Str("aard23b")]);
+}
+
+#[test]
+fn from_str_with_rejects_kinds() {
+    let onboarding = ValidationPolicy {
+        reject_foreign_investors: true,
+        reject_placeholders: true,
+        ..ValidationPolicy::default()
+    };
+
+    assert!(Rut::from_str_with("17.951.585-7", onboarding).is_ok());
+    assert!(matches!(
+        Rut::from_str_with("46.000.000-9", onboarding),
+        Err(Error::RejectedBand(RutBand::ForeignInvestors))
+    ));
+    assert!(matches!(
+        Rut::from_str_with("44.444.446-0", onboarding),
+        Err(Error::Placeholder)
+    ));
+    assert!(matches!(
+        Rut::from_str_with("12.345.678-5", onboarding),
+        Err(Error::Placeholder)
+    ));
+    assert_eq!(
+        Rut::from_str_with("44.444.446-0", ValidationPolicy::default())
+            .unwrap()
+            .kind(),
+        RutKind::Placeholder
+    );
+    assert_eq!(Error::Placeholder.kind(), "placeholder");
+    assert_eq!(RutKind::ForeignInvestor.to_string(), "foreign_investor");
+}
+
+#[test]
+fn display_with_label_pads_whole_text() {
+    let rut = Rut::from_str("9.263.584-8").unwrap();
+
+    assert_eq!(
+        format!(
+            "[{:>20}]",
+            rut.display_with_label(RutTitle::Rut, Language::Spanish)
+        ),
+        "[    RUT: 9.263.584-8]"
+    );
+    assert_eq!(
+        rut.display_with_label(RutTitle::Run, Language::English)
+            .to_string(),
+        "National ID (RUN): 9.263.584-8"
+    );
+    assert_eq!(
+        rut.display_with_label(RutTitle::default(), Language::default())
+            .to_string(),
+        "RUT: 9.263.584-8"
+    );
+}
+
+#[test]
+fn rut_query_sql_agrees_with_matches() {
+    let ruts = samples()
+        .iter()
+        .map(|Sample { rut, .. }| Rut::from_str(rut).unwrap())
+        .collect::<Vec<Rut>>();
+
+    for prefix in ["1", "17", "7.6", "9 9", "123456789"] {
+        let query = RutQuery::new().starts_with_prefix(prefix).unwrap();
+        let digits = prefix.replace(['.', ' '], "");
+
+        for rut in &ruts {
+            assert_eq!(
+                query.matches(rut),
+                rut.num().to_string().starts_with(&digits),
+                "{prefix} {rut:?}"
+            );
+        }
+    }
+
+    let set = RutQuery::new().in_set(ruts.iter().copied().take(2));
+
+    assert!(ruts.iter().take(2).all(|rut| set.matches(rut)));
+    assert_eq!(
+        RutQuery::new()
+            .in_set([
+                Rut::from_str("9.263.584-8").unwrap(),
+                Rut::from_str("1.000.000-9").unwrap()
+            ])
+            .to_sql("num"),
+        "num IN (1000000, 9263584)"
+    );
+    assert_eq!(RutQuery::new().to_sql("num"), "1 = 1");
+    assert_eq!(RutQuery::new().in_set([]).to_sql("num"), "1 = 0");
+    assert_eq!(
+        RutQuery::new()
+            .starts_with_prefix("123456789")
+            .unwrap()
+            .to_sql("num"),
+        "1 = 0"
+    );
+    assert!(matches!(
+        RutQuery::new().starts_with_prefix("0"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        RutQuery::new().starts_with_prefix("1; DROP TABLE"),
+        Err(Error::InvalidFormat)
+    ));
+    assert!(matches!(
+        RutQuery::new().starts_with_prefix(" . "),
+        Err(Error::EmptyString)
+    ));
+}
+
+#[test]
+fn matches_prefix_follows_typing() {
+    let rut = Rut::from_str("92.635.843-K").unwrap();
+    let typed = "92.635.843-k";
+
+    for len in 0..=typed.len() {
+        assert!(rut.matches_prefix(&typed[..len]), "{}", &typed[..len]);
+    }
+
+    assert!(rut.matches_prefix(" 92 635 843K"));
+    assert!(!rut.matches_prefix("92.635.843-1"));
+    assert!(!rut.matches_prefix("92.635.843-K-"));
+    assert!(!rut.matches_prefix("92.635.843K0"));
+    assert!(!rut.matches_prefix("93"));
+
+    let ruts =
+        ["92.635.843-K", "9.263.584-8", "92.635.843-K"].map(|rut| Rut::from_str(rut).unwrap());
+
+    assert_eq!(
+        Rut::rank_prefix_matches("9.263.584", ruts),
+        [ruts[1], ruts[0]]
+    );
+    assert_eq!(Rut::rank_prefix_matches("9.263.584-", ruts), [ruts[1]]);
+    assert_eq!(Rut::rank_prefix_matches("", ruts), [ruts[1], ruts[0]]);
+}
+
+/// Collects the `kind` of every parse event emitted while running `parse`,
+/// `ok` for successful parses
+#[cfg(feature = "tracing")]
+fn recorded(parse: impl FnOnce()) -> Vec<String> {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    struct Kind(String);
+
+    impl Visit for Kind {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "kind" {
+                self.0 = value.to_owned();
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for Recorder {
+        fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+            let mut kind = Kind(String::from("ok"));
+
+            match event.metadata().name() {
+                "rutcl.parse.ok" => {}
+                "rutcl.parse.err" => event.record(&mut kind),
+                _ => return,
+            }
+
+            self.0.lock().unwrap().push(kind.0);
+        }
+    }
+
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(Recorder(Arc::clone(&recorded)));
+
+    tracing::subscriber::with_default(subscriber, parse);
+
+    let recorded = recorded.lock().unwrap();
+
+    recorded.clone()
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn telemetry_records_policy_parses_once() {
+    let policy = ValidationPolicy {
+        reject_placeholders: true,
+        ..ValidationPolicy::STRICT
+    };
+
+    assert_eq!(
+        recorded(|| drop(Rut::from_str_with("017.951.585-7", policy))),
+        ["invalid_format"]
+    );
+    assert_eq!(
+        recorded(|| drop(Rut::from_str_with("17.951.585-k", policy))),
+        ["invalid_format"]
+    );
+    assert_eq!(
+        recorded(|| drop(Rut::from_str_with("11.111.111-1", policy))),
+        ["placeholder"]
+    );
+    assert_eq!(
+        recorded(|| drop(Rut::from_str_with("17.951.585-7", policy))),
+        ["ok"]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "tracing", feature = "winnow"))]
+fn telemetry_records_strict_parses_once() {
+    assert_eq!(
+        recorded(|| drop(Rut::parse_strict("1-7.9.5-15857"))),
+        ["syntax"]
+    );
+    assert_eq!(
+        recorded(|| drop(Rut::parse_strict("17.951.585-8"))),
+        ["invalid_verification_digit"]
+    );
+    assert_eq!(recorded(|| drop(Rut::parse_strict("17.951.585-7"))), ["ok"]);
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use crate::{Format, Rut};
+
+/// Language titles are written in, see [`RutTitle`]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    Spanish,
+    English,
+}
+
+/// Title printed before a RUT in documents, see [`Rut::display_with_label`]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum RutTitle {
+    /// Rol Único Tributario, the RUT as a tax ID: `RUT:` in Spanish and
+    /// `Tax ID (RUT):` in English
+    #[default]
+    Rut,
+    /// Rol Único Nacional, the RUT as a national ID for natural persons:
+    /// `R.U.N.` in Spanish and `National ID (RUN):` in English
+    Run,
+}
+
+impl RutTitle {
+    /// Retrieves the title written in the provided [`Language`], without
+    /// trailing punctuation
+    pub const fn text(&self, lang: Language) -> &'static str {
+        match (self, lang) {
+            (RutTitle::Rut, Language::Spanish) => "RUT",
+            (RutTitle::Run, Language::Spanish) => "R.U.N.",
+            (RutTitle::Rut, Language::English) => "Tax ID (RUT)",
+            (RutTitle::Run, Language::English) => "National ID (RUN)",
+        }
+    }
+}
+
+/// A [`Rut`] written in [`Format::Dots`] after its title, created with
+/// [`Rut::display_with_label`].
+///
+/// Titles are followed by a colon and a space, abbreviations ending in a
+/// period (`R.U.N.`) by a space only. Width, fill and alignment flags apply
+/// to the whole text.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LabeledRut {
+    rut: Rut,
+    title: RutTitle,
+    lang: Language,
+}
+
+impl Display for LabeledRut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let title = self.title.text(self.lang);
+        let sep = if title.ends_with('.') { " " } else { ": " };
+
+        f.pad(&format!("{title}{sep}{}", self.rut.format(Format::Dots)))
+    }
+}
+
+impl Rut {
+    /// Writes the RUT after its title, for documents, receipts and other
+    /// printed output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::{Language, Rut, RutTitle};
+    ///
+    /// let rut: Rut = "17951585-7".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     rut.display_with_label(RutTitle::Rut, Language::Spanish).to_string(),
+    ///     "RUT: 17.951.585-7"
+    /// );
+    /// assert_eq!(
+    ///     rut.display_with_label(RutTitle::Run, Language::Spanish).to_string(),
+    ///     "R.U.N. 17.951.585-7"
+    /// );
+    /// assert_eq!(
+    ///     rut.display_with_label(RutTitle::Rut, Language::English).to_string(),
+    ///     "Tax ID (RUT): 17.951.585-7"
+    /// );
+    /// ```
+    pub fn display_with_label(&self, title: RutTitle, lang: Language) -> LabeledRut {
+        LabeledRut {
+            rut: *self,
+            title,
+            lang,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Error, Rut};
+
+/// A raw RUT input whose verification is deferred.
+///
+/// Creating an [`UnverifiedRut`] only keeps the input, [`UnverifiedRut::verify`]
+/// parses and checks it, yielding a [`Rut`]. With the `serde` feature it
+/// deserializes from any string, so pipelines can decode records first and
+/// verify RUTs later, in batches or on a different stage.
+///
+/// # Example
+///
+/// ```
+/// use rutcl::{Rut, UnverifiedRut};
+///
+/// let inputs = ["17.951.585-7", "17.951.585-8"].map(UnverifiedRut::new);
+///
+/// assert_eq!(inputs[1].as_str(), "17.951.585-8");
+/// assert!(inputs[0].verify().is_ok());
+/// assert!(inputs[1].verify().is_err());
+/// assert_eq!(UnverifiedRut::verify_all(&inputs).filter(Result::is_ok).count(), 1);
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnverifiedRut(String);
+
+impl UnverifiedRut {
+    /// Keeps the provided input, without checking it
+    pub fn new(input: impl Into<String>) -> Self {
+        UnverifiedRut(input.into())
+    }
+
+    /// Retrieves the input as provided
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Retrieves the input as provided
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Parses and verifies the input, as [`Rut::from_str`] does
+    pub fn verify(&self) -> Result<Rut, Error> {
+        Rut::from_str(&self.0)
+    }
+
+    /// Verifies each of the provided inputs, in order
+    pub fn verify_all(inputs: &[UnverifiedRut]) -> impl Iterator<Item = Result<Rut, Error>> + '_ {
+        inputs.iter().map(UnverifiedRut::verify)
+    }
+}
+
+impl From<String> for UnverifiedRut {
+    fn from(input: String) -> Self {
+        UnverifiedRut(input)
+    }
+}
+
+impl From<&str> for UnverifiedRut {
+    fn from(input: &str) -> Self {
+        UnverifiedRut(input.to_string())
+    }
+}
+
+/// Keeps the [`Rut`] as written by its `Display` implementation
+impl From<Rut> for UnverifiedRut {
+    fn from(rut: Rut) -> Self {
+        UnverifiedRut(rut.to_string())
+    }
+}
+
+impl TryFrom<UnverifiedRut> for Rut {
+    type Error = Error;
+
+    fn try_from(value: UnverifiedRut) -> Result<Self, Self::Error> {
+        value.verify()
+    }
+}
+
+impl AsRef<str> for UnverifiedRut {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for UnverifiedRut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::UnverifiedRut;
+
+    /// Serializes the input as provided
+    impl Serialize for UnverifiedRut {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    /// Deserializes any string, without checking it
+    impl<'de> Deserialize<'de> for UnverifiedRut {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            String::deserialize(deserializer).map(UnverifiedRut)
+        }
+    }
+}
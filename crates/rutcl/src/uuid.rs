@@ -0,0 +1,30 @@
+use ::uuid::Uuid;
+
+use crate::{Format, Rut};
+
+impl Rut {
+    /// Derives a stable version 5 (SHA-1) [`Uuid`] for this [`Rut`] inside
+    /// the provided namespace.
+    ///
+    /// The name hashed is the [`Format::Sans`] representation, so the same
+    /// RUT always maps to the same UUID regardless of how it was written,
+    /// and different namespaces yield unrelated UUIDs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rutcl::Rut;
+    /// use uuid::Uuid;
+    ///
+    /// let namespace = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com/customers");
+    /// let rut: Rut = "17.951.585-7".parse().unwrap();
+    /// let id = rut.to_uuid_v5(&namespace);
+    ///
+    /// assert_eq!(id, Uuid::new_v5(&namespace, b"179515857"));
+    /// assert_eq!(id, "179515857".parse::<Rut>().unwrap().to_uuid_v5(&namespace));
+    /// assert_eq!(id.get_version_num(), 5);
+    /// ```
+    pub fn to_uuid_v5(&self, namespace: &Uuid) -> Uuid {
+        Uuid::new_v5(namespace, self.format(Format::Sans).as_bytes())
+    }
+}
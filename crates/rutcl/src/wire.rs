@@ -0,0 +1,135 @@
+//! Versioned wire encodings for [`Rut`].
+//!
+//! The encodings in this module are frozen: they never change once released,
+//! regardless of future changes to [`Display`](std::fmt::Display),
+//! [`Rut::format`] or the `serde` implementations. Long-lived message queues
+//! and event stores should depend on these instead.
+//!
+//! New encodings are added as new versions (`WireV2`, ...) side by side.
+
+use crate::{Error, Num, Rut, VerificationDigit};
+
+/// Version 1 of the [`Rut`] wire encodings.
+///
+/// # String Encoding
+///
+/// The RUT's number in base 10 without leading zeros nor thousands separators,
+/// followed by an ASCII dash (`-`) and the verification digit, using an
+/// uppercase `K`. E.g. `17951585-7` or `92635843-K`.
+///
+/// # Binary Encoding
+///
+/// [`WireV1::BYTES`] bytes long:
+///
+/// | Offset | Size | Content                                     |
+/// |--------|------|---------------------------------------------|
+/// | 0      | 1    | Version, always [`WireV1::VERSION`]         |
+/// | 1      | 4    | RUT's number as big-endian `u32`            |
+/// | 5      | 1    | Verification digit as ASCII (`0`-`9`, `K`)  |
+///
+/// # Example
+///
+/// ```
+/// use rutcl::wire::WireV1;
+/// use rutcl::Rut;
+///
+/// let rut: Rut = "17.951.585-7".parse().unwrap();
+///
+/// assert_eq!(WireV1::encode_str(&rut), "17951585-7");
+/// assert_eq!(WireV1::decode_str("17951585-7").unwrap(), rut);
+/// assert_eq!(WireV1::decode_bytes(&WireV1::encode_bytes(&rut)).unwrap(), rut);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WireV1;
+
+impl WireV1 {
+    /// Version tag written as the first byte of the binary encoding
+    pub const VERSION: u8 = 1;
+
+    /// Length of the binary encoding
+    pub const BYTES: usize = 6;
+
+    /// Encodes the [`Rut`] using the V1 string encoding
+    pub fn encode_str(rut: &Rut) -> String {
+        format!("{}-{}", rut.num(), char::from(rut.vd()))
+    }
+
+    /// Decodes a [`Rut`] from the V1 string encoding.
+    ///
+    /// Fails with [`Error::InvalidFormat`] if the input is not exactly in the
+    /// V1 string encoding, even if it is a valid RUT in other format.
+    pub fn decode_str(input: &str) -> Result<Rut, Error> {
+        let Some((num, vd)) = input.split_once('-') else {
+            return Err(Error::InvalidFormat);
+        };
+
+        let is_num =
+            !num.is_empty() && !num.starts_with('0') && num.bytes().all(|b| b.is_ascii_digit());
+
+        let &[vd] = vd.as_bytes() else {
+            return Err(Error::InvalidFormat);
+        };
+
+        if !is_num {
+            return Err(Error::InvalidFormat);
+        }
+
+        Self::decode_parts(num.parse::<Num>().map_err(Error::NaN)?, vd)
+    }
+
+    /// Encodes the [`Rut`] using the V1 binary encoding
+    pub fn encode_bytes(rut: &Rut) -> [u8; Self::BYTES] {
+        let num = rut.num().to_be_bytes();
+
+        [
+            Self::VERSION,
+            num[0],
+            num[1],
+            num[2],
+            num[3],
+            char::from(rut.vd()) as u8,
+        ]
+    }
+
+    /// Decodes a [`Rut`] from the V1 binary encoding.
+    ///
+    /// Fails with [`Error::UnsupportedWireVersion`] if the version tag is not
+    /// [`WireV1::VERSION`], whatever the input's length, and with
+    /// [`Error::InvalidFormat`] if the input is not [`WireV1::BYTES`] long or
+    /// the verification digit is not one of `0`-`9` or `K`.
+    pub fn decode_bytes(bytes: &[u8]) -> Result<Rut, Error> {
+        let Some(&version) = bytes.first() else {
+            return Err(Error::InvalidFormat);
+        };
+
+        if version != Self::VERSION {
+            return Err(Error::UnsupportedWireVersion(version));
+        }
+
+        let &[_, a, b, c, d, vd] = bytes else {
+            return Err(Error::InvalidFormat);
+        };
+
+        Self::decode_parts(Num::from_be_bytes([a, b, c, d]), vd)
+    }
+
+    /// Builds the [`Rut`] checking the encoded verification digit, which must
+    /// be written as in the V1 encodings
+    fn decode_parts(num: Num, vd: u8) -> Result<Rut, Error> {
+        if !matches!(vd, b'0'..=b'9' | b'K') {
+            return Err(Error::InvalidFormat);
+        }
+
+        let want = Rut::try_from(num)?;
+        let have = VerificationDigit::try_from(vd as char)?;
+
+        if want.vd() != have {
+            return Err(Error::InvalidVerificationDigit {
+                have: vd as char,
+                want: want.vd().into(),
+            });
+        }
+
+        Ok(want)
+    }
+}
@@ -0,0 +1,25 @@
+use axum::routing::post;
+use axum::Router;
+use leptos::{get_configuration, view};
+use leptos_axum::{generate_route_list, handle_server_fns, LeptosRoutes};
+
+use web::fileserv::file_and_error_handler;
+use web::App;
+
+#[tokio::main]
+async fn main() {
+    let conf = get_configuration(None).await.unwrap();
+    let leptos_options = conf.leptos_options;
+    let addr = leptos_options.site_addr;
+    let routes = generate_route_list(App);
+    let app = Router::new()
+        .route("/api/*fn_name", post(handle_server_fns))
+        .leptos_routes(&leptos_options, routes, || view! { <App/> })
+        .fallback(file_and_error_handler)
+        .with_state(leptos_options);
+    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
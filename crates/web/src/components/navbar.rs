@@ -13,6 +13,9 @@ pub fn NavBar() -> impl IntoView {
                 <span class="section-divider">Usage</span>
                 <a class="link" href="/#create-rut">Create RUT</a>
                 <a class="link" href="/#random-in-range-rut">Random in Range RUT</a>
+                <a class="link" href="/#explain-the-checksum">Explain the Checksum</a>
+                <a class="link" href="/#format-converter">Format Converter</a>
+                <a class="link" href="/#validation-parity">Validation Parity</a>
             </nav>
             <footer class="h-[60px] bg-zinc-950 absolute p-4 bottom-0 w-full">
                 <small class="text-xs text-center block text-gray-400">
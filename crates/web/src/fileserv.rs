@@ -0,0 +1,40 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Request, Response, StatusCode, Uri};
+use axum::response::{IntoResponse, Response as AxumResponse};
+use leptos::{view, LeptosOptions};
+use tower::ServiceExt;
+use tower_http::services::ServeDir;
+
+use crate::App;
+
+/// Serves the files under `site_root`, such as the `/pkg` bundle, falling
+/// back to rendering the app for any other path
+pub async fn file_and_error_handler(
+    uri: Uri,
+    State(options): State<LeptosOptions>,
+    req: Request<Body>,
+) -> AxumResponse {
+    let res = get_static_file(uri, &options.site_root).await;
+
+    match res {
+        Ok(res) if res.status() == StatusCode::OK => res.into_response(),
+        _ => {
+            let handler = leptos_axum::render_app_to_stream(options, || view! { <App/> });
+
+            handler(req).await.into_response()
+        }
+    }
+}
+
+async fn get_static_file(uri: Uri, root: &str) -> Result<Response<Body>, (StatusCode, String)> {
+    let req = Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))?;
+
+    match ServeDir::new(root).oneshot(req).await {
+        Ok(res) => Ok(res.into_response()),
+        Err(err) => Err((StatusCode::INTERNAL_SERVER_ERROR, err.to_string())),
+    }
+}
@@ -1,5 +1,9 @@
 mod components;
 mod sections;
+mod server;
+
+#[cfg(feature = "ssr")]
+pub mod fileserv;
 
 use components::header::Header;
 use leptos::{component, view, IntoView};
@@ -7,9 +11,12 @@ use leptos_meta::{provide_meta_context, Title};
 
 use self::components::navbar::NavBar;
 use self::sections::create_rut::CreateRut;
+use self::sections::explain_checksum::ExplainChecksum;
+use self::sections::format_converter::FormatConverter;
 use self::sections::hero::Hero;
 use self::sections::installation::Installation;
 use self::sections::motivation::Motivation;
+use self::sections::validation_parity::ValidationParity;
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -26,8 +33,17 @@ pub fn App() -> impl IntoView {
                     <Motivation />
                     <Installation />
                     <CreateRut />
+                    <ExplainChecksum />
+                    <FormatConverter />
+                    <ValidationParity />
                 </main>
             </div>
         </div>
     }
 }
+
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount_to_body(App);
+}
@@ -0,0 +1,68 @@
+use leptos::{
+    component, create_signal, event_target_value, view, CollectView, IntoView, SignalGet, SignalSet,
+};
+use rutcl::explain::ChecksumTrace;
+use rutcl::Num;
+
+use crate::components::section::Section;
+
+/// Traces the number typed by the user, ignoring dots and anything after the
+/// dash
+fn trace(input: &str) -> Option<ChecksumTrace> {
+    let num = input.split_once('-').map_or(input, |(num, _)| num);
+    let num = num.trim().replace('.', "").parse::<Num>().ok()?;
+
+    (num > 0).then(|| ChecksumTrace::new(num))
+}
+
+#[component]
+pub fn ExplainChecksum() -> impl IntoView {
+    let (input_reader, input_writer) = create_signal(String::from("17.951.585"));
+    let trace_reader = move || trace(&input_reader.get());
+
+    view! {
+        <Section title="Explain the Checksum">
+            <p>The verification digit is computed with the Modulo 11 algorithm. <code>Rut::explain()</code> returns a <code>ChecksumTrace</code> with every step of the calculation.</p>
+            <code class="my-4">
+                let trace = rut.explain();
+            </code>
+            <h3>Example</h3>
+            <p>Type a RUT number, the verification digit is ignored.</p>
+            <input
+                type="text"
+                class="bg-gray-900 p-2 font-mono rounded-md mb-4"
+                prop:value={move || input_reader.get()}
+                on:input={move |ev| input_writer.set(event_target_value(&ev))}
+            />
+            {move || match trace_reader() {
+                Some(trace) => view! {
+                    <table class="font-mono mb-4">
+                        <tr>
+                            <th class="pr-4 text-left">Reversed digits</th>
+                            {trace.steps.iter().map(|step| view! { <td class="px-2">{step.digit}</td> }).collect_view()}
+                        </tr>
+                        <tr>
+                            <th class="pr-4 text-left">Factors</th>
+                            {trace.steps.iter().map(|step| view! { <td class="px-2">{step.factor}</td> }).collect_view()}
+                        </tr>
+                        <tr>
+                            <th class="pr-4 text-left">Products</th>
+                            {trace.steps.iter().map(|step| view! { <td class="px-2">{step.product}</td> }).collect_view()}
+                        </tr>
+                    </table>
+                    <ol class="bg-gray-900 p-4 font-mono rounded-md shadow-md mb-4">
+                        <li>"Sum: "{trace.sum}</li>
+                        <li>"Remainder: "{trace.sum}" mod 11 = "{trace.remainder}</li>
+                        <li>"Result: 11 - "{trace.remainder}" = "{trace.result}</li>
+                        <li>"Verification digit: "{trace.vd.to_string()}</li>
+                    </ol>
+                }
+                .into_view(),
+                None => view! {
+                    <p class="text-red-400">Not a RUT number</p>
+                }
+                .into_view(),
+            }}
+        </Section>
+    }
+}
@@ -0,0 +1,79 @@
+use leptos::{
+    component, create_signal, event_target_value, view, CollectView, IntoView, SignalGet, SignalSet,
+};
+use rutcl::{Format, Rut};
+
+use crate::components::section::Section;
+
+#[component]
+pub fn FormatConverter() -> impl IntoView {
+    let (input_reader, input_writer) =
+        create_signal(String::from("17.951.585-7\n76086428-5\n179515850"));
+    let (format_reader, format_writer) = create_signal(Format::Dots);
+    let output = move || {
+        Rut::parse_batch(&input_reader.get())
+            .iter()
+            .map(|line| line.convert(format_reader.get()))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+    let errors = move || {
+        let input = input_reader.get();
+
+        Rut::parse_batch(&input)
+            .iter()
+            .filter_map(|line| {
+                let err = line.result().err()?;
+                let span = line.error_span()?;
+                let start = span.start - line.range().start;
+                let end = span.end - line.range().start;
+                let text = line.input();
+
+                Some(view! {
+                    <li>
+                        <span class="text-gray-400">"Line "{line.line()}": "</span>
+                        {text[..start].to_string()}
+                        <mark class="bg-red-400 text-zinc-950">{text[start..end].to_string()}</mark>
+                        {text[end..].to_string()}
+                        <span class="text-red-400">" — "{err.to_string()}</span>
+                    </li>
+                })
+            })
+            .collect_view()
+    };
+
+    view! {
+        <Section title="Format Converter">
+            <p>Paste RUTs in any format, one per line, and pick the target format. <code>Rut::parse_batch()</code> parses each line and points errors to the offending chars.</p>
+            <code class="my-4">
+                let lines = Rut::parse_batch(input);
+            </code>
+            <h3>Example</h3>
+            <textarea
+                class="bg-gray-900 p-2 font-mono rounded-md w-full mb-4"
+                rows="6"
+                prop:value={move || input_reader.get()}
+                on:input={move |ev| input_writer.set(event_target_value(&ev))}
+            />
+            <select
+                class="bg-gray-900 p-2 rounded-md mb-4"
+                on:change={move |ev| {
+                    if let Ok(fmt) = event_target_value(&ev).parse::<Format>() {
+                        format_writer.set(fmt);
+                    }
+                }}
+            >
+                <option value="dots" selected>"Dots (12.345.678-5)"</option>
+                <option value="dash">"Dash (12345678-5)"</option>
+                <option value="sans">"Sans (123456785)"</option>
+            </select>
+            <textarea
+                class="bg-gray-900 p-2 font-mono rounded-md w-full mb-4"
+                rows="6"
+                readonly
+                prop:value={output}
+            />
+            <ul class="font-mono">{errors}</ul>
+        </Section>
+    }
+}
@@ -1,4 +1,7 @@
 pub mod create_rut;
+pub mod explain_checksum;
+pub mod format_converter;
 pub mod hero;
 pub mod installation;
 pub mod motivation;
+pub mod validation_parity;
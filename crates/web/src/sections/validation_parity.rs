@@ -0,0 +1,56 @@
+use leptos::{
+    component, create_resource, create_signal, event_target_checked, event_target_value, view,
+    IntoView, SignalGet, SignalSet, Transition,
+};
+
+use crate::components::section::Section;
+use crate::server::{validate_rut, Side, Validation};
+
+#[component]
+pub fn ValidationParity() -> impl IntoView {
+    let (input_reader, input_writer) = create_signal(String::from("17.951.585-7"));
+    let (server_reader, server_writer) = create_signal(false);
+    let validation = create_resource(
+        move || (input_reader.get(), server_reader.get()),
+        |(input, server)| async move {
+            if server {
+                validate_rut(input, String::from("dots"))
+                    .await
+                    .map_err(|err| err.to_string())
+            } else {
+                Ok(Validation::run(Side::Client, &input, "dots"))
+            }
+        },
+    );
+
+    view! {
+        <Section title="Validation Parity">
+            <p>The same <code>rutcl</code> code validates RUTs in the browser, compiled to WASM, and on the server, behind a Leptos server function.</p>
+            <h3>Example</h3>
+            <input
+                type="text"
+                class="bg-gray-900 p-2 font-mono rounded-md mb-4"
+                prop:value={move || input_reader.get()}
+                on:input={move |ev| input_writer.set(event_target_value(&ev))}
+            />
+            <label class="block mb-4">
+                <input
+                    type="checkbox"
+                    class="mr-2"
+                    prop:checked={move || server_reader.get()}
+                    on:change={move |ev| server_writer.set(event_target_checked(&ev))}
+                />
+                Validate on the server
+            </label>
+            <Transition fallback={move || view! { <p>"Validating…"</p> }}>
+                <p class="bg-gray-900 p-4 font-mono rounded-md shadow-md mb-4">
+                    {move || validation.get().map(|validation| match validation {
+                        Ok(Validation { side, result: Ok(rut) }) => format!("{rut} (validated on {side:?})"),
+                        Ok(Validation { side, result: Err(err) }) => format!("{err} (validated on {side:?})"),
+                        Err(err) => format!("Server error: {err}"),
+                    })}
+                </p>
+            </Transition>
+        </Section>
+    }
+}
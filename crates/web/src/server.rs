@@ -0,0 +1,35 @@
+use leptos::{server, ServerFnError};
+use rutcl::{Format, Rut};
+use serde::{Deserialize, Serialize};
+
+/// Where a [`Validation`] was run
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Client,
+    Server,
+}
+
+/// Outcome of validating a RUT, computed with the same code on both sides
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Validation {
+    pub side: Side,
+    pub result: Result<String, String>,
+}
+
+impl Validation {
+    /// Validates `input` and writes it in the format named by `format`
+    pub fn run(side: Side, input: &str, format: &str) -> Self {
+        let result = format
+            .parse::<Format>()
+            .and_then(|fmt| input.parse::<Rut>().map(|rut| rut.format(fmt)))
+            .map_err(|err| err.to_string());
+
+        Validation { side, result }
+    }
+}
+
+/// Runs [`Validation::run`] on the server
+#[server(ValidateRut, "/api")]
+pub async fn validate_rut(input: String, format: String) -> Result<Validation, ServerFnError> {
+    Ok(Validation::run(Side::Server, &input, &format))
+}